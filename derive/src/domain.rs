@@ -0,0 +1,167 @@
+// SPDX-License-Identifier: MIT
+
+use crate::add_unique;
+use crate::capability_trait;
+use crate::crate_path;
+use crate::implement_traits;
+use crate::transparent_trait;
+use crate::validate_impl;
+use quote::quote;
+use syn::parse::Parse;
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+use syn::Token;
+
+/// Input of `domain!` macro: list of modules.
+pub struct Domain {
+    modules: Vec<DomainModule>,
+}
+
+/// `user { Id: u64 [permissive], ... }`
+struct DomainModule {
+    attrs: Vec<syn::Attribute>,
+    vis: syn::Visibility,
+    name: syn::Ident,
+    types: Punctuated<DomainType, Token![,]>,
+}
+
+/// `Id: u64 [permissive]` or `Name: String [Clone, validated(length(min = 1))]`
+struct DomainType {
+    attrs: Vec<syn::Attribute>,
+    name: syn::Ident,
+    inner: syn::Type,
+    properties: Punctuated<syn::Meta, Token![,]>,
+}
+
+impl Parse for Domain {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut modules = Vec::new();
+        while !input.is_empty() {
+            modules.push(input.parse()?);
+        }
+        Ok(Self { modules })
+    }
+}
+
+impl Parse for DomainModule {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(syn::Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let name = input.parse()?;
+        let content;
+        syn::braced!(content in input);
+        let types = content.parse_terminated(DomainType::parse, Token![,])?;
+        Ok(Self {
+            attrs,
+            vis,
+            name,
+            types,
+        })
+    }
+}
+
+impl Parse for DomainType {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(syn::Attribute::parse_outer)?;
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let inner = input.parse()?;
+        let content;
+        syn::bracketed!(content in input);
+        let properties = content.parse_terminated(syn::Meta::parse, Token![,])?;
+        Ok(Self {
+            attrs,
+            name,
+            inner,
+            properties,
+        })
+    }
+}
+
+impl Domain {
+    /// Generate modules with tags and aliases.
+    pub fn expand(&self) -> proc_macro2::TokenStream {
+        self.modules
+            .iter()
+            .map(|module| {
+                let DomainModule {
+                    attrs,
+                    vis,
+                    name,
+                    types,
+                } = module;
+                let types = types
+                    .iter()
+                    .map(|t| t.expand().unwrap_or_else(syn::Error::into_compile_error));
+                quote! {
+                    #(#attrs)*
+                    #vis mod #name {
+                        #[allow(unused_imports)]
+                        use super::*;
+                        #(#types)*
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+impl DomainType {
+    fn expand(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let Self {
+            attrs,
+            name,
+            inner,
+            properties,
+        } = self;
+        let tag = quote::format_ident!("{name}Tag");
+        let tt = crate_path();
+        let mut out = quote! {
+            #[doc = concat!("Tag of [`", stringify!(#name), "`].")]
+            pub enum #tag {}
+            #(#attrs)*
+            pub type #name = #tt::TaggedType<#inner, #tag>;
+        };
+        let mut traits = Vec::new();
+        let mut validated = false;
+        for property in properties {
+            if property.path().is_ident("validated") {
+                // Validated tag is not Plain and keeps value as is.
+                out.extend(validate_impl(&tag, property.require_list()?)?);
+                out.extend(quote! {
+                    impl<V: ?Sized> #tt::Normalize<V> for #tag {}
+                });
+                validated = true;
+                continue;
+            }
+            let path = property.require_path_only()?;
+            let ident = path.require_ident()?.to_string();
+            let implied = if ident == "permissive" {
+                if properties.len() > 1 {
+                    return Err(syn::Error::new_spanned(
+                        property,
+                        "permissive must be the only property",
+                    ));
+                }
+                vec![quote::format_ident!("Permissive")]
+            } else {
+                implement_traits(&ident)
+                    .or_else(|| transparent_trait(&ident).map(|t| vec![t]))
+                    .or_else(|| capability_trait(&ident).map(|t| vec![t]))
+                    .ok_or_else(|| {
+                        syn::Error::new_spanned(property, format!("Don't know property: {ident}"))
+                    })?
+            };
+            add_unique(&mut traits, implied);
+        }
+        if !validated && !traits.iter().any(|t| t == "Permissive") {
+            traits.push(quote::format_ident!("Plain"));
+        }
+        out.extend(traits.iter().map(|trait_name| {
+            quote! {
+                impl #tt::#trait_name for #tag {}
+            }
+        }));
+        Ok(out)
+    }
+}
@@ -34,28 +34,56 @@
 
 #![deny(missing_docs)]
 
+mod domain;
+
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::quote;
+use syn::parse::Parse as _;
 use syn::DeriveInput;
 
 /// # Attributes
 ///
 /// - `#[implement(...)]`\
-///   List **individual traits** to implement.
+///   List **individual traits** to implement. Traits required by listed
+///   traits are implemented too (`Ord` implies `PartialOrd`, `Eq` and
+///   `PartialEq`; `Eq`, `PartialOrd` and `Hash` imply `PartialEq`;
+///   `Copy` implies `Clone`; `DerefMut` implies `Deref`).
 ///   Supported:
 ///    - `Default`
 ///    - `Clone`
 ///    - `Copy`
 ///    - `PartialEq`
+///    - `PartialEqInner` (`PartialEq<Inner>`)
+///    - `PartialOrdInner` (`PartialOrd<Inner>`, implies `PartialEqInner`)
 ///    - `Eq`
 ///    - `PartialOrd`
 ///    - `Ord`
 ///    - `Hash`
+///    - `Borrow` (`Borrow<Inner>` and `Borrow<str>` for `String`)
+///    - `Deref`
+///    - `DerefMut`
+///    - `AsRef` (`AsRef<Inner>`)
+///    - `AsMut` (`AsMut<Inner>`)
 ///    - `Add`
 ///    - `Sub`
 ///    - `Mul`
 ///    - `Div`
+///    - `Rem`
+///    - `AddAssign`
+///    - `SubAssign`
+///    - `MulAssign`
+///    - `DivAssign`
+///    - `Neg`
+///    - `Not`
+///    - `BitAnd`
+///    - `BitOr`
+///    - `BitXor`
+///    - `BitAndAssign`
+///    - `BitOrAssign`
+///    - `BitXorAssign`
+///    - `Shl`
+///    - `Shr`
 ///
 /// - `#[transparent]`\
 ///   Transparent implementations as if no wrapper at all.
@@ -63,19 +91,190 @@ use syn::DeriveInput;
 ///    - `Display`
 ///    - `Debug`
 ///    - `FromStr`
+///    - `AsRef` (`AsRef<U>` for all `U` that `Inner` implements `AsRef<U>` for)
+///    - `Index` (`Index<Idx>` for all `Idx` that `Inner` can be indexed by)
+///    - `IndexMut` (implies `Index`)
+///    - `IntoIterator` (for `TaggedType`, `&TaggedType` and `&mut TaggedType`)
+///    - `Iterator` (`by_ref()` to inner iterator, implies `IntoIterator`)
+///    - `FromIterator`
+///    - `Extend`
+///    - `Future`
+///    - `Error` (requires `Debug` and `Display`)
+///    - `LowerHex`
+///    - `UpperHex`
+///    - `Octal`
+///    - `Binary`
+///    - `LowerExp`
+///    - `UpperExp`
+///    - `FmtWrite` (`core::fmt::Write`)
+///    - `Read` (`std::io::Read`, requires `support_io` feature)
+///    - `Write` (`std::io::Write`, requires `support_io` feature)
+///    - `Seek` (`std::io::Seek`, requires `support_io` feature)
+///    - `TryFrom` (`try_new_from()` for all `S` that `Inner` implements `TryFrom<S>` for)
 ///
 /// - `#[capability(...)]`\
 ///   Enable additional capabilities for `TaggedType`.
 ///   Supported:
-///   - `inner_access` provides `into_inner()` and `inner()` functions.
+///   - `inner_access` provides `into_inner()`, `inner()` and `inspect()` functions.
+///   - `inner_mut` provides `inner_mut()`, `modify()`, `update()`, `replace()`, `take()`,
+///     `swap()` and `inspect_mut()` functions.
 ///   - `from_inner` provides implmentation `From<Inner>` for `TaggedType<Inner, Tag>`.
-///   - `value_map` provides `map(self, F)` and `try_map(self, F)` for `TaggedType<Inner, Tag>`.
+///   - `value_map` provides `map(self, F)`, `try_map(self, F)`, `with(self, F)`, `map_ref(&self, F)` and
+///     `try_map_ref(&self, F)` for `TaggedType<Inner, Tag>`.
 ///   - `cloned` provides `cloned(self)` for `TaggedType<&Inner, Tag>`.
+///   - `copied` provides `copied(self)` for `TaggedType<&Inner, Tag>` if `Inner` is `Copy`.
+///   - `as_ref` provides `as_ref(&self)` that returns `TaggedType<&Inner, Tag>`.
+///   - `as_deref` provides `as_deref(&self)` that returns `TaggedType<&Inner::Target, Tag>`.
+///   - `keyed_access` provides `get`, `contains_key`, `insert`, `len` and `is_empty`
+///     for `HashMap` and `BTreeMap` inner types.
+///   - `byte_conversion` provides `to_be_bytes`, `to_le_bytes`, `from_be_bytes` and
+///     `from_le_bytes` for integer inner types.
+///   - `from_utf8` provides `TryFrom<&[u8]>` for `String` / `&str` inner types and
+///     `from_utf8(&[u8])` for inner types that implement `FromStr`.
+///   - `from_os_str` provides `TryFrom<OsString>` / `TryFrom<&OsStr>` for `String` / `&str`
+///     inner types and `from_os_str(&OsStr)` for inner types that implement `FromStr`.
+///   - `stable_hash` provides `stable_hash64()` and `stable_hash128()` that do not depend
+///     on process and platform.
+///   - `ffi` provides `from_ffi(Inner)` and `into_ffi(self)` for crossing `extern "C"` boundary.
+///   - `step_range` provides `range(start, end)` and `range_inclusive(start, end)` iterators
+///     over tagged values.
+///   - `checked_arithmetic` provides `checked_add`, `checked_sub`, `checked_mul`,
+///     `checked_div` and `checked_rem` for integer inner types.
+///   - `saturating_arithmetic` provides `saturating_add`, `saturating_sub` and
+///     `saturating_mul` for integer inner types.
+///   - `wrapping_arithmetic` provides `wrapping_add`, `wrapping_sub` and
+///     `wrapping_mul` for integer inner types.
+///
+/// - `#[redacted(...)]`\
+///   Implementations that hide inner value.
+///   Supported:
+///    - `Serialize` serializes placeholder (`"***"`) instead of inner value.
+///      Cannot be combined with `#[transparent(Serialize)]`.
+///
+/// - `#[truncated(..., max_len = N)]`\
+///   Implementations that print at most `N` characters of inner value
+///   followed by ellipsis (`"…"`) if value is longer. Cannot be combined
+///   with transparent implementation of the same trait.
+///   Supported:
+///    - `Display` (`TruncatedDisplay`)
+///    - `Debug` (`TruncatedDebug`)
+///
+/// - `#[release_redacted]`, `#[release_redacted(placeholder = "...")]`\
+///   Implements `ReleaseRedacted`: `Display` and `Debug` print inner value
+///   in debug builds and placeholder (`"***"` by default) in release builds
+///   of the crate that derives the tag. Cannot be combined with
+///   `#[transparent(Display)]` / `#[transparent(Debug)]`.
+///
+/// - `#[display_with_unit(prefix = "...", suffix = "...")]`\
+///   Implements `TagUnit` and `Display` that prints inner value between
+///   prefix and suffix (`#[display_with_unit(suffix = " ms")]`,
+///   `#[display_with_unit(prefix = "€")]`). Both are optional.
+///
+/// - `#[default_value = ...]`\
+///   Implements `DefaultValue` (so `Default` of `TaggedType`) that
+///   returns given literal instead of `V::default()`:
+///    - unsuffixed integer (`#[default_value = 42]`, negative as
+///      `#[default_value(-1)]`) for primitive integer inner types, range
+///      is checked at compile time;
+///    - suffixed number (`#[default_value = 42u64]`), float, `bool`, `char`
+///      and string (`#[default_value = "unknown"]`) for inner types that
+///      implement `From` of the literal type.
+///
+///   `#[default_value(Type = expr)]` uses constant expression
+///   for inner types that implement `From<Type>`.
+///
+/// - `#[normalize(...)]`\
+///   Implements `Normalize<String>` that applies listed steps in order:
+///    - `trim`: removes leading and trailing whitespace.
+///    - `lowercase`
+///    - `uppercase`
+///
+///   `#[normalize]` without steps means that `Normalize` is implemented
+///   by hand. `try_new()`, `FromStr` and `Deserialize` of
+///   `#[transparent(...)]` normalize value (before validation). Tags
+///   with `#[normalize]` do not implement `Plain`; without
+///   `#[validate(...)]` they implement `NotValidated`, so `new()` and
+///   `From<V>` normalize value too.
+///
+/// - `#[validate(...)]`\
+///   Implements `Validate` (so `try_new()` of `TaggedType`) with
+///   `InvalidTagged` error that names tagged type and failed rule
+///   (`"Username: length must be in range 1..=64"`). Supported:
+///    - `length(min = N, max = N)`: number of characters of strings or
+///      number of elements of collections (`HasLength`).
+///    - `range(min = N, max = N)`: unsuffixed integer literals for
+///      primitive integer inner types, float literals for `f32` / `f64`.
+///
+///   Pattern rules (`regex = "..."`) are not supported: crate does not
+///   depend on regex engine, so such tags implement `Validate` by hand:
+///   ```rust,compile_fail
+///   #[derive(tagged_types_derive::Tag)]
+///   #[validate(regex = "^[a-z0-9_]+$")]
+///   enum UsernameTag {}
+///   ```
+///
+///   With `#[transparent(FromStr)]` parsed value is validated too
+///   (`ParseValidatedError` error), with `#[transparent(Deserialize)]`
+///   deserialized value is validated too (`serde::de::Error::custom`).
+///
+///   Tags without `#[validate(...)]` and `#[normalize]` implement
+///   `Plain` (so `NotValidated`). Tags with `#[validate(...)]` do not
+///   implement `NotValidated`, so `new` and capabilities that
+///   construct or modify value without checks are not available:
+///   ```rust,compile_fail,E0599
+///   type Port = tagged_types::TaggedType<u16, PortTag>;
+///   #[derive(tagged_types_derive::Tag)]
+///   #[validate(range(min = 1))]
+///   enum PortTag {}
+///
+///   let port = Port::new(0);
+///   ```
+///
+/// - `#[roundtrip_tests(..., samples(Inner: value, ...))]`\
+///   Generate test module `<tag_name>_roundtrip_tests` that checks round trip
+///   of `TaggedType<Inner, Tag>` built from each sample value by `try_new`
+///   (samples of `#[validate]` tags must be valid). Requires `PartialEq`
+///   for the tag. Supported:
+///    - `display_fromstr`: `parse(display(x)) == x`.
+///    - `serde_json`: `from_str(to_string(x)) == x` (requires `serde_json`
+///      dev-dependency).
 ///
 /// - `#[permissive]`\
 ///   Convenience mode that implents all supported capabilities, implentations and transparent
 ///   implementations of traits.
-#[proc_macro_derive(Tag, attributes(implement, transparent, capability, permissive))]
+///
+/// - `#[impl_snapshot(Inner: Trait, ...)]`\
+///   Compile-time assertion that `TaggedType<Inner, Tag>` implements exactly
+///   listed traits among `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`,
+///   `Hash`, `Default`, `Debug`, `Display`, `FromStr`, `Deref`, `From` (of `Inner`),
+///   `Add`, `Sub`, `Mul`, `Div` (with `Inner`), `Serialize` and `Deserialize`.
+///   Accidental widening (or narrowing) of the tag fails compilation:
+///   ```rust,compile_fail
+///   type Password = tagged_types::TaggedType<String, PasswordTag>;
+///   #[derive(tagged_types_derive::Tag)]
+///   #[implement(PartialEq, Clone)]
+///   #[transparent(Debug)]
+///   #[impl_snapshot(String: PartialEq)]
+///   enum PasswordTag {}
+///   ```
+#[proc_macro_derive(
+    Tag,
+    attributes(
+        implement,
+        transparent,
+        capability,
+        redacted,
+        truncated,
+        release_redacted,
+        display_with_unit,
+        default_value,
+        normalize,
+        validate,
+        impl_snapshot,
+        roundtrip_tests,
+        permissive
+    )
+)]
 pub fn derive_tag(input: TokenStream) -> TokenStream {
     let derive = syn::parse_macro_input!(input as syn::DeriveInput);
     let mut out = quote! {};
@@ -83,10 +282,53 @@ pub fn derive_tag(input: TokenStream) -> TokenStream {
         handle_capability(&derive, &mut out);
         handle_implement(&derive, &mut out);
         handle_transparent(&derive, &mut out);
+        handle_redacted(&derive, &mut out);
+        handle_truncated(&derive, &mut out);
+        handle_release_redacted(&derive, &mut out);
+        handle_display_with_unit(&derive, &mut out);
+        handle_default_value(&derive, &mut out);
+        handle_plain(&derive, &mut out);
     }
+    handle_normalize(&derive, &mut out);
+    handle_validate(&derive, &mut out);
+    handle_impl_snapshot(&derive, &mut out);
+    handle_roundtrip_tests(&derive, &mut out);
     TokenStream::from(out)
 }
 
+/// Declare family of related tagged types in one block.
+///
+/// Each module of the block becomes a Rust module that contains
+/// for each listed type a tag `<Name>Tag` and an alias
+/// `<Name> = TaggedType<Inner, <Name>Tag>`. Properties in square
+/// brackets accept the same keywords as `#[implement(...)]`,
+/// `#[transparent(...)]` and `#[capability(...)]` attributes of
+/// `#[derive(Tag)]`, `permissive` and `validated(...)` with the rules
+/// of `#[validate(...)]` (values of such types are created by
+/// `try_new`).
+///
+/// Example:
+/// ```rust
+/// tagged_types_derive::domain! {
+///     pub user {
+///         /// Unique identifier of the user.
+///         Id: u64 [permissive],
+///         Name: String [Clone, PartialEq, Display, from_inner],
+///         Login: String [Display, validated(length(min = 1, max = 32))],
+///     }
+/// }
+///
+/// let id = user::Id::new(1);
+/// let name: user::Name = String::from("admin").into();
+/// assert_eq!(format!("{id}: {name}"), "1: admin");
+/// assert!(user::Login::try_new(String::new()).is_err());
+/// ```
+#[proc_macro]
+pub fn domain(input: TokenStream) -> TokenStream {
+    let domain = syn::parse_macro_input!(input as domain::Domain);
+    TokenStream::from(domain.expand())
+}
+
 fn find_attr<'a>(derive: &'a DeriveInput, attr_name: &str) -> Option<&'a syn::Attribute> {
     derive
         .attrs
@@ -98,7 +340,9 @@ fn handle_permissive(derive: &DeriveInput, out: &mut proc_macro2::TokenStream) -
     if find_attr(derive, "permissive").is_none() {
         false
     } else {
-        if derive.attrs.len() > 1 {
+        if derive.attrs.iter().any(|attr| {
+            !attr.path().is_ident("permissive") && !attr.path().is_ident("impl_snapshot")
+        }) {
             out.extend(quote! {
                 compile_error!("permissive must be the only attribute in derive");
             });
@@ -118,39 +362,13 @@ fn handle_capability(derive: &DeriveInput, out: &mut proc_macro2::TokenStream) {
         let name = &derive.ident;
         let tt = crate_path();
         match impl_attr.parse_nested_meta(|meta| {
-            match meta.path.require_ident()?.to_string().as_str() {
-                "inner_access" => {
-                    out.extend(quote! {
-                        impl #tt::InnerAccess for #name {}
-                    });
-                    Ok(())
-                }
-                "from_inner" => {
-                    out.extend(quote! {
-                        impl #tt::FromInner for #name {}
-                    });
-                    Ok(())
-                }
-                "value_map" => {
-                    out.extend(quote! {
-                        impl #tt::ValueMap for #name {}
-                    });
-                    Ok(())
-                }
-                "cloned" => {
-                    out.extend(quote! {
-                        impl #tt::Cloned for #name {}
-                    });
-                    Ok(())
-                }
-                "as_ref" => {
-                    out.extend(quote! {
-                        impl #tt::AsRef for #name {}
-                    });
-                    Ok(())
-                }
-                v => Err(meta.error(format!("Don't know capability: {v}"))),
-            }
+            let capability = meta.path.require_ident()?.to_string();
+            let trait_name = capability_trait(&capability)
+                .ok_or_else(|| meta.error(format!("Don't know capability: {capability}")))?;
+            out.extend(quote! {
+                impl #tt::#trait_name for #name {}
+            });
+            Ok(())
         }) {
             Ok(()) => (),
             Err(e) => out.extend(e.into_compile_error()),
@@ -162,17 +380,89 @@ fn handle_implement(derive: &DeriveInput, out: &mut proc_macro2::TokenStream) {
     if let Some(impl_attr) = find_attr(derive, "implement") {
         let name = &derive.ident;
         let tt = crate_path();
+        let mut traits = Vec::new();
+        match impl_attr.parse_nested_meta(|meta| {
+            let implement = meta.path.require_ident()?.to_string();
+            if implement == "AsRef" {
+                out.extend(quote! {
+                    impl<V> #tt::AsRefTag<V, V> for #name {
+                        #[inline]
+                        fn as_ref(v: &V) -> &V {
+                            v
+                        }
+                    }
+                });
+                return Ok(());
+            }
+            let implied = implement_traits(&implement)
+                .ok_or_else(|| meta.error(format!("Don't know how to implement: {implement}")))?;
+            add_unique(&mut traits, implied);
+            Ok(())
+        }) {
+            Ok(()) => out.extend(traits.iter().map(|trait_name| {
+                quote! {
+                    impl #tt::#trait_name for #name {}
+                }
+            })),
+            Err(e) => out.extend(e.into_compile_error()),
+        }
+    }
+}
+
+/// Append `traits` that are not in `all` yet.
+fn add_unique(all: &mut Vec<syn::Ident>, traits: Vec<syn::Ident>) {
+    for t in traits {
+        if !all.contains(&t) {
+            all.push(t);
+        }
+    }
+}
+
+fn handle_transparent(derive: &DeriveInput, out: &mut proc_macro2::TokenStream) {
+    let name = &derive.ident;
+    let tt = crate_path();
+    if let Some(impl_attr) = find_attr(derive, "transparent") {
+        let mut traits = Vec::new();
+        match impl_attr.parse_nested_meta(|meta| {
+            let transparent = meta.path.require_ident()?.to_string();
+            let implied = transparent_traits(&transparent).ok_or_else(|| {
+                meta.error(format!("Don't know how to make {transparent} transparent"))
+            })?;
+            add_unique(&mut traits, implied);
+            Ok(())
+        }) {
+            Ok(()) => {
+                let hooks = ConstructionHooks::of(derive);
+                out.extend(traits.iter().map(|trait_name| {
+                    if trait_name == "TransparentFromStr" && hooks.any() {
+                        hooks.parse_tag(name)
+                    } else if trait_name == "TransparentDeserialize" && hooks.any() {
+                        hooks.deserialize_tag(name)
+                    } else {
+                        quote! {
+                            impl #tt::#trait_name for #name {}
+                        }
+                    }
+                }));
+            }
+            Err(e) => out.extend(e.into_compile_error()),
+        }
+    }
+}
+
+fn handle_redacted(derive: &DeriveInput, out: &mut proc_macro2::TokenStream) {
+    let name = &derive.ident;
+    let tt = crate_path();
+    if let Some(impl_attr) = find_attr(derive, "redacted") {
         match impl_attr.parse_nested_meta(|meta| {
             match meta.path.require_ident()?.to_string().as_str() {
-                s @ ("Default" | "Clone" | "Copy" | "PartialEq" | "Eq" | "PartialOrd" | "Ord"
-                | "Hash" | "Deref" | "Add" | "Sub" | "Mul" | "Div") => {
-                    let trait_name = quote::format_ident!("Implement{s}");
+                "Serialize" => {
                     out.extend(quote! {
-                        impl #tt::#trait_name for #name {}
+                        impl #tt::RedactedSerialize for #name {}
                     });
                     Ok(())
                 }
-                v => Err(meta.error(format!("Don't know how to implement: {v}"))),
+                v => Err(meta.error(format!("Don't know how to make {v} redacted"))),
             }
         }) {
             Ok(()) => (),
@@ -181,28 +471,888 @@ fn handle_implement(derive: &DeriveInput, out: &mut proc_macro2::TokenStream) {
     }
 }
 
-fn handle_transparent(derive: &DeriveInput, out: &mut proc_macro2::TokenStream) {
+fn handle_truncated(derive: &DeriveInput, out: &mut proc_macro2::TokenStream) {
     let name = &derive.ident;
     let tt = crate_path();
-    if let Some(impl_attr) = find_attr(derive, "transparent") {
-        match impl_attr.parse_nested_meta(|meta| {
+    if let Some(impl_attr) = find_attr(derive, "truncated") {
+        let mut max_len = None;
+        let mut traits = Vec::new();
+        let parsed = impl_attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("max_len") {
+                max_len = Some(meta.value()?.parse::<syn::Expr>()?);
+                return Ok(());
+            }
             match meta.path.require_ident()?.to_string().as_str() {
-                s @ ("Display" | "Debug" | "FromStr" | "Serialize" | "Deserialize") => {
-                    let trait_name = quote::format_ident!("Transparent{s}");
+                "Display" => traits.push(quote!(TruncatedDisplay)),
+                "Debug" => traits.push(quote!(TruncatedDebug)),
+                v => return Err(meta.error(format!("Don't know how to make {v} truncated"))),
+            }
+            Ok(())
+        });
+        match (parsed, max_len) {
+            (Ok(()), Some(max_len)) => {
+                for t in traits {
                     out.extend(quote! {
-                        impl #tt::#trait_name for #name {}
+                        impl #tt::#t for #name {
+                            const MAX_LEN: usize = #max_len;
+                        }
                     });
+                }
+            }
+            (Ok(()), None) => out.extend(
+                syn::Error::new_spanned(impl_attr, "truncated requires max_len = N")
+                    .into_compile_error(),
+            ),
+            (Err(e), _) => out.extend(e.into_compile_error()),
+        }
+    }
+}
+
+fn handle_release_redacted(derive: &DeriveInput, out: &mut proc_macro2::TokenStream) {
+    let name = &derive.ident;
+    let tt = crate_path();
+    if let Some(impl_attr) = find_attr(derive, "release_redacted") {
+        let mut placeholder = None;
+        if !matches!(impl_attr.meta, syn::Meta::Path(_)) {
+            if let Err(e) = impl_attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("placeholder") {
+                    let value = meta.value()?.parse::<syn::LitStr>()?;
+                    placeholder = Some(quote!(const PLACEHOLDER: &'static str = #value;));
                     Ok(())
+                } else {
+                    Err(meta.error("release_redacted supports only placeholder = \"...\""))
                 }
-                v => Err(meta.error(format!("Don't know how to make {v} transparent"))),
+            }) {
+                out.extend(e.into_compile_error());
+                return;
+            }
+        }
+        // Evaluated in the crate of the tag, so its build profile
+        // decides whether value is revealed.
+        out.extend(quote! {
+            impl #tt::ReleaseRedacted for #name {
+                const REVEAL: bool = ::core::cfg!(debug_assertions);
+                #placeholder
             }
+        });
+    }
+}
+
+fn handle_display_with_unit(derive: &DeriveInput, out: &mut proc_macro2::TokenStream) {
+    let name = &derive.ident;
+    let tt = crate_path();
+    if let Some(impl_attr) = find_attr(derive, "display_with_unit") {
+        let mut consts = quote! {};
+        match impl_attr.parse_nested_meta(|meta| {
+            let value = meta.value()?.parse::<syn::LitStr>()?;
+            match meta.path.require_ident()?.to_string().as_str() {
+                "prefix" => consts.extend(quote! { const PREFIX: &'static str = #value; }),
+                "suffix" => consts.extend(quote! { const SUFFIX: &'static str = #value; }),
+                v => return Err(meta.error(format!("Unknown unit property {v}"))),
+            }
+            Ok(())
         }) {
-            Ok(()) => (),
+            Ok(()) => out.extend(quote! {
+                impl #tt::TagUnit for #name {
+                    #consts
+                }
+
+                impl<V: ::core::fmt::Display + ?Sized> #tt::DisplayTag<V> for #name {
+                    #[inline]
+                    fn fmt(v: &V, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        <Self as #tt::TagUnit>::fmt_with_unit(v, f)
+                    }
+                }
+            }),
             Err(e) => out.extend(e.into_compile_error()),
         }
     }
 }
 
+fn handle_default_value(derive: &DeriveInput, out: &mut proc_macro2::TokenStream) {
+    let name = &derive.ident;
+    let tt = crate_path();
+    if let Some(impl_attr) = find_attr(derive, "default_value") {
+        let parsed = match &impl_attr.meta {
+            syn::Meta::NameValue(nv) => Ok(DefaultValueArg::Literal(nv.value.clone())),
+            _ => impl_attr.parse_args::<DefaultValueArg>(),
+        };
+        let (bound, value) = match parsed.and_then(|arg| arg.expand(impl_attr)) {
+            Ok(v) => v,
+            Err(e) => {
+                out.extend(e.into_compile_error());
+                return;
+            }
+        };
+        out.extend(quote! {
+            impl<V: #bound> #tt::DefaultValue<V> for #name {
+                #[inline]
+                fn default_value() -> V {
+                    #value
+                }
+            }
+        });
+    }
+}
+
+fn handle_normalize(derive: &DeriveInput, out: &mut proc_macro2::TokenStream) {
+    let name = &derive.ident;
+    let tt = crate_path();
+    let Some(impl_attr) = find_attr(derive, "normalize") else {
+        if find_attr(derive, "validate").is_some() {
+            // Validate requires Normalize, value is kept as is.
+            out.extend(quote! {
+                impl<V: ?Sized> #tt::Normalize<V> for #name {}
+            });
+        }
+        return;
+    };
+    if find_attr(derive, "validate").is_none() {
+        // Normalizing tag is not Plain, so `new` normalizes value.
+        out.extend(quote! {
+            impl #tt::NotValidated for #name {}
+        });
+    }
+    if matches!(impl_attr.meta, syn::Meta::Path(_)) {
+        // Normalize is implemented by hand.
+        return;
+    }
+    let mut steps = Vec::new();
+    match impl_attr.parse_nested_meta(|meta| {
+        steps.push(match meta.path.require_ident()?.to_string().as_str() {
+            "trim" => quote! {
+                let v = if v.trim().len() == v.len() {
+                    v
+                } else {
+                    ::std::string::String::from(v.trim())
+                };
+            },
+            "lowercase" => quote!(let v = v.to_lowercase();),
+            "uppercase" => quote!(let v = v.to_uppercase();),
+            v => return Err(meta.error(format!("Don't know how to normalize {v}"))),
+        });
+        Ok(())
+    }) {
+        Ok(()) => out.extend(quote! {
+            impl #tt::Normalize<::std::string::String> for #name {
+                #[inline]
+                fn normalize(v: ::std::string::String) -> ::std::string::String {
+                    #(#steps)*
+                    v
+                }
+            }
+        }),
+        Err(e) => out.extend(e.into_compile_error()),
+    }
+}
+
+fn handle_plain(derive: &DeriveInput, out: &mut proc_macro2::TokenStream) {
+    if find_attr(derive, "validate").is_none() && find_attr(derive, "normalize").is_none() {
+        let name = &derive.ident;
+        let tt = crate_path();
+        out.extend(quote! {
+            impl #tt::Plain for #name {}
+        });
+    }
+}
+
+fn handle_validate(derive: &DeriveInput, out: &mut proc_macro2::TokenStream) {
+    if let Some(impl_attr) = find_attr(derive, "validate") {
+        match impl_attr
+            .meta
+            .require_list()
+            .and_then(|rules| validate_impl(&derive.ident, rules))
+        {
+            Ok(v) => out.extend(v),
+            Err(e) => out.extend(e.into_compile_error()),
+        }
+    }
+}
+
+/// `Validate` of `length(...)` / `range(...)` rules of `#[validate(...)]`
+/// attribute or `validated(...)` property of `domain!`.
+fn validate_impl(
+    name: &syn::Ident,
+    rules: &syn::MetaList,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let tt = crate_path();
+    let mut bounds = Vec::new();
+    let mut checks = Vec::new();
+    rules.parse_nested_meta(|meta| {
+        let rule = meta.path.require_ident()?.to_string();
+        let (bound, cond, text) = match rule.as_str() {
+            "length" => ValidateBounds::parse(&meta)?.expand_length(),
+            "range" => ValidateBounds::parse(&meta)?.expand_range(&meta)?,
+            "regex" | "pattern" => {
+                return Err(meta.error(
+                    "pattern rules are not supported, implement `Validate` of the tag by hand",
+                ))
+            }
+            v => return Err(meta.error(format!("Don't know how to validate {v}"))),
+        };
+        bounds.push(bound);
+        checks.push(quote! {
+            if !#cond {
+                return ::core::result::Result::Err(#tt::InvalidTagged::new(#text));
+            }
+        });
+        Ok(())
+    })?;
+    Ok(quote! {
+        impl<V: ?Sized #(+ #bounds)*> #tt::Validate<V> for #name
+        where
+            Self: #tt::Normalize<V>,
+        {
+            type Error = #tt::InvalidTagged<Self>;
+            #[inline]
+            fn validate(v: &V) -> ::core::result::Result<(), Self::Error> {
+                #(#checks)*
+                ::core::result::Result::Ok(())
+            }
+        }
+    })
+}
+
+fn handle_impl_snapshot(derive: &DeriveInput, out: &mut proc_macro2::TokenStream) {
+    let name = &derive.ident;
+    if let Some(impl_attr) = find_attr(derive, "impl_snapshot") {
+        let parsed = impl_attr.parse_args_with(|input: syn::parse::ParseStream<'_>| {
+            let inner: syn::Type = input.parse()?;
+            input.parse::<syn::Token![:]>()?;
+            let traits = input.parse_terminated(syn::Ident::parse, syn::Token![,])?;
+            Ok((inner, traits))
+        });
+        let (inner, traits) = match parsed {
+            Ok(v) => v,
+            Err(e) => {
+                out.extend(e.into_compile_error());
+                return;
+            }
+        };
+        for t in &traits {
+            if snapshot_trait(&t.to_string(), &inner).is_none() {
+                out.extend(
+                    syn::Error::new_spanned(t, format!("Don't know how to snapshot: {t}"))
+                        .into_compile_error(),
+                );
+                return;
+            }
+        }
+        let tt = crate_path();
+        let tagged = quote!(#tt::TaggedType<#inner, #name>);
+        for t in SNAPSHOT_TRAITS {
+            let Some(path) = snapshot_trait(t, &inner) else {
+                continue;
+            };
+            if traits.iter().any(|listed| listed == t) {
+                out.extend(quote! {
+                    const _: fn() = || {
+                        fn implemented<T: ?Sized + #path>() {}
+                        implemented::<#tagged>();
+                    };
+                });
+            } else {
+                // Call is ambiguous if type implements the trait.
+                out.extend(quote! {
+                    const _: fn() = || {
+                        trait AmbiguousIfImplemented<A> {
+                            fn some_item() {}
+                        }
+                        impl<T: ?Sized> AmbiguousIfImplemented<()> for T {}
+                        impl<T: ?Sized + #path> AmbiguousIfImplemented<u8> for T {}
+                        let _ = <#tagged as AmbiguousIfImplemented<_>>::some_item;
+                    };
+                });
+            }
+        }
+    }
+}
+
+fn handle_roundtrip_tests(derive: &DeriveInput, out: &mut proc_macro2::TokenStream) {
+    let name = &derive.ident;
+    let tt = crate_path();
+    if let Some(impl_attr) = find_attr(derive, "roundtrip_tests") {
+        let mut samples = None;
+        let mut checks = Vec::new();
+        let parsed = impl_attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("samples") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let inner: syn::Type = content.parse()?;
+                content.parse::<syn::Token![:]>()?;
+                let values = content.parse_terminated(syn::Expr::parse, syn::Token![,])?;
+                samples = Some((inner, values.into_iter().collect::<Vec<_>>()));
+                return Ok(());
+            }
+            let check = meta.path.require_ident()?;
+            match check.to_string().as_str() {
+                "display_fromstr" => checks.push(quote! {
+                    #[test]
+                    fn display_fromstr() {
+                        for x in samples() {
+                            let s = x.to_string();
+                            assert!(
+                                s.parse::<Tagged>().is_ok_and(|parsed| parsed == x),
+                                "display / from_str round trip of {:?} failed",
+                                s
+                            );
+                        }
+                    }
+                }),
+                "serde_json" => checks.push(quote! {
+                    #[test]
+                    fn serde_json() {
+                        for x in samples() {
+                            let json = ::serde_json::to_string(&x);
+                            assert!(
+                                json.as_ref().is_ok_and(|json| {
+                                    ::serde_json::from_str::<Tagged>(json).is_ok_and(|parsed| parsed == x)
+                                }),
+                                "serde_json round trip of {:?} failed",
+                                json.ok()
+                            );
+                        }
+                    }
+                }),
+                v => return Err(meta.error(format!("Don't know round trip: {v}"))),
+            }
+            Ok(())
+        });
+        match (parsed, samples) {
+            (Ok(()), Some((inner, values))) => {
+                let module =
+                    quote::format_ident!("{}_roundtrip_tests", snake_case(&name.to_string()));
+                let count = values.len();
+                out.extend(quote! {
+                    #[cfg(test)]
+                    mod #module {
+                        use super::*;
+
+                        type Tagged = #tt::TaggedType<#inner, #name>;
+
+                        // Samples are validated (and normalized) like any
+                        // other value of the tag.
+                        fn samples() -> ::std::vec::Vec<Tagged> {
+                            let values: [#inner; #count] = [#(#values),*];
+                            let samples: ::std::vec::Vec<Tagged> =
+                                ::core::iter::IntoIterator::into_iter(values)
+                                    .filter_map(|v| Tagged::try_new(v).ok())
+                                    .collect();
+                            assert_eq!(
+                                samples.len(),
+                                #count,
+                                "roundtrip_tests: every sample must pass validation of the tag"
+                            );
+                            samples
+                        }
+
+                        #(#checks)*
+                    }
+                });
+            }
+            (Ok(()), None) => out.extend(
+                syn::Error::new_spanned(
+                    impl_attr,
+                    "roundtrip_tests requires samples(Inner: value, ...)",
+                )
+                .into_compile_error(),
+            ),
+            (Err(e), _) => out.extend(e.into_compile_error()),
+        }
+    }
+}
+
+/// Convert `CamelCase` identifier to `snake_case`.
+fn snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Traits checked by `#[impl_snapshot(...)]`.
+const SNAPSHOT_TRAITS: &[&str] = &[
+    "Clone",
+    "Copy",
+    "PartialEq",
+    "Eq",
+    "PartialOrd",
+    "Ord",
+    "Hash",
+    "Default",
+    "Debug",
+    "Display",
+    "FromStr",
+    "Deref",
+    "From",
+    "Add",
+    "Sub",
+    "Mul",
+    "Div",
+    "Serialize",
+    "Deserialize",
+];
+
+/// Path of the trait of `#[impl_snapshot(...)]`.
+fn snapshot_trait(name: &str, inner: &syn::Type) -> Option<proc_macro2::TokenStream> {
+    let tt = crate_path();
+    Some(match name {
+        "Clone" => quote!(::core::clone::Clone),
+        "Copy" => quote!(::core::marker::Copy),
+        "PartialEq" => quote!(::core::cmp::PartialEq),
+        "Eq" => quote!(::core::cmp::Eq),
+        "PartialOrd" => quote!(::core::cmp::PartialOrd),
+        "Ord" => quote!(::core::cmp::Ord),
+        "Hash" => quote!(::core::hash::Hash),
+        "Default" => quote!(::core::default::Default),
+        "Debug" => quote!(::core::fmt::Debug),
+        "Display" => quote!(::core::fmt::Display),
+        "FromStr" => quote!(::core::str::FromStr),
+        "Deref" => quote!(::core::ops::Deref),
+        "From" => quote!(::core::convert::From<#inner>),
+        "Add" => quote!(::core::ops::Add<#inner>),
+        "Sub" => quote!(::core::ops::Sub<#inner>),
+        "Mul" => quote!(::core::ops::Mul<#inner>),
+        "Div" => quote!(::core::ops::Div<#inner>),
+        "Serialize" => quote!(#tt::__snapshot::Serialize),
+        "Deserialize" => quote!(#tt::__snapshot::Deserialize),
+        _ => return None,
+    })
+}
+
+/// Argument of `#[default_value = lit]`, `#[default_value(lit)]` or
+/// `#[default_value(Type = expr)]`.
+enum DefaultValueArg {
+    Literal(syn::Expr),
+    Typed(Box<syn::Type>, syn::Expr),
+}
+
+impl syn::parse::Parse for DefaultValueArg {
+    fn parse(input: syn::parse::ParseStream<'_>) -> syn::Result<Self> {
+        let fork = input.fork();
+        if fork.parse::<syn::Type>().is_ok() && fork.peek(syn::Token![=]) {
+            let ty: syn::Type = input.parse()?;
+            input.parse::<syn::Token![=]>()?;
+            return Ok(Self::Typed(Box::new(ty), input.parse()?));
+        }
+        Ok(Self::Literal(input.parse()?))
+    }
+}
+
+impl DefaultValueArg {
+    /// Bound of inner type and expression that builds inner value.
+    fn expand(
+        self,
+        attr: &syn::Attribute,
+    ) -> syn::Result<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
+        let from = |ty: proc_macro2::TokenStream, expr: &syn::Expr| {
+            (
+                quote!(::core::convert::From<#ty>),
+                quote!(<V as ::core::convert::From<#ty>>::from(#expr)),
+            )
+        };
+        let expr = match self {
+            Self::Typed(ty, expr) => return Ok(from(quote!(#ty), &expr)),
+            Self::Literal(expr) => expr,
+        };
+        let (neg, lit) = match &expr {
+            syn::Expr::Lit(lit) => (false, &lit.lit),
+            syn::Expr::Unary(syn::ExprUnary {
+                op: syn::UnOp::Neg(_),
+                expr: inner,
+                ..
+            }) => match &**inner {
+                syn::Expr::Lit(lit) => (true, &lit.lit),
+                _ => return Err(Self::not_literal(attr)),
+            },
+            _ => return Err(Self::not_literal(attr)),
+        };
+        let tt = crate_path();
+        match lit {
+            syn::Lit::Int(int) if int.suffix().is_empty() => Ok((
+                quote!(#tt::PrimitiveInteger),
+                quote! {
+                    const {
+                        assert!(
+                            <V as #tt::PrimitiveInteger>::MIN_I128 <= #expr
+                                && #expr <= <V as #tt::PrimitiveInteger>::MAX_I128,
+                            "default_value is out of range of inner type"
+                        );
+                    }
+                    match <V as #tt::PrimitiveInteger>::from_i128(#expr) {
+                        Some(v) => v,
+                        None => unreachable!(),
+                    }
+                },
+            )),
+            syn::Lit::Int(int) => {
+                let ty = quote::format_ident!("{}", int.suffix());
+                Ok(from(quote!(#ty), &expr))
+            }
+            syn::Lit::Float(float) if float.suffix().is_empty() => Ok(from(quote!(f64), &expr)),
+            syn::Lit::Float(float) => {
+                let ty = quote::format_ident!("{}", float.suffix());
+                Ok(from(quote!(#ty), &expr))
+            }
+            syn::Lit::Str(_) if !neg => Ok(from(quote!(&'static str), &expr)),
+            syn::Lit::Bool(_) if !neg => Ok(from(quote!(bool), &expr)),
+            syn::Lit::Char(_) if !neg => Ok(from(quote!(char), &expr)),
+            _ => Err(Self::not_literal(attr)),
+        }
+    }
+
+    fn not_literal(attr: &syn::Attribute) -> syn::Error {
+        syn::Error::new_spanned(
+            attr,
+            "default_value expects literal, use #[default_value(Type = expr)] for expressions",
+        )
+    }
+}
+
+/// `#[normalize(...)]` and `#[validate(...)]` attributes that are
+/// applied to values built by `FromStr` and `Deserialize`.
+struct ConstructionHooks {
+    normalize: bool,
+    validate: bool,
+}
+
+impl ConstructionHooks {
+    fn of(derive: &DeriveInput) -> Self {
+        Self {
+            normalize: find_attr(derive, "normalize").is_some(),
+            validate: find_attr(derive, "validate").is_some(),
+        }
+    }
+
+    const fn any(&self) -> bool {
+        self.normalize || self.validate
+    }
+
+    /// Bounds and statement that normalizes `v`.
+    fn normalize(&self) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        let tt = crate_path();
+        if self.normalize {
+            (
+                quote!(Self: #tt::Normalize<V>,),
+                quote!(let v = <Self as #tt::Normalize<V>>::normalize(v);),
+            )
+        } else {
+            (quote!(), quote!())
+        }
+    }
+
+    /// `ParseTag` that parses, normalizes and validates inner value.
+    fn parse_tag(&self, name: &syn::Ident) -> proc_macro2::TokenStream {
+        let tt = crate_path();
+        let (normalize_bound, normalize) = self.normalize();
+        let (validate_bound, err, parse_err, validate) = if self.validate {
+            (
+                quote!(Self: #tt::Validate<V>,),
+                quote! {
+                    #tt::ParseValidatedError<
+                        <V as ::core::str::FromStr>::Err,
+                        <Self as #tt::Validate<V>>::Error,
+                    >
+                },
+                quote!(.map_err(#tt::ParseValidatedError::Parse)),
+                quote! {
+                    <Self as #tt::Validate<V>>::validate(&v)
+                        .map_err(#tt::ParseValidatedError::Invalid)?;
+                },
+            )
+        } else {
+            (
+                quote!(),
+                quote!(<V as ::core::str::FromStr>::Err),
+                quote!(),
+                quote!(),
+            )
+        };
+        quote! {
+            impl<V: ::core::str::FromStr> #tt::ParseTag<V> for #name
+            where
+                #normalize_bound
+                #validate_bound
+            {
+                type Err = #err;
+                #[inline]
+                fn parse(s: &str) -> ::core::result::Result<V, Self::Err> {
+                    let v = <V as ::core::str::FromStr>::from_str(s)#parse_err?;
+                    #normalize
+                    #validate
+                    ::core::result::Result::Ok(v)
+                }
+            }
+        }
+    }
+
+    /// `DeserializeTag` that deserializes, normalizes and validates
+    /// inner value.
+    fn deserialize_tag(&self, name: &syn::Ident) -> proc_macro2::TokenStream {
+        let tt = crate_path();
+        let (normalize_bound, normalize) = self.normalize();
+        let (validate_bound, validate) = if self.validate {
+            (
+                quote! {
+                    Self: #tt::Validate<V>,
+                    <Self as #tt::Validate<V>>::Error: ::core::fmt::Display,
+                },
+                quote! {
+                    <Self as #tt::Validate<V>>::validate(&v)
+                        .map_err(<D::Error as #tt::__serde::de::Error>::custom)?;
+                },
+            )
+        } else {
+            (quote!(), quote!())
+        };
+        quote! {
+            impl<'de, V: #tt::__serde::Deserialize<'de>> #tt::DeserializeTag<'de, V> for #name
+            where
+                #normalize_bound
+                #validate_bound
+            {
+                #[inline]
+                fn deserialize<D: #tt::__serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> ::core::result::Result<V, D::Error> {
+                    let v = <V as #tt::__serde::Deserialize<'de>>::deserialize(deserializer)?;
+                    #normalize
+                    #validate
+                    ::core::result::Result::Ok(v)
+                }
+            }
+        }
+    }
+}
+
+/// `min = ...` / `max = ...` arguments of `length(...)` and `range(...)`
+/// rules of `#[validate(...)]`.
+struct ValidateBounds {
+    min: Option<syn::Expr>,
+    max: Option<syn::Expr>,
+}
+
+impl ValidateBounds {
+    fn parse(meta: &syn::meta::ParseNestedMeta<'_>) -> syn::Result<Self> {
+        let mut bounds = Self {
+            min: None,
+            max: None,
+        };
+        meta.parse_nested_meta(|arg| {
+            if arg.path.is_ident("min") {
+                bounds.min = Some(arg.value()?.parse()?);
+            } else if arg.path.is_ident("max") {
+                bounds.max = Some(arg.value()?.parse()?);
+            } else {
+                return Err(arg.error("expected min = ... or max = ..."));
+            }
+            Ok(())
+        })?;
+        Ok(bounds)
+    }
+
+    /// Description of the bounds (`"in range 1..=64"`).
+    fn text(&self) -> String {
+        let text = |e: &syn::Expr| quote!(#e).to_string().replace(' ', "");
+        match (&self.min, &self.max) {
+            (Some(min), Some(max)) => format!("in range {}..={}", text(min), text(max)),
+            (Some(min), None) => format!("at least {}", text(min)),
+            (None, Some(max)) => format!("at most {}", text(max)),
+            (None, None) => String::from("anything"),
+        }
+    }
+
+    fn expand_length(self) -> (proc_macro2::TokenStream, proc_macro2::TokenStream, String) {
+        let tt = crate_path();
+        let text = format!("length must be {}", self.text());
+        let range = range_expr(self.min, self.max);
+        (
+            quote!(#tt::HasLength),
+            quote! {{
+                let len = <V as #tt::HasLength>::length(v);
+                ::core::ops::RangeBounds::contains(&(#range), &len)
+            }},
+            text,
+        )
+    }
+
+    /// Range of unsuffixed integer literals is checked for primitive
+    /// integer inner types, range with float literals for inner types
+    /// that convert to `f64`.
+    fn expand_range(
+        self,
+        meta: &syn::meta::ParseNestedMeta<'_>,
+    ) -> syn::Result<(proc_macro2::TokenStream, proc_macro2::TokenStream, String)> {
+        let tt = crate_path();
+        let text = format!("must be {}", self.text());
+        let literal = |e: &syn::Expr| -> syn::Result<(bool, syn::Lit)> {
+            match e {
+                syn::Expr::Lit(lit) => Ok((false, lit.lit.clone())),
+                syn::Expr::Unary(syn::ExprUnary {
+                    op: syn::UnOp::Neg(_),
+                    expr,
+                    ..
+                }) => match &**expr {
+                    syn::Expr::Lit(lit) => Ok((true, lit.lit.clone())),
+                    _ => Err(meta.error("range expects integer or float literals")),
+                },
+                _ => Err(meta.error("range expects integer or float literals")),
+            }
+        };
+        let min = self.min.as_ref().map(literal).transpose()?;
+        let max = self.max.as_ref().map(literal).transpose()?;
+        let is_float = [&min, &max]
+            .iter()
+            .any(|bound| matches!(bound, Some((_, syn::Lit::Float(_)))));
+        let value = |(neg, lit): (bool, syn::Lit)| {
+            let value = match &lit {
+                syn::Lit::Int(int) if int.suffix().is_empty() && !is_float => {
+                    let int = syn::LitInt::new(&format!("{}i128", int.base10_digits()), int.span());
+                    quote!(#int)
+                }
+                syn::Lit::Int(int) if int.suffix().is_empty() => {
+                    let float =
+                        syn::LitFloat::new(&format!("{}.0f64", int.base10_digits()), int.span());
+                    quote!(#float)
+                }
+                syn::Lit::Float(float) if float.suffix().is_empty() => {
+                    let float = syn::LitFloat::new(&format!("{float}f64"), float.span());
+                    quote!(#float)
+                }
+                _ => return Err(meta.error("range expects unsuffixed integer or float literals")),
+            };
+            Ok(if neg { quote!(-#value) } else { value })
+        };
+        let above = max.is_none();
+        let range = range_expr(min.map(value).transpose()?, max.map(value).transpose()?);
+        if is_float {
+            Ok((
+                quote!(::core::marker::Copy + ::core::convert::Into<f64>),
+                quote! {{
+                    let x: f64 = ::core::convert::Into::into(*v);
+                    ::core::ops::RangeBounds::contains(&(#range), &x)
+                }},
+                text,
+            ))
+        } else {
+            Ok((
+                quote!(#tt::PrimitiveInteger),
+                quote! {
+                    match <V as #tt::PrimitiveInteger>::to_i128(*v) {
+                        ::core::option::Option::Some(x) => {
+                            ::core::ops::RangeBounds::contains(&(#range), &x)
+                        }
+                        ::core::option::Option::None => #above,
+                    }
+                },
+                text,
+            ))
+        }
+    }
+}
+
+/// Range expression (`min..=max`, `min..`, `..=max` or `..`) of
+/// optional bounds.
+fn range_expr<B: quote::ToTokens>(min: Option<B>, max: Option<B>) -> proc_macro2::TokenStream {
+    match (min, max) {
+        (Some(min), Some(max)) => quote!(#min..=#max),
+        (Some(min), None) => quote!(#min..),
+        (None, Some(max)) => quote!(..=#max),
+        (None, None) => quote!(..),
+    }
+}
+
+/// Marker trait that enables capability of `#[capability(...)]`.
+fn capability_trait(capability: &str) -> Option<syn::Ident> {
+    let trait_name = match capability {
+        "inner_access" => "InnerAccess",
+        "inner_mut" => "MutInnerAccess",
+        "from_inner" => "FromInner",
+        "value_map" => "ValueMap",
+        "cloned" => "Cloned",
+        "copied" => "Copied",
+        "as_ref" => "AsRef",
+        "as_deref" => "AsDeref",
+        "keyed_access" => "KeyedAccess",
+        "byte_conversion" => "ByteConversion",
+        "from_utf8" => "FromUtf8",
+        "from_os_str" => "FromOsStr",
+        "stable_hash" => "StableHash",
+        "ffi" => "Ffi",
+        "step_range" => "StepRange",
+        "checked_arithmetic" => "CheckedArithmetic",
+        "saturating_arithmetic" => "SaturatingArithmetic",
+        "wrapping_arithmetic" => "WrappingArithmetic",
+        _ => return None,
+    };
+    Some(quote::format_ident!("{trait_name}"))
+}
+
+/// Marker trait that enables trait of `#[implement(...)]`.
+fn implement_trait(implement: &str) -> Option<syn::Ident> {
+    match implement {
+        "Default" | "Clone" | "Copy" | "PartialEq" | "PartialEqInner" | "PartialOrdInner"
+        | "Eq" | "PartialOrd" | "Ord" | "Hash" | "Borrow" | "Deref" | "DerefMut" | "AsMut"
+        | "Add" | "Sub" | "Mul" | "Div" | "Rem" | "AddAssign" | "SubAssign" | "MulAssign"
+        | "DivAssign" | "Neg" | "Not" | "BitAnd" | "BitOr" | "BitXor" | "BitAndAssign"
+        | "BitOrAssign" | "BitXorAssign" | "Shl" | "Shr" => {
+            Some(quote::format_ident!("Implement{implement}"))
+        }
+        _ => None,
+    }
+}
+
+/// Marker traits of `#[implement(...)]` together with markers it
+/// requires (for example `Ord` requires `PartialOrd`, `Eq` and `PartialEq`).
+fn implement_traits(implement: &str) -> Option<Vec<syn::Ident>> {
+    let prerequisites: &[&str] = match implement {
+        "Eq" | "PartialOrd" | "Hash" => &["PartialEq"],
+        "Ord" => &["PartialOrd", "Eq", "PartialEq"],
+        "Copy" => &["Clone"],
+        "DerefMut" => &["Deref"],
+        "PartialOrdInner" => &["PartialEqInner"],
+        _ => &[],
+    };
+    let mut traits = vec![implement_trait(implement)?];
+    traits.extend(prerequisites.iter().filter_map(|p| implement_trait(p)));
+    Some(traits)
+}
+
+/// Marker trait that enables trait of `#[transparent(...)]`.
+fn transparent_trait(transparent: &str) -> Option<syn::Ident> {
+    match transparent {
+        "Display" | "Debug" | "FromStr" | "AsRef" | "Index" | "IndexMut" | "IntoIterator"
+        | "Iterator" | "FromIterator" | "Extend" | "Future" | "Error" | "LowerHex" | "UpperHex"
+        | "Octal" | "Binary" | "LowerExp" | "UpperExp" | "FmtWrite" | "Read" | "Write" | "Seek"
+        | "TryFrom" | "Serialize" | "Deserialize" => {
+            Some(quote::format_ident!("Transparent{transparent}"))
+        }
+        _ => None,
+    }
+}
+
+/// Marker traits of `#[transparent(...)]` together with markers it
+/// requires (`IndexMut` requires `Index`, `Iterator` requires
+/// `IntoIterator`).
+fn transparent_traits(transparent: &str) -> Option<Vec<syn::Ident>> {
+    let prerequisites: &[&str] = match transparent {
+        "IndexMut" => &["Index"],
+        "Iterator" => &["IntoIterator"],
+        _ => &[],
+    };
+    let mut traits = vec![transparent_trait(transparent)?];
+    traits.extend(prerequisites.iter().filter_map(|p| transparent_trait(p)));
+    Some(traits)
+}
+
 fn crate_path() -> syn::Path {
     use proc_macro_crate::{crate_name, FoundCrate};
     match crate_name("tagged-types") {
@@ -0,0 +1,274 @@
+// SPDX-License-Identifier: MIT
+
+use crate::AsRef;
+use crate::Cloned;
+use crate::Copied;
+use crate::ImplementClone;
+use crate::ImplementCopy;
+use crate::ImplementDeref;
+use crate::ImplementEq;
+use crate::ImplementHash;
+use crate::ImplementOrd;
+use crate::ImplementPartialEq;
+use crate::ImplementPartialOrd;
+use crate::InnerAccess;
+use crate::InvalidTagged;
+use crate::Normalize;
+use crate::PrimitiveInteger;
+use crate::TaggedType;
+use crate::TransparentBinary;
+use crate::TransparentDebug;
+use crate::TransparentDisplay;
+use crate::TransparentLowerHex;
+use crate::TransparentOctal;
+#[cfg(feature = "support_serde")]
+use crate::TransparentSerialize;
+use crate::TransparentUpperHex;
+use crate::Validate;
+use core::convert::Infallible;
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::marker::PhantomData;
+
+/// Tag that restricts integer inner value of `TaggedType` to
+/// `MIN..=MAX` range.
+///
+/// `Tag` is the tag of the type. `Bounded<Tag, MIN, MAX>` has
+/// capabilities of `Tag` that cannot break the range: `Clone`, `Copy`,
+/// comparison, `Hash`, `Debug`, `Display`, `Serialize`, `Deref`, inner
+/// access, `as_ref`, `cloned` and `copied`. Capabilities that construct value
+/// without range check (`FromStr`, `Deserialize`, `From<V>`, `map`,
+/// `Default`, arithmetic operators, ...) are not inherited.
+///
+/// Values are created by `new_checked` / `new_clamped` (or `try_new`
+/// of `Validate`) and modified by `checked_*` / `saturating_*`
+/// arithmetic that keep value in range. `Bounded` does not implement
+/// `NotValidated`, so `TaggedType::new` is not available:
+/// ```rust,compile_fail,E0599
+/// use tagged_types::{Bounded, TaggedType};
+/// pub type Percent = TaggedType<u8, Bounded<PercentTag, 0, 100>>;
+/// pub enum PercentTag {}
+///
+/// let p = Percent::new(101);
+/// ```
+///
+/// Range must be representable by the inner type. Otherwise
+/// usage of `new_checked` / `new_clamped` does not compile.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{Bounded, TaggedType, InnerAccess, ImplementClone, ImplementCopy};
+/// pub type Percent = TaggedType<u8, Bounded<PercentTag, 0, 100>>;
+/// pub enum PercentTag {}
+/// impl InnerAccess for PercentTag {};
+/// impl ImplementClone for PercentTag {};
+/// impl ImplementCopy for PercentTag {};
+///
+/// assert!(Percent::new_checked(101).is_none());
+/// let p = Percent::new_clamped(120);
+/// assert_eq!(*p.inner(), 100);
+/// assert_eq!(*p.saturating_sub(30).inner(), 70);
+/// assert!(p.checked_add(1).is_none());
+/// ```
+pub struct Bounded<Tag, const MIN: i128, const MAX: i128> {
+    _marker: PhantomData<Tag>,
+    _never: Infallible,
+}
+
+impl<V: PrimitiveInteger, T, const MIN: i128, const MAX: i128> TaggedType<V, Bounded<T, MIN, MAX>> {
+    const VALID_RANGE: () = assert!(
+        V::MIN_I128 <= MIN && MIN <= MAX && MAX <= V::MAX_I128,
+        "range of Bounded tag does not fit inner type"
+    );
+
+    /// Create `TaggedType` if value is in `MIN..=MAX` range.
+    #[inline]
+    pub fn new_checked(v: V) -> Option<Self> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_RANGE;
+        v.to_i128()
+            .filter(|v| (MIN..=MAX).contains(v))
+            .map(|_| Self::new_raw(v))
+    }
+
+    /// Create `TaggedType` from value clamped to `MIN..=MAX` range.
+    #[inline]
+    pub fn new_clamped(v: V) -> Self {
+        Self::from_i128_clamped(v.to_i128().unwrap_or(MAX))
+    }
+
+    /// Add `rhs` to inner value. Returns `None` if result is out of
+    /// range.
+    #[inline]
+    #[must_use]
+    pub fn checked_add(self, rhs: V) -> Option<Self> {
+        self.into_value()
+            .to_i128()
+            .zip(rhs.to_i128())
+            .and_then(|(v, rhs)| v.checked_add(rhs))
+            .and_then(V::from_i128)
+            .and_then(Self::new_checked)
+    }
+
+    /// Subtract `rhs` from inner value. Returns `None` if result is
+    /// out of range.
+    #[inline]
+    #[must_use]
+    pub fn checked_sub(self, rhs: V) -> Option<Self> {
+        self.into_value()
+            .to_i128()
+            .zip(rhs.to_i128())
+            .and_then(|(v, rhs)| v.checked_sub(rhs))
+            .and_then(V::from_i128)
+            .and_then(Self::new_checked)
+    }
+
+    /// Add `rhs` to inner value. Result is clamped to the range.
+    #[inline]
+    #[must_use]
+    pub fn saturating_add(self, rhs: V) -> Self {
+        let v = self.into_value().to_i128().unwrap_or(MAX);
+        Self::from_i128_clamped(rhs.to_i128().map_or(MAX, |rhs| v.saturating_add(rhs)))
+    }
+
+    /// Subtract `rhs` from inner value. Result is clamped to the range.
+    #[inline]
+    #[must_use]
+    pub fn saturating_sub(self, rhs: V) -> Self {
+        let v = self.into_value().to_i128().unwrap_or(MAX);
+        Self::from_i128_clamped(rhs.to_i128().map_or(MIN, |rhs| v.saturating_sub(rhs)))
+    }
+
+    #[inline]
+    fn from_i128_clamped(v: i128) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_RANGE;
+        let v = v.clamp(MIN, MAX);
+        // Cannot fail because VALID_RANGE guarantees that range fits V.
+        Self::new_raw(V::from_i128(v).unwrap_or_else(|| unreachable!()))
+    }
+}
+
+impl<V: PrimitiveInteger, T, const MIN: i128, const MAX: i128> Normalize<V>
+    for Bounded<T, MIN, MAX>
+{
+}
+
+impl<V: PrimitiveInteger, T, const MIN: i128, const MAX: i128> Validate<V>
+    for Bounded<T, MIN, MAX>
+{
+    type Error = InvalidTagged<T>;
+
+    #[inline]
+    fn validate(v: &V) -> Result<(), Self::Error> {
+        #[allow(clippy::let_unit_value)]
+        let () = TaggedType::<V, Self>::VALID_RANGE;
+        if v.to_i128().is_some_and(|v| (MIN..=MAX).contains(&v)) {
+            Ok(())
+        } else {
+            Err(InvalidTagged::new("out of range"))
+        }
+    }
+}
+
+macro_rules! inherit_marker {
+    ($($marker:ident),*) => {
+        $(
+            impl<T: $marker, const MIN: i128, const MAX: i128> $marker for Bounded<T, MIN, MAX> {}
+        )*
+    };
+}
+
+inherit_marker!(
+    AsRef,
+    Cloned,
+    Copied,
+    ImplementClone,
+    ImplementCopy,
+    ImplementDeref,
+    ImplementEq,
+    ImplementHash,
+    ImplementOrd,
+    ImplementPartialEq,
+    ImplementPartialOrd,
+    InnerAccess,
+    TransparentBinary,
+    TransparentLowerHex,
+    TransparentOctal,
+    TransparentUpperHex
+);
+
+#[cfg(feature = "support_serde")]
+inherit_marker!(TransparentSerialize);
+
+// Formatting is forwarded, so `ReleaseRedacted` tags stay hidden.
+impl<T: TransparentDebug, const MIN: i128, const MAX: i128> TransparentDebug
+    for Bounded<T, MIN, MAX>
+{
+    #[inline]
+    fn fmt_debug<V: Debug + ?Sized>(v: &V, f: &mut Formatter<'_>) -> FmtResult {
+        T::fmt_debug(v, f)
+    }
+}
+
+impl<T: TransparentDisplay, const MIN: i128, const MAX: i128> TransparentDisplay
+    for Bounded<T, MIN, MAX>
+{
+    #[inline]
+    fn fmt_display<V: Display + ?Sized>(v: &V, f: &mut Formatter<'_>) -> FmtResult {
+        T::fmt_display(v, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_bounded_signed() {
+        enum OffsetTag {}
+        type Offset = TaggedType<i8, Bounded<OffsetTag, -10, 10>>;
+        impl InnerAccess for OffsetTag {}
+        impl ImplementPartialEq for OffsetTag {}
+        impl TransparentDebug for OffsetTag {}
+        assert_eq!(Offset::new_checked(-11), None);
+        assert_eq!(
+            *Offset::new_checked(-10).expect("value in range").inner(),
+            -10
+        );
+        assert_eq!(*Offset::new_clamped(i8::MIN).inner(), -10);
+        assert_eq!(*Offset::new_clamped(i8::MAX).inner(), 10);
+        let zero = Offset::new_clamped(0);
+        assert_eq!(zero.checked_sub(11), None);
+        assert_eq!(*Offset::new_clamped(0).saturating_sub(i8::MIN).inner(), 10);
+        assert_eq!(*Offset::new_clamped(0).saturating_add(i8::MIN).inner(), -10);
+        assert_eq!(
+            *Offset::new_clamped(5)
+                .checked_add(5)
+                .expect("value in range")
+                .inner(),
+            10
+        );
+        assert_eq!(*Offset::try_new(-10).expect("value in range").inner(), -10);
+        assert_eq!(
+            Offset::try_new(11).err().map(|e| e.to_string()),
+            Some("Offset: out of range".into())
+        );
+    }
+
+    #[test]
+    fn test_bounded_u128() {
+        enum BigTag {}
+        type Big = TaggedType<u128, Bounded<BigTag, 1, { i128::MAX }>>;
+        impl InnerAccess for BigTag {}
+        assert!(Big::new_checked(0).is_none());
+        assert!(Big::new_checked(u128::MAX).is_none());
+        assert_eq!(*Big::new_clamped(u128::MAX).inner(), i128::MAX as u128);
+        assert_eq!(
+            *Big::new_clamped(1).saturating_add(u128::MAX).inner(),
+            i128::MAX as u128
+        );
+    }
+}
@@ -0,0 +1,236 @@
+// SPDX-License-Identifier: MIT
+
+use core::any::type_name;
+use core::convert::Infallible;
+use core::error::Error;
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::marker::PhantomData;
+use core::str::Utf8Error;
+use std::ffi::OsString;
+
+/// Error of creation of `TaggedType<V, T>` from UTF-8 bytes.
+///
+/// `E` is error of parsing of the string by `V`.
+pub enum FromUtf8Error<T, E = Infallible> {
+    /// Bytes are not valid UTF-8.
+    Utf8(Utf8Error, PhantomData<T>),
+    /// Inner type failed to parse the string.
+    Parse(E),
+}
+
+impl<T, E> FromUtf8Error<T, E> {
+    pub(crate) const fn utf8(e: Utf8Error) -> Self {
+        Self::Utf8(e, PhantomData)
+    }
+}
+
+impl<T, E: Debug> Debug for FromUtf8Error<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Utf8(e, _) => f.debug_tuple("Utf8").field(e).finish(),
+            Self::Parse(e) => f.debug_tuple("Parse").field(e).finish(),
+        }
+    }
+}
+
+impl<T, E: Display> Display for FromUtf8Error<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Utf8(e, _) => write!(f, "{}: invalid UTF-8: {e}", type_name::<T>()),
+            Self::Parse(e) => write!(f, "{}: {e}", type_name::<T>()),
+        }
+    }
+}
+
+impl<T, E: Error + 'static> Error for FromUtf8Error<T, E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Utf8(e, _) => Some(e),
+            Self::Parse(e) => Some(e),
+        }
+    }
+}
+
+/// Error of creation of `TaggedType<V, T>` from OS string.
+///
+/// `E` is error of parsing of the string by `V`.
+pub enum FromOsStrError<T, E = Infallible> {
+    /// OS string is not valid Unicode. Contains original string.
+    NotUnicode(OsString, PhantomData<T>),
+    /// Inner type failed to parse the string.
+    Parse(E),
+}
+
+impl<T, E> FromOsStrError<T, E> {
+    pub(crate) const fn not_unicode(v: OsString) -> Self {
+        Self::NotUnicode(v, PhantomData)
+    }
+}
+
+impl<T, E: Debug> Debug for FromOsStrError<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::NotUnicode(v, _) => f.debug_tuple("NotUnicode").field(v).finish(),
+            Self::Parse(e) => f.debug_tuple("Parse").field(e).finish(),
+        }
+    }
+}
+
+impl<T, E: Display> Display for FromOsStrError<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::NotUnicode(v, _) => {
+                write!(
+                    f,
+                    "{}: invalid unicode: {}",
+                    type_name::<T>(),
+                    v.to_string_lossy()
+                )
+            }
+            Self::Parse(e) => write!(f, "{}: {e}", type_name::<T>()),
+        }
+    }
+}
+
+impl<T, E: Error + 'static> Error for FromOsStrError<T, E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NotUnicode(..) => None,
+            Self::Parse(e) => Some(e),
+        }
+    }
+}
+
+/// Kind of `ByteArrayError`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ByteArrayErrorKind {
+    /// Input encodes wrong number of bytes.
+    InvalidLength {
+        /// Number of bytes of the array.
+        expected: usize,
+        /// Number of bytes of the input.
+        actual: usize,
+    },
+    /// Input is not valid hex string.
+    InvalidHex,
+    /// Input is not valid base64 string.
+    InvalidBase64,
+}
+
+/// Error of creation of fixed-size byte array `T`.
+pub struct ByteArrayError<T> {
+    kind: ByteArrayErrorKind,
+    _marker: PhantomData<T>,
+}
+
+impl<T> ByteArrayError<T> {
+    #[cfg(feature = "provide_presets")]
+    pub(crate) const fn new(kind: ByteArrayErrorKind) -> Self {
+        Self {
+            kind,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns kind of the error.
+    #[must_use]
+    pub const fn kind(&self) -> ByteArrayErrorKind {
+        self.kind
+    }
+}
+
+impl<T> Debug for ByteArrayError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("ByteArrayError").field(&self.kind).finish()
+    }
+}
+
+impl<T> Display for ByteArrayError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            ByteArrayErrorKind::InvalidLength { expected, actual } => write!(
+                f,
+                "{}: expected {expected} bytes, got {actual}",
+                type_name::<T>()
+            ),
+            ByteArrayErrorKind::InvalidHex => write!(f, "{}: invalid hex", type_name::<T>()),
+            ByteArrayErrorKind::InvalidBase64 => {
+                write!(f, "{}: invalid base64", type_name::<T>())
+            }
+        }
+    }
+}
+
+impl<T> Error for ByteArrayError<T> {}
+
+/// Name of the tagged type of tag `T` (`Balance` for `BalanceTag`).
+pub(crate) fn tagged_name<T>() -> &'static str {
+    let name = type_name::<T>();
+    let path = name.split('<').next().unwrap_or(name);
+    let short = path.rsplit("::").next().unwrap_or(path);
+    short
+        .strip_suffix("Tag")
+        .filter(|s| !s.is_empty())
+        .unwrap_or(short)
+}
+
+/// Overflow of arithmetic operation of `TaggedType<V, T>` (`try_add`,
+/// `try_sub`, `try_mul`). `Display` names the tagged type
+/// (`"Balance: overflow in subtraction"`).
+pub struct ArithmeticOverflow<T> {
+    operation: &'static str,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> ArithmeticOverflow<T> {
+    pub(crate) const fn new(operation: &'static str) -> Self {
+        Self {
+            operation,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Name of the operation that overflowed (`"addition"`).
+    #[must_use]
+    pub const fn operation(&self) -> &'static str {
+        self.operation
+    }
+}
+
+impl<T> Clone for ArithmeticOverflow<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ArithmeticOverflow<T> {}
+
+impl<T> PartialEq for ArithmeticOverflow<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.operation == other.operation
+    }
+}
+
+impl<T> Eq for ArithmeticOverflow<T> {}
+
+impl<T> Debug for ArithmeticOverflow<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ArithmeticOverflow")
+            .field("tagged", &tagged_name::<T>())
+            .field("operation", &self.operation)
+            .finish()
+    }
+}
+
+impl<T> Display for ArithmeticOverflow<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}: overflow in {}", tagged_name::<T>(), self.operation)
+    }
+}
+
+impl<T> Error for ArithmeticOverflow<T> {}
@@ -0,0 +1,473 @@
+// SPDX-License-Identifier: MIT
+
+use crate::AsRef;
+use crate::Cloned;
+use crate::Copied;
+#[cfg(feature = "support_serde")]
+use crate::DeserializeTag;
+use crate::FromInner;
+use crate::ImplementAdd;
+use crate::ImplementAddAssign;
+use crate::ImplementClone;
+use crate::ImplementCopy;
+use crate::ImplementDefault;
+use crate::ImplementDeref;
+use crate::ImplementDiv;
+use crate::ImplementDivAssign;
+use crate::ImplementEq;
+use crate::ImplementHash;
+use crate::ImplementMul;
+use crate::ImplementMulAssign;
+use crate::ImplementNeg;
+use crate::ImplementPartialEq;
+use crate::ImplementPartialOrd;
+use crate::ImplementSub;
+use crate::ImplementSubAssign;
+use crate::InnerAccess;
+use crate::InvalidTagged;
+use crate::Normalize;
+use crate::NotValidated;
+use crate::ParseTag;
+use crate::TaggedType;
+use crate::TransparentDebug;
+#[cfg(feature = "support_serde")]
+use crate::TransparentDeserialize;
+use crate::TransparentDisplay;
+use crate::TransparentFromStr;
+use crate::TransparentLowerExp;
+#[cfg(feature = "support_serde")]
+use crate::TransparentSerialize;
+use crate::TransparentUpperExp;
+use crate::Validate;
+use crate::ValueMap;
+use core::cmp::Ordering;
+use core::convert::Infallible;
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::hash::Hash;
+use core::hash::Hasher;
+use core::marker::PhantomData;
+use core::str::FromStr;
+#[cfg(feature = "support_serde")]
+use serde::Deserialize;
+#[cfg(feature = "support_serde")]
+use serde::Deserializer;
+
+/// Tag that forbids NaN as inner `f32` / `f64` value of `TaggedType`.
+///
+/// `Tag` is the tag of the type. `NotNan<Tag>` has capabilities of
+/// `Tag` that cannot produce NaN: `Clone`, `Copy`, `Debug`, `Display`,
+/// `Serialize`, `Deref`, inner access, `as_ref`, `cloned` and `copied`.
+/// Capabilities that construct value without check (`FromStr`,
+/// `Deserialize`, `From<V>`, `map`, `Default`, arithmetic operators,
+/// ...) are not inherited.
+///
+/// Because value cannot be NaN, comparison is total: `PartialEq` /
+/// `Eq`, `PartialOrd` / `Ord` and `Hash` are implemented if `Tag`
+/// implements `ImplementPartialEq` / `ImplementEq`,
+/// `ImplementPartialOrd` (`Ord` also requires `ImplementEq`) and
+/// `ImplementHash`. Negative and positive zeros are equal.
+///
+/// Values are created by `new_checked` (or `try_new` of `Validate`).
+/// `NotNan` does not implement `NotValidated`, so `TaggedType::new` is
+/// not available:
+/// ```rust,compile_fail,E0599
+/// use tagged_types::{NotNan, TaggedType};
+/// pub type Score = TaggedType<f64, NotNan<ScoreTag>>;
+/// pub enum ScoreTag {}
+///
+/// let score = Score::new(f64::NAN);
+/// ```
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{NotNan, TaggedType, ImplementEq, ImplementOrd, ImplementPartialEq, ImplementPartialOrd};
+/// use std::collections::BTreeSet;
+/// pub type Score = TaggedType<f64, NotNan<ScoreTag>>;
+/// pub enum ScoreTag {}
+/// impl ImplementPartialEq for ScoreTag {};
+/// impl ImplementEq for ScoreTag {};
+/// impl ImplementPartialOrd for ScoreTag {};
+/// impl ImplementOrd for ScoreTag {};
+///
+/// assert!(Score::new_checked(f64::NAN).is_none());
+/// let scores: BTreeSet<_> = [0.5, f64::INFINITY, -1.0]
+///     .iter()
+///     .filter_map(|v| Score::new_checked(*v))
+///     .collect();
+/// assert_eq!(scores.len(), 3);
+/// ```
+pub struct NotNan<Tag> {
+    _marker: PhantomData<Tag>,
+    _never: Infallible,
+}
+
+/// Tag that forbids NaN and infinities as inner `f32` / `f64` value
+/// of `TaggedType`.
+///
+/// Same as `NotNan` but `new_checked` rejects infinities too.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{Finite, TaggedType, ImplementEq, ImplementHash, ImplementPartialEq};
+/// use std::collections::HashSet;
+/// pub type Price = TaggedType<f64, Finite<PriceTag>>;
+/// pub enum PriceTag {}
+/// impl ImplementPartialEq for PriceTag {};
+/// impl ImplementEq for PriceTag {};
+/// impl ImplementHash for PriceTag {};
+///
+/// assert!(Price::new_checked(f64::INFINITY).is_none());
+/// let mut prices = HashSet::new();
+/// prices.insert(Price::new_checked(9.99).unwrap());
+/// ```
+pub struct Finite<Tag> {
+    _marker: PhantomData<Tag>,
+    _never: Infallible,
+}
+
+/// Tag that gives total order to `f32` / `f64` inner value of
+/// `TaggedType` using `total_cmp`.
+///
+/// Unlike `NotNan` and `Finite` all values are allowed. Comparison
+/// follows IEEE 754 `totalOrder`: negative NaN < negative infinity <
+/// ... < -0.0 < +0.0 < ... < positive infinity < positive NaN.
+/// `PartialEq` / `Eq`, `PartialOrd` / `Ord` and `Hash` (by bits, so it
+/// matches equality) are implemented if `Tag` implements
+/// `ImplementPartialEq` / `ImplementEq`, `ImplementPartialOrd`
+/// (`Ord` also requires `ImplementEq`) and `ImplementHash`.
+///
+/// Since there is no invariant to check, `TotalOrd<Tag>` also inherits
+/// capabilities that construct values (`FromStr`, `Deserialize`,
+/// `From<V>`, `map`, `Default` and arithmetic operators).
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TotalOrd, TaggedType, ImplementEq, ImplementOrd, ImplementPartialEq, ImplementPartialOrd, Plain};
+/// use std::collections::BTreeMap;
+/// pub type Price = TaggedType<f64, TotalOrd<PriceTag>>;
+/// pub enum PriceTag {}
+/// impl Plain for PriceTag {};
+/// impl ImplementPartialEq for PriceTag {};
+/// impl ImplementEq for PriceTag {};
+/// impl ImplementPartialOrd for PriceTag {};
+/// impl ImplementOrd for PriceTag {};
+///
+/// let mut orders = BTreeMap::new();
+/// orders.insert(Price::new(9.99), "book");
+/// orders.insert(Price::new(-0.0), "refund");
+/// assert!(orders.keys().next() == Some(&Price::new(-0.0)));
+/// ```
+pub struct TotalOrd<Tag> {
+    _marker: PhantomData<Tag>,
+    _never: Infallible,
+}
+
+macro_rules! total_float {
+    ($wrapper:ident, |$v:ident| $is_valid:expr, $rule:literal, $($f:ty),*) => {
+        $(
+            impl<T> Normalize<$f> for $wrapper<T> {}
+
+            impl<T> Validate<$f> for $wrapper<T> {
+                type Error = InvalidTagged<T>;
+
+                #[inline]
+                fn validate($v: &$f) -> Result<(), Self::Error> {
+                    let $v = *$v;
+                    if $is_valid {
+                        Ok(())
+                    } else {
+                        Err(InvalidTagged::new($rule))
+                    }
+                }
+            }
+
+            impl<T> TaggedType<$f, $wrapper<T>> {
+                /// Create `TaggedType` if value is allowed by the tag.
+                #[inline]
+                pub const fn new_checked($v: $f) -> Option<Self> {
+                    if $is_valid {
+                        Some(Self::new_raw($v))
+                    } else {
+                        None
+                    }
+                }
+
+                /// Inner value with negative zero replaced by positive zero.
+                #[inline]
+                fn normalized(&self) -> $f {
+                    let v = *self.value();
+                    if v == 0.0 {
+                        0.0
+                    } else {
+                        v
+                    }
+                }
+            }
+
+            impl<T: ImplementPartialEq> PartialEq for TaggedType<$f, $wrapper<T>> {
+                #[inline]
+                fn eq(&self, other: &Self) -> bool {
+                    self.value() == other.value()
+                }
+            }
+
+            impl<T: ImplementEq> Eq for TaggedType<$f, $wrapper<T>> {}
+
+            impl<T: ImplementPartialOrd> PartialOrd for TaggedType<$f, $wrapper<T>> {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    Some(self.normalized().total_cmp(&other.normalized()))
+                }
+            }
+
+            impl<T: ImplementPartialOrd + ImplementEq> Ord for TaggedType<$f, $wrapper<T>> {
+                #[inline]
+                fn cmp(&self, other: &Self) -> Ordering {
+                    // Values are never NaN and zeros are normalized, so
+                    // total order matches `PartialEq`.
+                    self.normalized().total_cmp(&other.normalized())
+                }
+            }
+
+            impl<T: ImplementHash> Hash for TaggedType<$f, $wrapper<T>> {
+                #[inline]
+                fn hash<H: Hasher>(&self, state: &mut H) {
+                    self.normalized().to_bits().hash(state);
+                }
+            }
+        )*
+    };
+}
+
+total_float!(NotNan, |v| !v.is_nan(), "must not be NaN", f32, f64);
+total_float!(Finite, |v| v.is_finite(), "must be finite", f32, f64);
+
+macro_rules! total_cmp_float {
+    ($($f:ty),*) => {
+        $(
+            impl<T: ImplementPartialEq> PartialEq for TaggedType<$f, TotalOrd<T>> {
+                #[inline]
+                fn eq(&self, other: &Self) -> bool {
+                    self.value().total_cmp(other.value()) == Ordering::Equal
+                }
+            }
+
+            impl<T: ImplementEq> Eq for TaggedType<$f, TotalOrd<T>> {}
+
+            impl<T: ImplementPartialOrd> PartialOrd for TaggedType<$f, TotalOrd<T>> {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    Some(self.value().total_cmp(other.value()))
+                }
+            }
+
+            impl<T: ImplementPartialOrd + ImplementEq> Ord for TaggedType<$f, TotalOrd<T>> {
+                #[inline]
+                fn cmp(&self, other: &Self) -> Ordering {
+                    self.value().total_cmp(other.value())
+                }
+            }
+
+            impl<T: ImplementHash> Hash for TaggedType<$f, TotalOrd<T>> {
+                #[inline]
+                fn hash<H: Hasher>(&self, state: &mut H) {
+                    self.value().to_bits().hash(state);
+                }
+            }
+        )*
+    };
+}
+
+total_cmp_float!(f32, f64);
+
+macro_rules! inherit_marker {
+    ($($marker:ident),*) => {
+        $(
+            impl<T: $marker> $marker for NotNan<T> {}
+            impl<T: $marker> $marker for Finite<T> {}
+            impl<T: $marker> $marker for TotalOrd<T> {}
+        )*
+    };
+}
+
+// Formatting is forwarded, so `ReleaseRedacted` tags stay hidden.
+macro_rules! inherit_fmt {
+    ($($wrapper:ident),*) => {
+        $(
+            impl<T: TransparentDebug> TransparentDebug for $wrapper<T> {
+                #[inline]
+                fn fmt_debug<V: Debug + ?Sized>(v: &V, f: &mut Formatter<'_>) -> FmtResult {
+                    T::fmt_debug(v, f)
+                }
+            }
+
+            impl<T: TransparentDisplay> TransparentDisplay for $wrapper<T> {
+                #[inline]
+                fn fmt_display<V: Display + ?Sized>(v: &V, f: &mut Formatter<'_>) -> FmtResult {
+                    T::fmt_display(v, f)
+                }
+            }
+        )*
+    };
+}
+
+inherit_fmt!(NotNan, Finite, TotalOrd);
+
+macro_rules! inherit_unchecked_marker {
+    ($($marker:ident),*) => {
+        $(
+            impl<T: $marker> $marker for TotalOrd<T> {}
+        )*
+    };
+}
+
+inherit_marker!(
+    AsRef,
+    Cloned,
+    Copied,
+    ImplementClone,
+    ImplementCopy,
+    ImplementDeref,
+    InnerAccess,
+    TransparentLowerExp,
+    TransparentUpperExp
+);
+
+#[cfg(feature = "support_serde")]
+inherit_marker!(TransparentSerialize);
+
+inherit_unchecked_marker!(
+    FromInner,
+    ImplementAdd,
+    ImplementAddAssign,
+    ImplementDefault,
+    ImplementDiv,
+    ImplementDivAssign,
+    ImplementMul,
+    ImplementMulAssign,
+    ImplementNeg,
+    ImplementSub,
+    ImplementSubAssign,
+    NotValidated,
+    ValueMap
+);
+
+impl<V: ?Sized, T: Normalize<V>> Normalize<V> for TotalOrd<T> {
+    #[inline]
+    fn normalize(v: V) -> V
+    where
+        V: Sized,
+    {
+        T::normalize(v)
+    }
+}
+
+// `TotalOrd` is not `Plain` (it forwards `Normalize`), so transparent
+// parsing and deserialization of the tag are forwarded as hooks.
+impl<V: FromStr, T: TransparentFromStr> ParseTag<V> for TotalOrd<T> {
+    type Err = V::Err;
+
+    #[inline]
+    fn parse(s: &str) -> Result<V, Self::Err> {
+        s.parse()
+    }
+}
+
+#[cfg(feature = "support_serde")]
+impl<'de, V: Deserialize<'de>, T: TransparentDeserialize> DeserializeTag<'de, V> for TotalOrd<T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<V, D::Error> {
+        V::deserialize(deserializer)
+    }
+
+    #[inline]
+    fn deserialize_in_place<D: Deserializer<'de>>(
+        deserializer: D,
+        place: &mut V,
+    ) -> Result<(), D::Error> {
+        V::deserialize_in_place(deserializer, place)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use alloc::collections::BTreeSet;
+    use core::cmp::Ordering;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_not_nan() {
+        enum ScoreTag {}
+        type Score = TaggedType<f32, NotNan<ScoreTag>>;
+        impl ImplementPartialEq for ScoreTag {}
+        impl ImplementEq for ScoreTag {}
+        impl ImplementPartialOrd for ScoreTag {}
+        impl ImplementHash for ScoreTag {}
+        impl TransparentDebug for ScoreTag {}
+        assert!(Score::new_checked(f32::NAN).is_none());
+        let inf = Score::new_checked(f32::INFINITY).expect("non-NaN value");
+        let zero = Score::new_checked(0.0).expect("non-NaN value");
+        let neg_zero = Score::new_checked(-0.0).expect("non-NaN value");
+        assert_eq!(zero, neg_zero);
+        assert!(zero < inf);
+        assert_eq!(zero.cmp(&neg_zero), Ordering::Equal);
+        let set: HashSet<_> = vec![zero, neg_zero, inf].into_iter().collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_finite() {
+        enum PriceTag {}
+        type Price = TaggedType<f64, Finite<PriceTag>>;
+        impl InnerAccess for PriceTag {}
+        impl ImplementPartialEq for PriceTag {}
+        impl ImplementEq for PriceTag {}
+        impl ImplementPartialOrd for PriceTag {}
+        assert!(Price::new_checked(f64::NAN).is_none());
+        assert!(Price::new_checked(f64::NEG_INFINITY).is_none());
+        assert_eq!(
+            Price::try_new(f64::INFINITY).err().map(|e| e.to_string()),
+            Some("Price: must be finite".into())
+        );
+        let set: BTreeSet<_> = vec![2.5, 1.0, 2.5]
+            .into_iter()
+            .filter_map(Price::new_checked)
+            .collect();
+        let prices: Vec<f64> = set.into_iter().map(Price::into_inner).collect();
+        assert_eq!(prices, vec![1.0, 2.5]);
+    }
+
+    #[test]
+    fn test_total_ord() {
+        enum ScoreTag {}
+        type Score = TaggedType<f64, TotalOrd<ScoreTag>>;
+        impl Plain for ScoreTag {}
+        impl InnerAccess for ScoreTag {}
+        impl ImplementPartialEq for ScoreTag {}
+        impl ImplementEq for ScoreTag {}
+        impl ImplementPartialOrd for ScoreTag {}
+        impl ImplementOrd for ScoreTag {}
+        impl ImplementHash for ScoreTag {}
+        impl ImplementAdd for ScoreTag {}
+        let nan = Score::new(f64::NAN);
+        assert_eq!(nan.cmp(&Score::new(f64::NAN)), Ordering::Equal);
+        assert!(Score::new(-0.0) < Score::new(0.0));
+        assert!(Score::new(f64::INFINITY) < nan);
+        let set: HashSet<_> = vec![Score::new(0.0), Score::new(-0.0), Score::new(0.0) + 0.0]
+            .into_iter()
+            .collect();
+        assert_eq!(set.len(), 2);
+        let sorted: Vec<f64> = [2.5, f64::NEG_INFINITY, 1.0]
+            .iter()
+            .map(|v| Score::new(*v))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(Score::into_inner)
+            .collect();
+        assert_eq!(sorted, vec![f64::NEG_INFINITY, 1.0, 2.5]);
+    }
+}
@@ -38,35 +38,148 @@
 )]
 #![deny(missing_docs)]
 
+extern crate alloc;
+
 /// Definition of `TaggedType`.
 pub mod tagged_type;
 
 /// Definitions of crate's traits.
 pub mod traits;
 
+/// Definitions of crate's error types.
+pub mod error;
+
+/// Definitions of `StableHasher` and `StableEncode`.
+pub mod stable_hash;
+
+/// Validation of inner values of `TaggedType`.
+pub mod validation;
+
+/// Definition of `Bounded` tag for integer ranges.
+pub mod bounded;
+
+/// Definitions of `NotNan`, `Finite` and `TotalOrd` tags for floating
+/// point values.
+pub mod float;
+
+/// Conversions from / to newtypes of other libraries if
+/// `provide_migration` feature is defined.
+#[cfg(feature = "provide_migration")]
+pub mod migration;
+
+/// Ready-made tags for common domains if `provide_presets` feature
+/// is defined.
+#[cfg(feature = "provide_presets")]
+pub mod presets;
+
+/// Helpers for `#[serde(with = "...")]` field attributes if
+/// `support_serde` feature is defined.
+#[cfg(feature = "support_serde")]
+pub mod serde_helpers;
+
+pub use traits::AllowRetagFrom;
+pub use traits::AsDeref;
 pub use traits::AsRef;
+pub use traits::ByteConversion;
+pub use traits::CheckedArithmetic;
 pub use traits::Cloned;
+pub use traits::ConvertTag;
+pub use traits::Copied;
+pub use traits::DefaultValue;
+pub use traits::Ffi;
 pub use traits::FromInner;
+pub use traits::FromOsStr;
+pub use traits::FromUtf8;
 pub use traits::ImplementAdd;
+pub use traits::ImplementAddAssign;
+pub use traits::ImplementAsMut;
+pub use traits::ImplementBitAnd;
+pub use traits::ImplementBitAndAssign;
+pub use traits::ImplementBitOr;
+pub use traits::ImplementBitOrAssign;
+pub use traits::ImplementBitXor;
+pub use traits::ImplementBitXorAssign;
+pub use traits::ImplementBorrow;
 pub use traits::ImplementClone;
 pub use traits::ImplementCopy;
 pub use traits::ImplementDefault;
 pub use traits::ImplementDeref;
+pub use traits::ImplementDerefMut;
 pub use traits::ImplementDiv;
+pub use traits::ImplementDivAssign;
 pub use traits::ImplementEq;
 pub use traits::ImplementHash;
 pub use traits::ImplementMul;
+pub use traits::ImplementMulAssign;
+pub use traits::ImplementNeg;
+pub use traits::ImplementNot;
 pub use traits::ImplementOrd;
 pub use traits::ImplementPartialEq;
+pub use traits::ImplementPartialEqInner;
 pub use traits::ImplementPartialOrd;
+pub use traits::ImplementPartialOrdInner;
+pub use traits::ImplementRem;
+pub use traits::ImplementShl;
+pub use traits::ImplementShr;
 pub use traits::ImplementSub;
+pub use traits::ImplementSubAssign;
 pub use traits::InnerAccess;
+pub use traits::KeyedAccess;
+pub use traits::MutInnerAccess;
+pub use traits::SaturatingArithmetic;
+pub use traits::StableHash;
+pub use traits::StepRange;
+pub use traits::SubtagOf;
+pub use traits::TagDiv;
+pub use traits::TagMul;
+pub use traits::TagSubOutput;
+pub use traits::TransparentAsRef;
+pub use traits::TransparentBinary;
 pub use traits::TransparentDebug;
 pub use traits::TransparentDisplay;
+pub use traits::TransparentError;
+pub use traits::TransparentExtend;
+pub use traits::TransparentFmtWrite;
 pub use traits::TransparentFromInner;
+pub use traits::TransparentFromIterator;
 pub use traits::TransparentFromStr;
+pub use traits::TransparentFuture;
+pub use traits::TransparentIndex;
+pub use traits::TransparentIndexMut;
+pub use traits::TransparentIntoIterator;
+pub use traits::TransparentIterator;
+pub use traits::TransparentLowerExp;
+pub use traits::TransparentLowerHex;
+pub use traits::TransparentOctal;
+pub use traits::TransparentTryFrom;
+pub use traits::TransparentUpperExp;
+pub use traits::TransparentUpperHex;
 pub use traits::ValueMap;
+pub use traits::WrappingArithmetic;
+
+pub use traits::convert::AsRefTag;
+pub use traits::convert::ParseTag;
+pub use traits::fmt::DebugTag;
+pub use traits::fmt::DisplayTag;
+pub use traits::fmt::ReleaseRedacted;
+pub use traits::fmt::TagUnit;
+pub use traits::fmt::TruncatedDebug;
+pub use traits::fmt::TruncatedDisplay;
+pub use traits::num::PrimitiveInteger;
+
+#[cfg(feature = "support_io")]
+pub use traits::io::TransparentRead;
+#[cfg(feature = "support_io")]
+pub use traits::io::TransparentSeek;
+#[cfg(feature = "support_io")]
+pub use traits::io::TransparentWrite;
 
+#[cfg(feature = "support_serde")]
+pub use traits::serde::DeserializeTag;
+#[cfg(feature = "support_serde")]
+pub use traits::serde::RedactedSerialize;
+#[cfg(feature = "support_serde")]
+pub use traits::serde::SerializeTag;
 #[cfg(feature = "support_serde")]
 pub use traits::serde::TransparentDeserialize;
 #[cfg(feature = "support_serde")]
@@ -75,17 +188,101 @@ pub use traits::serde::TransparentSerialize;
 #[cfg(feature = "provide_permissive")]
 pub use traits::permissive::Permissive;
 
+#[cfg(feature = "provide_migration")]
+pub use migration::ForeignNewtype;
+
+pub use bounded::Bounded;
+
+pub use float::Finite;
+pub use float::NotNan;
+pub use float::TotalOrd;
+
+pub use validation::AsyncValidate;
+pub use validation::ConstValidate;
+pub use validation::HasLength;
+pub use validation::InvalidTagged;
+pub use validation::Normalize;
+pub use validation::NotValidated;
+pub use validation::ParseValidatedError;
+pub use validation::Plain;
+pub use validation::Validate;
+
 /// Export `TaggedType` from top level.
 pub type TaggedType<V, T> = tagged_type::TaggedType<V, T>;
 
 #[cfg(feature = "provide_derive")]
 pub use tagged_types_derive::Tag;
 
+/// Re-export of serde for code generated by derive.
+#[cfg(all(feature = "provide_derive", feature = "support_serde"))]
+#[doc(hidden)]
+pub use serde as __serde;
+
+#[cfg(feature = "provide_derive")]
+pub use tagged_types_derive::domain;
+
+/// Traits checked by `#[impl_snapshot(...)]` of derive that are
+/// not defined in `core`.
+#[cfg(feature = "provide_derive")]
+#[doc(hidden)]
+pub mod __snapshot {
+    #[cfg(feature = "support_serde")]
+    use serde::de::DeserializeOwned;
+
+    /// Implemented by types that implement `serde::Serialize`.
+    pub trait Serialize {}
+
+    #[cfg(feature = "support_serde")]
+    impl<T: serde::Serialize + ?Sized> Serialize for T {}
+
+    /// Implemented by types that implement `serde::Deserialize`.
+    pub trait Deserialize {}
+
+    #[cfg(feature = "support_serde")]
+    impl<T: DeserializeOwned> Deserialize for T {}
+}
+
+#[cfg(test)]
+mod test_utils {
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::Context;
+    use core::task::Poll;
+    use core::task::Waker;
+
+    /// Run future that does not wait for events to completion.
+    pub fn block_on<F: Future>(f: F) -> F::Output {
+        let mut f = pin!(f);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(v) = f.as_mut().poll(&mut cx) {
+                return v;
+            }
+        }
+    }
+}
+
 #[cfg(feature = "provide_derive")]
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(feature = "support_serde")]
+    #[derive(Tag)]
+    #[implement(PartialEq)]
+    #[transparent(Display, FromStr, Serialize, Deserialize)]
+    #[roundtrip_tests(display_fromstr, serde_json, samples(i64: 0, -1, i64::MAX))]
+    enum BalanceTag {}
+
+    #[cfg(feature = "support_serde")]
+    #[derive(Tag)]
+    #[implement(PartialEq)]
+    #[transparent(Display, FromStr, Serialize, Deserialize)]
+    #[validate(length(min = 1, max = 8))]
+    #[normalize(trim, lowercase)]
+    #[roundtrip_tests(display_fromstr, serde_json, samples(String: "admin".into(), " Root ".into()))]
+    enum LoginTag {}
+
     #[test]
     fn test_derive_all() {
         type CounterU64 = TaggedType<u64, CounterU64Tag>;
@@ -100,6 +297,238 @@ mod tests {
     }
 
     #[test]
+    fn test_derive_inner_mut() {
+        type Counter = TaggedType<u64, CounterTag>;
+        #[derive(Tag)]
+        #[capability(inner_access, inner_mut)]
+        enum CounterTag {}
+
+        let mut c = Counter::new(1);
+        *c.inner_mut() += 1;
+        assert_eq!(*c.inner(), 2);
+    }
+
+    #[test]
+    fn test_derive_copied_as_deref() {
+        type Username<V> = TaggedType<V, UsernameTag>;
+        #[derive(Tag)]
+        #[capability(inner_access, copied, as_deref)]
+        enum UsernameTag {}
+
+        let username = Username::new(String::from("admin"));
+        assert_eq!(*username.as_deref().inner(), "admin");
+        let port = 22_u16;
+        assert_eq!(*Username::new(&port).copied().inner(), 22);
+    }
+
+    #[test]
+    fn test_derive_keyed_access() {
+        use std::collections::HashMap;
+        type FeatureFlags = TaggedType<HashMap<String, bool>, FeatureFlagsTag>;
+        #[derive(Tag)]
+        #[implement(Default)]
+        #[capability(inner_access, keyed_access)]
+        enum FeatureFlagsTag {}
+
+        let mut flags = FeatureFlags::default();
+        assert!(flags.insert("dark_mode".into(), true).is_none());
+        assert_eq!(flags.get("dark_mode").map(|v| **v.inner()), Some(true));
+        assert_eq!(flags.len(), 1);
+    }
+
+    #[test]
+    fn test_derive_byte_conversion() {
+        type SeqNo = TaggedType<u16, SeqNoTag>;
+        #[derive(Tag)]
+        #[capability(inner_access, byte_conversion)]
+        enum SeqNoTag {}
+
+        assert_eq!(SeqNo::new(1).to_be_bytes(), [0, 1]);
+        assert_eq!(*SeqNo::from_le_bytes([1, 0]).inner(), 1);
+    }
+
+    #[test]
+    fn test_derive_from_utf8_from_os_str() {
+        use std::ffi::OsStr;
+        type Port = TaggedType<u16, PortTag>;
+        #[derive(Tag)]
+        #[capability(inner_access, from_utf8, from_os_str)]
+        enum PortTag {}
+
+        assert_eq!(*Port::from_utf8(b"22").expect("valid port").inner(), 22);
+        assert_eq!(
+            *Port::from_os_str(OsStr::new("80"))
+                .expect("valid port")
+                .inner(),
+            80
+        );
+    }
+
+    #[test]
+    fn test_derive_stable_hash() {
+        type TenantId = TaggedType<u64, TenantIdTag>;
+        #[derive(Tag)]
+        #[capability(stable_hash)]
+        enum TenantIdTag {}
+
+        assert_eq!(
+            TenantId::new(42).stable_hash64(),
+            TenantId::new(42).stable_hash64()
+        );
+    }
+
+    #[cfg(not(feature = "track_construction"))]
+    #[test]
+    fn test_derive_ffi() {
+        type UserId = TaggedType<u64, UserIdTag>;
+        #[derive(Tag)]
+        #[capability(ffi)]
+        enum UserIdTag {}
+
+        extern "C" fn next(id: UserId) -> UserId {
+            UserId::from_ffi(id.into_ffi() + 1)
+        }
+        assert_eq!(next(UserId::from_ffi(1)).into_ffi(), 2);
+    }
+
+    #[test]
+    fn test_derive_step_range() {
+        type Page = TaggedType<u32, PageTag>;
+        #[derive(Tag)]
+        #[capability(inner_access, step_range)]
+        enum PageTag {}
+
+        assert_eq!(Page::range(Page::new(1), Page::new(4)).count(), 3);
+        assert_eq!(Page::range_inclusive(Page::new(1), Page::new(4)).count(), 4);
+    }
+
+    #[test]
+    fn test_derive_overflow_arithmetic() {
+        type Balance = TaggedType<u8, BalanceTag>;
+        #[derive(Tag)]
+        #[capability(
+            inner_access,
+            checked_arithmetic,
+            saturating_arithmetic,
+            wrapping_arithmetic
+        )]
+        enum BalanceTag {}
+
+        assert!(Balance::new(255).checked_add(1).is_none());
+        assert_eq!(*Balance::new(255).saturating_add(1).inner(), 255);
+        assert_eq!(*Balance::new(255).wrapping_add(1).inner(), 0);
+    }
+
+    #[test]
+    fn test_derive_transparent_collections() {
+        use core::str;
+        type Ports = TaggedType<Vec<u16>, PortsTag>;
+        #[derive(Tag)]
+        #[transparent(AsRef, IndexMut, IntoIterator, FromIterator, Extend)]
+        enum PortsTag {}
+        type Lines<'a> = TaggedType<str::Lines<'a>, LinesTag>;
+        #[derive(Tag)]
+        #[transparent(Iterator)]
+        enum LinesTag {}
+
+        let mut ports: Ports = (80..82).collect();
+        ports.extend([443]);
+        ports[0] = 8080;
+        let slice: &[u16] = ports.as_ref();
+        assert_eq!(slice, &[8080, 81, 443]);
+        assert_eq!(ports.into_iter().count(), 3);
+
+        let mut lines = Lines::new("a\nb".lines());
+        assert_eq!(lines.by_ref().next(), Some("a"));
+        assert_eq!(lines.into_iter().count(), 1);
+    }
+
+    #[test]
+    fn test_derive_transparent_future_error() {
+        use crate::test_utils::block_on;
+        use core::error::Error;
+        use core::future::ready;
+        use core::num::ParseIntError;
+        #[derive(Tag)]
+        #[transparent(Future)]
+        enum JobTag {}
+        type ParseError = TaggedType<ParseIntError, ParseErrorTag>;
+        #[derive(Tag)]
+        #[transparent(Debug, Display, Error)]
+        enum ParseErrorTag {}
+
+        assert_eq!(block_on(TaggedType::<_, JobTag>::new(ready(42))), 42);
+
+        let e = ParseError::new("x".parse::<u16>().expect_err("not a number"));
+        let e: &dyn Error = &e;
+        assert!(e.source().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "support_io")]
+    fn test_derive_transparent_io() {
+        use std::io::Cursor;
+        use std::io::Read as _;
+        use std::io::Seek as _;
+        use std::io::SeekFrom;
+        use std::io::Write as _;
+        type AuditLog = TaggedType<Cursor<Vec<u8>>, AuditLogTag>;
+        #[derive(Tag)]
+        #[transparent(Read, Write, Seek)]
+        enum AuditLogTag {}
+
+        let mut log = AuditLog::new(Cursor::new(vec![]));
+        log.write_all(b"login").expect("in-memory IO");
+        log.seek(SeekFrom::Start(0)).expect("in-memory IO");
+        let mut line = String::new();
+        log.read_to_string(&mut line).expect("in-memory IO");
+        assert_eq!(line, "login");
+    }
+
+    #[test]
+    fn test_derive_transparent_try_from() {
+        type Initial = TaggedType<char, InitialTag>;
+        #[derive(Tag)]
+        #[transparent(TryFrom)]
+        #[capability(inner_access)]
+        enum InitialTag {}
+
+        assert_eq!(
+            *Initial::try_new_from(0x41_u32).expect("valid char").inner(),
+            'A'
+        );
+    }
+
+    #[test]
+    fn test_derive_implied() {
+        use alloc::collections::BTreeSet;
+        type Priority = TaggedType<u32, PriorityTag>;
+        #[derive(Tag)]
+        #[implement(Ord, Eq, Copy)]
+        enum PriorityTag {}
+
+        let p = Priority::new(1);
+        let set: BTreeSet<_> = vec![p, p].into_iter().collect();
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn test_derive_as_ref_as_mut() {
+        use core::convert::AsMut as _;
+        use core::convert::AsRef as _;
+        type Buffer = TaggedType<Vec<u8>, BufferTag>;
+        #[derive(Tag)]
+        #[implement(AsRef, AsMut)]
+        enum BufferTag {}
+
+        let mut buffer = Buffer::new(vec![]);
+        buffer.as_mut().push(1);
+        let v: &Vec<u8> = buffer.as_ref();
+        assert_eq!(v, &[1]);
+    }
+
+    #[test]
+    #[cfg(feature = "provide_permissive")]
     fn test_derive_permissive() {
         #[derive(Tag)]
         #[permissive]
@@ -108,4 +537,248 @@ mod tests {
         let c = CounterU64::default();
         assert_eq!(*c.inner(), 0);
     }
+
+    #[test]
+    fn test_derive_truncated() {
+        type Body = TaggedType<String, BodyTag>;
+        #[derive(Tag)]
+        #[truncated(Display, Debug, max_len = 5)]
+        enum BodyTag {}
+
+        let body = Body::new("Hello, world".into());
+        assert_eq!(format!("{body}"), "Hello…");
+        assert_eq!(format!("{body:?}"), r#""Hell…"#);
+    }
+
+    #[test]
+    fn test_derive_release_redacted() {
+        type Email = TaggedType<String, EmailTag>;
+        #[derive(Tag)]
+        #[release_redacted]
+        enum EmailTag {}
+
+        type Phone = TaggedType<String, PhoneTag>;
+        #[derive(Tag)]
+        #[release_redacted(placeholder = "<phone>")]
+        enum PhoneTag {}
+
+        let email = Email::new("admin@example.com".into());
+        let phone = Phone::new("+100".into());
+        if cfg!(debug_assertions) {
+            assert_eq!(
+                format!("{email} {email:?} {phone}"),
+                r#"admin@example.com "admin@example.com" +100"#
+            );
+        } else {
+            assert_eq!(format!("{email} {email:?} {phone}"), "*** *** <phone>");
+        }
+    }
+
+    #[test]
+    fn test_derive_display_with_unit() {
+        type Latency = TaggedType<u64, LatencyTag>;
+        #[derive(Tag)]
+        #[display_with_unit(suffix = "ms")]
+        enum LatencyTag {}
+        type Price = TaggedType<u32, PriceTag>;
+        #[derive(Tag)]
+        #[display_with_unit(prefix = "€")]
+        enum PriceTag {}
+
+        assert_eq!(
+            format!("{} {}", Latency::new(15), Price::new(10)),
+            "15ms €10"
+        );
+    }
+
+    #[test]
+    fn test_derive_default_value() {
+        type Retries = TaggedType<u32, RetriesTag>;
+        #[derive(Tag)]
+        #[default_value = 3]
+        #[capability(inner_access)]
+        enum RetriesTag {}
+
+        type Offset = TaggedType<i64, OffsetTag>;
+        #[derive(Tag)]
+        #[default_value(-1)]
+        #[capability(inner_access)]
+        enum OffsetTag {}
+
+        type Region = TaggedType<String, RegionTag>;
+        #[derive(Tag)]
+        #[default_value = "unknown"]
+        #[capability(inner_access)]
+        enum RegionTag {}
+
+        const DEFAULT_TIMEOUT_MS: u32 = 1500;
+        type TimeoutMs = TaggedType<u64, TimeoutMsTag>;
+        #[derive(Tag)]
+        #[default_value(u32 = DEFAULT_TIMEOUT_MS)]
+        #[capability(inner_access)]
+        enum TimeoutMsTag {}
+
+        assert_eq!(*Retries::default().inner(), 3);
+        assert_eq!(*Offset::default().inner(), -1);
+        assert_eq!(Region::default().inner(), "unknown");
+        assert_eq!(*TimeoutMs::default().inner(), 1500);
+    }
+
+    #[test]
+    fn test_derive_validate() {
+        type Username = TaggedType<String, UsernameTag>;
+        #[derive(Tag)]
+        #[validate(length(min = 1, max = 8))]
+        enum UsernameTag {}
+
+        type Port = TaggedType<u16, PortTag>;
+        #[derive(Tag)]
+        #[validate(range(min = 1, max = 65535))]
+        enum PortTag {}
+
+        type Age = TaggedType<u8, AgeTag>;
+        #[derive(Tag)]
+        #[transparent(FromStr)]
+        #[validate(range(max = 150))]
+        enum AgeTag {}
+
+        type Ratio = TaggedType<f32, RatioTag>;
+        #[derive(Tag)]
+        #[validate(range(min = -1, max = 1.0))]
+        enum RatioTag {}
+
+        assert!(Username::try_new("админ".into()).is_ok());
+        assert_eq!(
+            Username::try_new(String::new())
+                .err()
+                .map(|e| e.to_string()),
+            Some("Username: length must be in range 1..=8".into())
+        );
+        assert!(Username::try_new("administrator".into()).is_err());
+        assert!(Port::try_new(8080).is_ok());
+        assert_eq!(
+            Port::try_new(0).err(),
+            Some(InvalidTagged::new("must be in range 1..=65535"))
+        );
+        assert!(Ratio::try_new(-0.5).is_ok());
+        assert!(Ratio::try_new(1.5).is_err());
+        assert!(Ratio::try_new(f32::NAN).is_err());
+        assert!("42".parse::<Age>().is_ok());
+        assert!(matches!(
+            "-1".parse::<Age>(),
+            Err(ParseValidatedError::Parse(_))
+        ));
+        assert_eq!(
+            "200".parse::<Age>().err().map(|e| e.to_string()),
+            Some("Age: must be at most 150".into())
+        );
+    }
+
+    #[test]
+    fn test_derive_normalize() {
+        type Email = TaggedType<String, EmailTag>;
+        #[derive(Tag)]
+        #[transparent(FromStr)]
+        #[capability(inner_access)]
+        #[normalize(trim, lowercase)]
+        #[validate(length(min = 3))]
+        enum EmailTag {}
+
+        type Code = TaggedType<String, CodeTag>;
+        #[derive(Tag)]
+        #[transparent(FromStr)]
+        #[capability(inner_access)]
+        #[normalize]
+        enum CodeTag {}
+        impl Normalize<String> for CodeTag {
+            fn normalize(v: String) -> String {
+                v.replace('-', "")
+            }
+        }
+
+        let email: Email = " A@B.c ".parse().expect("valid email");
+        assert_eq!(email.inner(), "a@b.c");
+        assert!(" a  ".parse::<Email>().is_err());
+        assert_eq!("12-34".parse::<Code>().unwrap().inner(), "1234");
+        assert_eq!(
+            Email::try_new(" A@B.c ".into())
+                .expect("valid email")
+                .inner(),
+            "a@b.c"
+        );
+        assert_eq!(Code::new("1-2".into()).inner(), "12");
+    }
+
+    #[cfg(feature = "support_serde")]
+    #[test]
+    fn test_derive_validate_deserialize() {
+        type Username = TaggedType<String, UsernameTag>;
+        #[derive(Tag)]
+        #[transparent(Deserialize)]
+        #[capability(inner_access)]
+        #[normalize(trim)]
+        #[validate(length(min = 1))]
+        enum UsernameTag {}
+
+        assert_eq!(
+            serde_json::from_str::<Username>(r#"" admin""#)
+                .expect("valid JSON")
+                .inner(),
+            "admin"
+        );
+        assert_eq!(
+            serde_json::from_str::<Username>("\" \"")
+                .err()
+                .map(|e| e.to_string()),
+            Some("Username: length must be at least 1".into())
+        );
+    }
+
+    #[cfg(feature = "support_serde")]
+    #[test]
+    fn test_derive_impl_snapshot() {
+        type Password = TaggedType<String, PasswordTag>;
+        #[derive(Tag)]
+        #[implement(PartialEq, Clone)]
+        #[transparent(Deserialize)]
+        #[impl_snapshot(String: PartialEq, Clone, Deserialize)]
+        enum PasswordTag {}
+
+        type UserId = TaggedType<u64, UserIdTag>;
+        #[derive(Tag)]
+        #[permissive]
+        #[impl_snapshot(u64: Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default,
+                        Debug, Display, FromStr, From, Add, Sub, Mul, Div,
+                        Serialize, Deserialize)]
+        enum UserIdTag {}
+
+        let password = Password::new("secret".into());
+        assert!(password.clone() == password);
+        assert_eq!(UserId::from(1) + 1, UserId::new(2));
+    }
+
+    #[test]
+    #[cfg(feature = "provide_permissive")]
+    fn test_domain() {
+        domain! {
+            user {
+                Id: u64 [permissive],
+                Name: String [Clone, PartialEq, Debug, inner_access],
+                Age: u8 [inner_access, validated(range(min = 18, max = 150))],
+            }
+            group {
+                Id: u64 [Default, inner_access],
+            }
+        }
+        let id = user::Id::new(1);
+        let name = user::Name::new("admin".into());
+        assert_eq!(name.clone(), name);
+        assert_eq!(name.inner(), "admin");
+        assert_eq!(*id.inner(), *group::Id::default().inner() + 1);
+        assert_eq!(user::Age::try_new(18).map(|v| *v.inner()).ok(), Some(18));
+        assert_eq!(
+            user::Age::try_new(7).err().map(|e| e.to_string()),
+            Some("Age: must be in range 18..=150".into())
+        );
+    }
 }
@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: MIT
+
+use crate::FromInner;
+use crate::InnerAccess;
+use crate::TaggedType;
+
+/// Newtype defined without `TaggedType` (for example by `nutype` or
+/// `aliri_braid`) that can be converted to / from `TaggedType` with
+/// the same inner type.
+///
+/// Usually implemented by [`foreign_newtype!`](crate::foreign_newtype) macro.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ForeignNewtype, FromInner, InnerAccess, Plain};
+/// use core::convert::Infallible;
+///
+/// // Newtype that existed before migration.
+/// pub struct LegacyUsername(String);
+///
+/// impl ForeignNewtype for LegacyUsername {
+///     type Inner = String;
+///     type Error = Infallible;
+///     fn into_inner(self) -> String {
+///         self.0
+///     }
+///     fn try_from_inner(v: String) -> Result<Self, Infallible> {
+///         Ok(Self(v))
+///     }
+/// }
+///
+/// pub type Username = TaggedType<String, UsernameTag>;
+/// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {};
+/// impl InnerAccess for UsernameTag {};
+/// impl FromInner for UsernameTag {};
+///
+/// let username = Username::from_newtype(LegacyUsername("admin".into()));
+/// let legacy: LegacyUsername = username.try_into_newtype().unwrap();
+/// ```
+pub trait ForeignNewtype: Sized {
+    /// Type wrapped by the newtype.
+    type Inner;
+    /// Error of construction of the newtype.
+    type Error;
+
+    /// Convert newtype to inner data.
+    fn into_inner(self) -> Self::Inner;
+
+    /// Create newtype from inner data.
+    ///
+    /// # Errors
+    ///
+    /// Will return error if newtype rejects inner data.
+    fn try_from_inner(v: Self::Inner) -> Result<Self, Self::Error>;
+}
+
+impl<V, T: FromInner> TaggedType<V, T> {
+    /// Create `TaggedType` from foreign newtype with the same inner type.
+    #[inline]
+    pub fn from_newtype<N: ForeignNewtype<Inner = V>>(n: N) -> Self {
+        Self::new_raw(n.into_inner())
+    }
+}
+
+impl<V, T: InnerAccess> TaggedType<V, T> {
+    /// Convert `TaggedType` to foreign newtype with the same inner type.
+    ///
+    /// # Errors
+    ///
+    /// Will return error if newtype rejects inner data.
+    #[inline]
+    pub fn try_into_newtype<N: ForeignNewtype<Inner = V>>(self) -> Result<N, N::Error> {
+        N::try_from_inner(self.into_inner())
+    }
+}
+
+/// Implement [`ForeignNewtype`](crate::ForeignNewtype) for newtype defined
+/// by other library.
+///
+/// Infallible construction (`aliri_braid` braids, `nutype` without
+/// validation):
+/// ```rust
+/// pub struct Username(String);
+/// impl Username {
+///     pub fn new(v: String) -> Self { Self(v) }
+///     pub fn into_inner(self) -> String { self.0 }
+/// }
+///
+/// tagged_types::foreign_newtype!(Username: String,
+///     into_inner = Username::into_inner,
+///     from_inner = Username::new);
+/// ```
+///
+/// Fallible construction (`nutype` with validation):
+/// ```rust
+/// pub struct Port(u16);
+/// #[derive(Debug)]
+/// pub struct PortError;
+/// impl Port {
+///     pub fn try_new(v: u16) -> Result<Self, PortError> {
+///         if v == 0 { Err(PortError) } else { Ok(Self(v)) }
+///     }
+///     pub fn into_inner(self) -> u16 { self.0 }
+/// }
+///
+/// tagged_types::foreign_newtype!(Port: u16,
+///     into_inner = Port::into_inner,
+///     try_from_inner = Port::try_new,
+///     error = PortError);
+///
+/// pub type NetPort = tagged_types::TaggedType<u16, NetPortTag>;
+/// pub enum NetPortTag {}
+/// impl tagged_types::Plain for NetPortTag {}
+/// impl tagged_types::InnerAccess for NetPortTag {}
+///
+/// assert!(NetPort::new(0).try_into_newtype::<Port>().is_err());
+/// assert!(NetPort::new(22).try_into_newtype::<Port>().is_ok());
+/// ```
+#[macro_export]
+macro_rules! foreign_newtype {
+    ($newtype:ty : $inner:ty, into_inner = $into_inner:expr, from_inner = $from_inner:expr $(,)?) => {
+        impl $crate::ForeignNewtype for $newtype {
+            type Inner = $inner;
+            type Error = ::core::convert::Infallible;
+
+            #[inline]
+            fn into_inner(self) -> $inner {
+                ($into_inner)(self)
+            }
+
+            #[inline]
+            fn try_from_inner(v: $inner) -> ::core::result::Result<Self, Self::Error> {
+                ::core::result::Result::Ok(($from_inner)(v))
+            }
+        }
+    };
+    ($newtype:ty : $inner:ty, into_inner = $into_inner:expr, try_from_inner = $try_from_inner:expr, error = $error:ty $(,)?) => {
+        impl $crate::ForeignNewtype for $newtype {
+            type Inner = $inner;
+            type Error = $error;
+
+            #[inline]
+            fn into_inner(self) -> $inner {
+                ($into_inner)(self)
+            }
+
+            #[inline]
+            fn try_from_inner(v: $inner) -> ::core::result::Result<Self, Self::Error> {
+                ($try_from_inner)(v)
+            }
+        }
+    };
+}
@@ -0,0 +1,14 @@
+// SPDX-License-Identifier: MIT
+
+/// Currency tags and conversion between currencies.
+pub mod money;
+
+/// Units of International System of Units (SI).
+pub mod si;
+
+/// Fixed-size byte arrays: keys, digests, nonces.
+pub mod bytes;
+
+/// Positions in text: byte offsets and lengths, line and column
+/// numbers.
+pub mod text;
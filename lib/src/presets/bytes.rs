@@ -0,0 +1,439 @@
+// SPDX-License-Identifier: MIT
+
+use crate::error::ByteArrayError;
+use crate::error::ByteArrayErrorKind;
+use crate::AsRef;
+use crate::Cloned;
+use crate::DebugTag;
+use crate::DisplayTag;
+use crate::ImplementClone;
+use crate::ImplementCopy;
+use crate::ImplementDeref;
+use crate::InnerAccess;
+use crate::ParseTag;
+use crate::Plain;
+#[cfg(feature = "support_serde")]
+use crate::SerializeTag;
+use crate::TaggedType;
+use crate::TransparentDebug;
+use crate::TransparentDisplay;
+use core::convert::Infallible;
+use core::convert::TryFrom as _;
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::hash::Hash;
+use core::hash::Hasher;
+use core::hint::black_box;
+use core::marker::PhantomData;
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Tag for fixed-size byte arrays: keys, digests, nonces.
+///
+/// `TaggedType<[u8; N], ByteArray<Tag>>`:
+/// - is parsed (`FromStr`) from hex and formatted (`Display` / `Debug`)
+///   as lowercase hex if `Tag` implements `TransparentDisplay` /
+///   `TransparentDebug`, so secret keys are not printed by accident;
+/// - is converted from / to base64 (standard alphabet with padding,
+///   non-zero padding bits are rejected);
+/// - is created from slice with length check by `from_slice`;
+/// - implements `PartialEq` / `Eq` in constant time and `Hash`;
+/// - is serialized as hex string for human readable formats and
+///   as bytes otherwise.
+///
+/// Capabilities `Clone`, `Copy`, `Deref`, inner access, `as_ref` and
+/// `cloned` are inherited from `Tag`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementClone, ImplementCopy, TransparentDisplay};
+/// use tagged_types::presets::bytes::ByteArray;
+/// pub type Sha256 = TaggedType<[u8; 32], ByteArray<Sha256Tag>>;
+/// pub enum Sha256Tag {}
+/// impl ImplementClone for Sha256Tag {}
+/// impl ImplementCopy for Sha256Tag {}
+/// impl TransparentDisplay for Sha256Tag {}
+///
+/// let digest: Sha256 = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(digest.to_base64(), "47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=");
+/// assert!(Sha256::from_slice(&[0; 31]).is_err());
+/// assert!(digest.to_string().starts_with("e3b0c442"));
+/// ```
+pub struct ByteArray<Tag> {
+    _marker: PhantomData<Tag>,
+    _never: Infallible,
+}
+
+impl<const N: usize, T> TaggedType<[u8; N], ByteArray<T>> {
+    /// Create `TaggedType` from slice of exactly `N` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Will return error if length of slice is not `N`.
+    #[inline]
+    pub fn from_slice(v: &[u8]) -> Result<Self, ByteArrayError<Self>> {
+        <[u8; N]>::try_from(v).map(Self::new_raw).map_err(|_| {
+            ByteArrayError::new(ByteArrayErrorKind::InvalidLength {
+                expected: N,
+                actual: v.len(),
+            })
+        })
+    }
+
+    /// Create `TaggedType` from hex string (case insensitive).
+    ///
+    /// # Errors
+    ///
+    /// Will return error if string is not hex or does not encode
+    /// exactly `N` bytes.
+    pub fn from_hex(s: &str) -> Result<Self, ByteArrayError<Self>> {
+        let s = s.as_bytes();
+        if s.len() % 2 != 0 {
+            return Err(ByteArrayError::new(ByteArrayErrorKind::InvalidHex));
+        }
+        if s.len() / 2 != N {
+            return Err(ByteArrayError::new(ByteArrayErrorKind::InvalidLength {
+                expected: N,
+                actual: s.len() / 2,
+            }));
+        }
+        let mut out = [0; N];
+        for (b, pair) in out.iter_mut().zip(s.chunks_exact(2)) {
+            let (hi, lo) = hex_value(pair[0])
+                .zip(hex_value(pair[1]))
+                .ok_or_else(|| ByteArrayError::new(ByteArrayErrorKind::InvalidHex))?;
+            *b = hi << 4 | lo;
+        }
+        Ok(Self::new_raw(out))
+    }
+
+    /// Returns lowercase hex representation.
+    #[must_use]
+    pub fn to_hex(&self) -> String {
+        Hex(self.value()).to_string()
+    }
+
+    /// Create `TaggedType` from base64 string (standard alphabet
+    /// with padding).
+    ///
+    /// # Errors
+    ///
+    /// Will return error if string is not base64 or does not encode
+    /// exactly `N` bytes.
+    pub fn from_base64(s: &str) -> Result<Self, ByteArrayError<Self>> {
+        let s = s.as_bytes();
+        let invalid = || ByteArrayError::new(ByteArrayErrorKind::InvalidBase64);
+        if s.len() % 4 != 0 {
+            return Err(invalid());
+        }
+        let padding = s.iter().rev().take_while(|c| **c == b'=').count();
+        if padding > 2 {
+            return Err(invalid());
+        }
+        let actual = s.len() / 4 * 3 - padding;
+        if actual != N {
+            return Err(ByteArrayError::new(ByteArrayErrorKind::InvalidLength {
+                expected: N,
+                actual,
+            }));
+        }
+        let data_len = s.len() - padding;
+        let mut out = [0; N];
+        let mut pos = 0;
+        for (i, chunk) in s.chunks_exact(4).enumerate() {
+            let mut acc: u32 = 0;
+            for (j, c) in chunk.iter().enumerate() {
+                let v = if i * 4 + j < data_len {
+                    base64_value(*c).ok_or_else(invalid)?
+                } else {
+                    0
+                };
+                acc = acc << 6 | u32::from(v);
+            }
+            for b in &acc.to_be_bytes()[1..] {
+                if pos < N {
+                    out[pos] = *b;
+                    pos += 1;
+                } else if *b != 0 {
+                    // Bits of the last character that are cut by padding.
+                    return Err(invalid());
+                }
+            }
+        }
+        Ok(Self::new_raw(out))
+    }
+
+    /// Returns base64 representation (standard alphabet with padding).
+    #[must_use]
+    pub fn to_base64(&self) -> String {
+        let mut s = String::with_capacity(N.div_ceil(3) * 4);
+        for chunk in self.value().chunks(3) {
+            let mut bytes = [0; 4];
+            bytes[1..=chunk.len()].copy_from_slice(chunk);
+            let acc = u32::from_be_bytes(bytes);
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    s.push(char::from(BASE64[(acc >> (18 - 6 * i) & 0x3f) as usize]));
+                } else {
+                    s.push('=');
+                }
+            }
+        }
+        s
+    }
+
+    /// Compare bytes in constant time.
+    #[must_use]
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        let diff = self
+            .value()
+            .iter()
+            .zip(other.value().iter())
+            .fold(0, |diff, (a, b)| diff | (a ^ b));
+        black_box(diff) == 0
+    }
+}
+
+const fn hex_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+fn base64_value(c: u8) -> Option<u8> {
+    BASE64
+        .iter()
+        .position(|v| *v == c)
+        .and_then(|v| u8::try_from(v).ok())
+}
+
+/// Displays bytes as lowercase hex.
+struct Hex<'a>(&'a [u8]);
+
+impl Display for Hex<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.0.iter().try_for_each(|b| write!(f, "{b:02x}"))
+    }
+}
+
+impl Debug for Hex<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(self, f)
+    }
+}
+
+impl<const N: usize, T: TransparentDisplay> DisplayTag<[u8; N]> for ByteArray<T> {
+    #[inline]
+    fn fmt(v: &[u8; N], f: &mut Formatter<'_>) -> FmtResult {
+        T::fmt_display(&Hex(v), f)
+    }
+}
+
+impl<const N: usize, T: TransparentDebug> DebugTag<[u8; N]> for ByteArray<T> {
+    #[inline]
+    fn fmt(v: &[u8; N], f: &mut Formatter<'_>) -> FmtResult {
+        T::fmt_debug(&Hex(v), f)
+    }
+}
+
+impl<const N: usize, T> ParseTag<[u8; N]> for ByteArray<T> {
+    type Err = ByteArrayError<TaggedType<[u8; N], Self>>;
+
+    #[inline]
+    fn parse(s: &str) -> Result<[u8; N], Self::Err> {
+        TaggedType::from_hex(s).map(TaggedType::into_value)
+    }
+}
+
+impl<const N: usize, T> PartialEq for TaggedType<[u8; N], ByteArray<T>> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other)
+    }
+}
+
+impl<const N: usize, T> Eq for TaggedType<[u8; N], ByteArray<T>> {}
+
+impl<const N: usize, T> Hash for TaggedType<[u8; N], ByteArray<T>> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value().hash(state);
+    }
+}
+
+#[cfg(feature = "support_serde")]
+impl<const N: usize, T> SerializeTag<[u8; N]> for ByteArray<T> {
+    fn serialize<S: serde::Serializer>(v: &[u8; N], serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&Hex(v))
+        } else {
+            serializer.serialize_bytes(v)
+        }
+    }
+}
+
+#[cfg(feature = "support_serde")]
+impl<'de, const N: usize, T> serde::Deserialize<'de> for TaggedType<[u8; N], ByteArray<T>> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+        use serde::de::SeqAccess;
+        use serde::de::Visitor;
+
+        struct ByteArrayVisitor<const N: usize, T>(PhantomData<T>);
+
+        impl<'de, const N: usize, T> Visitor<'de> for ByteArrayVisitor<N, T> {
+            type Value = TaggedType<[u8; N], ByteArray<T>>;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+                write!(f, "{N} bytes as hex string or byte array")
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+                Self::Value::from_hex(v).map_err(E::custom)
+            }
+
+            fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                Self::Value::from_slice(v).map_err(E::custom)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut out = [0; N];
+                for (i, b) in out.iter_mut().enumerate() {
+                    *b = seq
+                        .next_element()?
+                        .ok_or_else(|| A::Error::invalid_length(i, &self))?;
+                }
+                if seq.next_element::<u8>()?.is_some() {
+                    return Err(A::Error::invalid_length(N + 1, &self));
+                }
+                Ok(TaggedType::new_raw(out))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(ByteArrayVisitor(PhantomData))
+        } else {
+            deserializer.deserialize_bytes(ByteArrayVisitor(PhantomData))
+        }
+    }
+}
+
+macro_rules! inherit_marker {
+    ($($marker:ident),*) => {
+        $(
+            impl<T: $marker> $marker for ByteArray<T> {}
+        )*
+    };
+}
+
+inherit_marker!(
+    AsRef,
+    Cloned,
+    ImplementClone,
+    ImplementCopy,
+    ImplementDeref,
+    InnerAccess
+);
+
+impl<T> Plain for ByteArray<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    enum NonceTag {}
+    type Nonce = TaggedType<[u8; 4], ByteArray<NonceTag>>;
+    impl TransparentDisplay for NonceTag {}
+    impl TransparentDebug for NonceTag {}
+
+    #[test]
+    fn test_hex() {
+        let nonce: Nonce = "DEADbeef".parse().expect("valid hex");
+        assert_eq!(nonce.to_string(), "deadbeef");
+        assert_eq!(format!("{nonce:?}"), "deadbeef");
+        assert_eq!(nonce.to_hex(), "deadbeef");
+        assert_eq!(
+            Nonce::from_hex("deadbe").expect_err("invalid hex").kind(),
+            ByteArrayErrorKind::InvalidLength {
+                expected: 4,
+                actual: 3
+            }
+        );
+        assert_eq!(
+            Nonce::from_hex("deadbeeg").expect_err("invalid hex").kind(),
+            ByteArrayErrorKind::InvalidHex
+        );
+    }
+
+    #[test]
+    fn test_base64() {
+        type Key = TaggedType<[u8; 5], ByteArray<NonceTag>>;
+        let nonce = Nonce::from_slice(b"abcd").expect("slice of array length");
+        assert_eq!(nonce.to_base64(), "YWJjZA==");
+        assert!(Nonce::from_base64("YWJjZA==").expect("valid base64") == nonce);
+        assert_eq!(
+            Key::from_slice(b"abcde")
+                .expect("slice of array length")
+                .to_base64(),
+            "YWJjZGU="
+        );
+        assert_eq!(
+            Key::from_base64("YWJjZGU=").expect("valid base64").to_hex(),
+            "6162636465"
+        );
+        assert_eq!(
+            Nonce::from_base64("YW=jZA==")
+                .expect_err("invalid base64")
+                .kind(),
+            ByteArrayErrorKind::InvalidBase64
+        );
+        assert_eq!(
+            Nonce::from_base64("YWJjZB==")
+                .expect_err("invalid base64")
+                .kind(),
+            ByteArrayErrorKind::InvalidBase64
+        );
+        assert_eq!(
+            Key::from_base64("YWJjZGV=")
+                .expect_err("invalid base64")
+                .kind(),
+            ByteArrayErrorKind::InvalidBase64
+        );
+        assert_eq!(
+            Nonce::from_base64("YWJj")
+                .expect_err("invalid base64")
+                .kind(),
+            ByteArrayErrorKind::InvalidLength {
+                expected: 4,
+                actual: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_eq() {
+        let nonce = Nonce::from_slice(&[1, 2, 3, 4]).expect("slice of array length");
+        assert!(nonce == Nonce::new([1, 2, 3, 4]));
+        assert!(nonce != Nonce::new([1, 2, 3, 5]));
+        assert!(Nonce::from_slice(&[1, 2, 3]).is_err());
+    }
+
+    #[cfg(feature = "support_serde")]
+    #[test]
+    fn test_serde() {
+        let nonce = Nonce::new([1, 2, 3, 4]);
+        assert_eq!(
+            serde_json::to_string(&nonce).expect("serializable value"),
+            r#""01020304""#
+        );
+        assert!(serde_json::from_str::<Nonce>(r#""01020304""#).expect("valid JSON") == nonce);
+        assert!(serde_json::from_str::<Nonce>(r#""010203""#).is_err());
+    }
+}
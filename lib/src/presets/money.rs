@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: MIT
+
+use crate::DisplayTag;
+use crate::FromInner;
+use crate::ImplementAdd;
+use crate::ImplementAddAssign;
+use crate::ImplementClone;
+use crate::ImplementCopy;
+use crate::ImplementDefault;
+use crate::ImplementDiv;
+use crate::ImplementDivAssign;
+use crate::ImplementEq;
+use crate::ImplementHash;
+use crate::ImplementMul;
+use crate::ImplementMulAssign;
+use crate::ImplementOrd;
+use crate::ImplementPartialEq;
+use crate::ImplementPartialOrd;
+use crate::ImplementSub;
+use crate::ImplementSubAssign;
+use crate::InnerAccess;
+use crate::Plain;
+use crate::TaggedType;
+use crate::TransparentDebug;
+#[cfg(feature = "support_serde")]
+use crate::TransparentDeserialize;
+#[cfg(feature = "support_serde")]
+use crate::TransparentSerialize;
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::marker::PhantomData;
+use core::ops::Mul;
+
+/// Tag of `Money` that defines currency.
+///
+/// Currencies of this module implement comparison, `Hash`, `Default`,
+/// `Clone` / `Copy`, inner access, `From<V>`, `Debug`, serde, and
+/// `Add` / `Sub` / `Mul` / `Div` (and assignment versions) with
+/// inner value. `Display` prints amount followed by currency code.
+pub trait Currency {
+    /// ISO 4217 code of the currency.
+    const CODE: &'static str;
+}
+
+/// Amount of money in currency `C`.
+///
+/// Inner value is expected to be decimal type (for example
+/// `rust_decimal::Decimal`). Values of different currencies cannot be
+/// mixed in arithmetic. They are converted using `ExchangeRate`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::presets::money::{Money, Usd};
+/// let price = Money::<i64, Usd>::new(1250);
+/// assert_eq!(format!("{}", price + 50), "1300 USD");
+/// ```
+pub type Money<V, C> = TaggedType<V, C>;
+
+macro_rules! currency {
+    ($($name:ident => $code:literal),* $(,)?) => {
+        $(
+            #[doc = concat!("Currency ", $code, ".")]
+            pub enum $name {}
+
+            impl Currency for $name {
+                const CODE: &'static str = $code;
+            }
+
+            impl<V: Display + ?Sized> DisplayTag<V> for $name {
+                #[inline]
+                fn fmt(v: &V, f: &mut Formatter<'_>) -> FmtResult {
+                    write!(f, "{v} {}", Self::CODE)
+                }
+            }
+
+            impl FromInner for $name {}
+            impl ImplementAdd for $name {}
+            impl ImplementAddAssign for $name {}
+            impl ImplementClone for $name {}
+            impl ImplementCopy for $name {}
+            impl ImplementDefault for $name {}
+            impl ImplementDiv for $name {}
+            impl ImplementDivAssign for $name {}
+            impl ImplementEq for $name {}
+            impl ImplementHash for $name {}
+            impl ImplementMul for $name {}
+            impl ImplementMulAssign for $name {}
+            impl ImplementOrd for $name {}
+            impl ImplementPartialEq for $name {}
+            impl ImplementPartialOrd for $name {}
+            impl ImplementSub for $name {}
+            impl ImplementSubAssign for $name {}
+            impl InnerAccess for $name {}
+            impl Plain for $name {}
+            impl TransparentDebug for $name {}
+            #[cfg(feature = "support_serde")]
+            impl TransparentDeserialize for $name {}
+            #[cfg(feature = "support_serde")]
+            impl TransparentSerialize for $name {}
+        )*
+    };
+}
+
+currency!(
+    Usd => "USD",
+    Eur => "EUR",
+    Gbp => "GBP",
+    Jpy => "JPY",
+    Chf => "CHF",
+    Cny => "CNY",
+);
+
+/// Rate of conversion of money from currency `From` to currency `To`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::presets::money::{ExchangeRate, Eur, Money, Usd};
+/// let eur_usd = ExchangeRate::<f64, Eur, Usd>::new(1.5);
+/// let usd: Money<f64, Usd> = eur_usd.convert(Money::new(10.0));
+/// assert_eq!(*usd.inner(), 15.0);
+/// ```
+pub struct ExchangeRate<V, From, To> {
+    rate: V,
+    _marker: PhantomData<fn(From) -> To>,
+}
+
+impl<V, From: Currency, To: Currency> ExchangeRate<V, From, To> {
+    /// Create exchange rate: one unit of `From` costs `rate` units
+    /// of `To`.
+    #[inline]
+    pub const fn new(rate: V) -> Self {
+        Self {
+            rate,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Provides reference to the rate.
+    #[inline]
+    pub const fn rate(&self) -> &V {
+        &self.rate
+    }
+
+    /// Convert money from `From` to `To` currency.
+    #[inline]
+    pub fn convert(&self, amount: Money<V, From>) -> Money<V, To>
+    where
+        V: Mul<Output = V> + Clone,
+    {
+        Money::new_raw(amount.into_value() * self.rate.clone())
+    }
+}
+
+impl<V: Clone, From, To> Clone for ExchangeRate<V, From, To> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            rate: self.rate.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<V: Copy, From, To> Copy for ExchangeRate<V, From, To> {}
+
+impl<V: Debug, From: Currency, To: Currency> Debug for ExchangeRate<V, From, To> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}/{} {:?}", From::CODE, To::CODE, self.rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_money() {
+        let price = Money::<i64, Eur>::new(1000);
+        let total = price * 3 - 500;
+        assert_eq!(total.to_string(), "2500 EUR");
+        assert_eq!(format!("{total:?}"), "2500");
+        assert!(price < total);
+        let eur_jpy = ExchangeRate::<i64, Eur, Jpy>::new(160);
+        assert_eq!(format!("{eur_jpy:?}"), "EUR/JPY 160");
+        assert_eq!(*eur_jpy.convert(price).inner(), 160_000);
+    }
+}
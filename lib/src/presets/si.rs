@@ -0,0 +1,210 @@
+// SPDX-License-Identifier: MIT
+
+use crate::DisplayTag;
+use crate::FromInner;
+use crate::ImplementAdd;
+use crate::ImplementAddAssign;
+use crate::ImplementClone;
+use crate::ImplementCopy;
+use crate::ImplementDefault;
+use crate::ImplementDiv;
+use crate::ImplementDivAssign;
+use crate::ImplementEq;
+use crate::ImplementHash;
+use crate::ImplementMul;
+use crate::ImplementMulAssign;
+use crate::ImplementOrd;
+use crate::ImplementPartialEq;
+use crate::ImplementPartialOrd;
+use crate::ImplementSub;
+use crate::ImplementSubAssign;
+use crate::InnerAccess;
+use crate::Plain;
+use crate::TagDiv;
+use crate::TagMul;
+use crate::TagSubOutput;
+use crate::TagUnit;
+use crate::TaggedType;
+use crate::TransparentDebug;
+#[cfg(feature = "support_serde")]
+use crate::TransparentDeserialize;
+#[cfg(feature = "support_serde")]
+use crate::TransparentSerialize;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
+/// Tag of `Quantity` that defines unit of measure.
+///
+/// Units of this module implement comparison, `Hash`, `Default`,
+/// `Clone` / `Copy`, inner access, `From<V>`, `Debug`, serde, and
+/// `Add` / `Sub` / `Mul` / `Div` (and assignment versions) with
+/// inner value. `Display` prints value followed by unit symbol.
+///
+/// Derived units are products and quotients of references to
+/// quantities (`&distance / &time` is `MetersPerSecond`); units without
+/// declared relation cannot be combined:
+/// ```rust,compile_fail
+/// use tagged_types::presets::si::{Meters, Quantity, Seconds};
+/// let distance = Quantity::<f64, Meters>::new(1.5);
+/// let time = Quantity::<f64, Seconds>::new(2.0);
+/// let _ = &distance * &time;
+/// ```
+pub trait Unit {
+    /// Symbol of the unit.
+    const SYMBOL: &'static str;
+}
+
+/// Quantity measured in unit `U`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::presets::si::{Meters, Quantity};
+/// let distance = Quantity::<f64, Meters>::new(1.5);
+/// assert_eq!(format!("{}", distance * 2.0), "3 m");
+/// ```
+pub type Quantity<V, U> = TaggedType<V, U>;
+
+macro_rules! unit {
+    ($($name:ident => $symbol:literal),* $(,)?) => {
+        $(
+            #[doc = concat!("Unit `", $symbol, "`.")]
+            pub enum $name {}
+
+            impl Unit for $name {
+                const SYMBOL: &'static str = $symbol;
+            }
+
+            impl TagUnit for $name {
+                const SUFFIX: &'static str = concat!(" ", $symbol);
+            }
+
+            impl<V: Display + ?Sized> DisplayTag<V> for $name {
+                #[inline]
+                fn fmt(v: &V, f: &mut Formatter<'_>) -> FmtResult {
+                    Self::fmt_with_unit(v, f)
+                }
+            }
+
+            impl FromInner for $name {}
+            impl ImplementAdd for $name {}
+            impl ImplementAddAssign for $name {}
+            impl ImplementClone for $name {}
+            impl ImplementCopy for $name {}
+            impl ImplementDefault for $name {}
+            impl ImplementDiv for $name {}
+            impl ImplementDivAssign for $name {}
+            impl ImplementEq for $name {}
+            impl ImplementHash for $name {}
+            impl ImplementMul for $name {}
+            impl ImplementMulAssign for $name {}
+            impl ImplementOrd for $name {}
+            impl ImplementPartialEq for $name {}
+            impl ImplementPartialOrd for $name {}
+            impl ImplementSub for $name {}
+            impl ImplementSubAssign for $name {}
+            impl TagSubOutput for $name {
+                type Output = $name;
+            }
+            impl InnerAccess for $name {}
+            impl Plain for $name {}
+            impl TransparentDebug for $name {}
+            #[cfg(feature = "support_serde")]
+            impl TransparentDeserialize for $name {}
+            #[cfg(feature = "support_serde")]
+            impl TransparentSerialize for $name {}
+        )*
+    };
+}
+
+unit!(
+    Meters => "m",
+    Seconds => "s",
+    Kilograms => "kg",
+    Amperes => "A",
+    Kelvins => "K",
+    Moles => "mol",
+    Candelas => "cd",
+    SquareMeters => "m²",
+    CubicMeters => "m³",
+    MetersPerSecond => "m/s",
+    MetersPerSecondSquared => "m/s²",
+    Hertz => "Hz",
+    Newtons => "N",
+    Joules => "J",
+    Watts => "W",
+);
+
+macro_rules! relation {
+    ($($lhs:ident $op:tt $rhs:ident => $output:ident),* $(,)?) => {
+        $(relation!(@impl $lhs $op $rhs => $output);)*
+    };
+    (@impl $lhs:ident * $rhs:ident => $output:ident) => {
+        impl TagMul<$rhs> for $lhs {
+            type Output = $output;
+        }
+    };
+    (@impl $lhs:ident / $rhs:ident => $output:ident) => {
+        impl TagDiv<$rhs> for $lhs {
+            type Output = $output;
+        }
+    };
+}
+
+relation!(
+    Meters * Meters => SquareMeters,
+    Meters * SquareMeters => CubicMeters,
+    SquareMeters * Meters => CubicMeters,
+    SquareMeters / Meters => Meters,
+    CubicMeters / Meters => SquareMeters,
+    CubicMeters / SquareMeters => Meters,
+    Meters / Seconds => MetersPerSecond,
+    MetersPerSecond * Seconds => Meters,
+    Seconds * MetersPerSecond => Meters,
+    MetersPerSecond / Seconds => MetersPerSecondSquared,
+    MetersPerSecondSquared * Seconds => MetersPerSecond,
+    Kilograms * MetersPerSecondSquared => Newtons,
+    MetersPerSecondSquared * Kilograms => Newtons,
+    Newtons / Kilograms => MetersPerSecondSquared,
+    Newtons * Meters => Joules,
+    Meters * Newtons => Joules,
+    Joules / Meters => Newtons,
+    Joules / Seconds => Watts,
+    Watts * Seconds => Joules,
+    Seconds * Watts => Joules,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_units() {
+        let area = Quantity::<u32, SquareMeters>::new(12) / 4;
+        assert_eq!(area.to_string(), "3 m²");
+        assert_eq!(*area.inner(), 3);
+        assert!(Quantity::<f64, Seconds>::new(1.0) < Quantity::new(2.0));
+    }
+
+    #[test]
+    fn test_derived_units() {
+        let side = Quantity::<u32, Meters>::new(3);
+        let area: Quantity<u32, SquareMeters> = &side * &side;
+        let volume: Quantity<u32, CubicMeters> = &area * &side;
+        assert_eq!(volume.to_string(), "27 m³");
+        let speed: Quantity<f64, MetersPerSecond> =
+            &Quantity::<f64, Meters>::new(10.0) / &Quantity::<f64, Seconds>::new(4.0);
+        assert_eq!(speed.to_string(), "2.5 m/s");
+        let force: Quantity<f64, Newtons> = &Quantity::<f64, Kilograms>::new(2.0)
+            * &Quantity::<_, MetersPerSecondSquared>::new(3.0);
+        assert_eq!(force.to_string(), "6 N");
+    }
+
+    #[test]
+    fn test_same_unit() {
+        let a = Quantity::<f64, Meters>::new(1.5);
+        let b = Quantity::<f64, Meters>::new(0.5);
+        assert_eq!((&a + &b).to_string(), "2 m");
+        assert_eq!((&a - &b).to_string(), "1 m");
+    }
+}
@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: MIT
+
+use crate::FromInner;
+use crate::ImplementClone;
+use crate::ImplementCopy;
+use crate::ImplementDefault;
+use crate::ImplementEq;
+use crate::ImplementHash;
+use crate::ImplementOrd;
+use crate::ImplementPartialEq;
+use crate::ImplementPartialOrd;
+use crate::InnerAccess;
+use crate::Plain;
+use crate::TaggedType;
+use crate::TransparentDebug;
+#[cfg(feature = "support_serde")]
+use crate::TransparentDeserialize;
+use crate::TransparentDisplay;
+#[cfg(feature = "support_serde")]
+use crate::TransparentSerialize;
+use core::ops::Add;
+use core::ops::Sub;
+
+macro_rules! position {
+    ($($(#[$meta:meta])* $name:ident),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            pub enum $name {}
+
+            impl FromInner for $name {}
+            impl ImplementClone for $name {}
+            impl ImplementCopy for $name {}
+            impl ImplementDefault for $name {}
+            impl ImplementEq for $name {}
+            impl ImplementHash for $name {}
+            impl ImplementOrd for $name {}
+            impl ImplementPartialEq for $name {}
+            impl ImplementPartialOrd for $name {}
+            impl InnerAccess for $name {}
+            impl Plain for $name {}
+            impl TransparentDebug for $name {}
+            impl TransparentDisplay for $name {}
+            #[cfg(feature = "support_serde")]
+            impl TransparentDeserialize for $name {}
+            #[cfg(feature = "support_serde")]
+            impl TransparentSerialize for $name {}
+        )*
+    };
+}
+
+position!(
+    /// Offset of byte in text (`TaggedType<usize, ByteOffset>`).
+    ///
+    /// `ByteOffset ± ByteLen = ByteOffset`,
+    /// `ByteOffset - ByteOffset = ByteLen`. Offsets cannot be added.
+    ///
+    /// Example:
+    /// ```rust
+    /// use tagged_types::TaggedType;
+    /// use tagged_types::presets::text::{ByteLen, ByteOffset};
+    /// let start = TaggedType::<usize, ByteOffset>::new(4);
+    /// let end = start + TaggedType::<usize, ByteLen>::new(3);
+    /// assert_eq!(*(end - start).inner(), 3);
+    /// ```
+    ByteOffset,
+    /// Length of text in bytes (`TaggedType<usize, ByteLen>`).
+    ///
+    /// `ByteLen ± ByteLen = ByteLen`.
+    ByteLen,
+    /// Line number in text (`TaggedType<u32, LineNumber>`).
+    ///
+    /// `LineNumber ± V = LineNumber`, `LineNumber - LineNumber = V`
+    /// where `V` is number of lines.
+    LineNumber,
+    /// Column number in line (`TaggedType<u32, ColumnNumber>`).
+    ///
+    /// `ColumnNumber ± V = ColumnNumber`, `ColumnNumber - ColumnNumber = V`
+    /// where `V` is number of columns.
+    ColumnNumber,
+);
+
+macro_rules! tagged_op {
+    ($($op:ident::$f:ident: $lhs:ty, $rhs:ty => $out:ty;)*) => {
+        $(
+            impl<V: $op<Output = V>> $op<TaggedType<V, $rhs>> for TaggedType<V, $lhs> {
+                type Output = TaggedType<V, $out>;
+                #[inline]
+                fn $f(self, rhs: TaggedType<V, $rhs>) -> Self::Output {
+                    TaggedType::new_raw($op::$f(self.into_value(), rhs.into_value()))
+                }
+            }
+        )*
+    };
+}
+
+tagged_op!(
+    Add::add: ByteOffset, ByteLen => ByteOffset;
+    Sub::sub: ByteOffset, ByteLen => ByteOffset;
+    Sub::sub: ByteOffset, ByteOffset => ByteLen;
+    Add::add: ByteLen, ByteLen => ByteLen;
+    Sub::sub: ByteLen, ByteLen => ByteLen;
+);
+
+macro_rules! counted_op {
+    ($($name:ident),*) => {
+        $(
+            impl<V: Add<Output = V>> Add<V> for TaggedType<V, $name> {
+                type Output = Self;
+                #[inline]
+                fn add(self, rhs: V) -> Self {
+                    Self::new_raw(self.into_value() + rhs)
+                }
+            }
+
+            impl<V: Sub<Output = V>> Sub<V> for TaggedType<V, $name> {
+                type Output = Self;
+                #[inline]
+                fn sub(self, rhs: V) -> Self {
+                    Self::new_raw(self.into_value() - rhs)
+                }
+            }
+
+            impl<V: Sub<Output = V>> Sub for TaggedType<V, $name> {
+                type Output = V;
+                #[inline]
+                fn sub(self, rhs: Self) -> V {
+                    self.into_value() - rhs.into_value()
+                }
+            }
+        )*
+    };
+}
+
+counted_op!(LineNumber, ColumnNumber);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_positions() {
+        let start = TaggedType::<usize, ByteOffset>::new(10);
+        let len = TaggedType::<usize, ByteLen>::new(5);
+        let end = start + len;
+        assert_eq!(*end.inner(), 15);
+        assert_eq!(end - start, len);
+        assert_eq!(end - len, start);
+        assert_eq!(*(len + len - len).inner(), 5);
+    }
+
+    #[test]
+    fn test_line_column() {
+        let line = TaggedType::<u32, LineNumber>::new(3);
+        let column = TaggedType::<u32, ColumnNumber>::new(7);
+        assert_eq!(format!("{}:{}", line + 1, column - 2), "4:5");
+        assert_eq!((line + 4) - line, 4);
+    }
+}
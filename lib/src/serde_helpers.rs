@@ -0,0 +1,560 @@
+// SPDX-License-Identifier: MIT
+
+/// Serialize / deserialize a raw `V` field through `TaggedType<V, T>`.
+///
+/// Serialization requires `TransparentSerialize` for the tag and
+/// deserialization requires `TransparentDeserialize` for the tag.
+///
+/// Tag cannot be inferred by serde, so functions are given to
+/// `serialize_with` / `deserialize_with` with explicit tag.
+/// `serde_as_tagged!` declares module for `#[serde(with = "...")]`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentDeserialize, TransparentSerialize, Plain};
+/// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {};
+/// impl TransparentSerialize for UsernameTag {};
+/// impl TransparentDeserialize for UsernameTag {};
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct User {
+///     #[serde(
+///         serialize_with = "tagged_types::serde_helpers::as_tagged::serialize::<UsernameTag, _, _>",
+///         deserialize_with = "tagged_types::serde_helpers::as_tagged::deserialize::<UsernameTag, _, _>"
+///     )]
+///     name: String,
+/// }
+///
+/// let user: User = serde_json::from_str(r#"{"name":"admin"}"#).unwrap();
+/// assert_eq!(user.name, "admin");
+/// ```
+pub mod as_tagged {
+    use crate::TaggedType;
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serialize;
+    use serde::Serializer;
+
+    /// Serialize `V` as `TaggedType<V, T>`.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of the serializer.
+    #[inline]
+    pub fn serialize<T, V, S>(v: &V, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        for<'a> TaggedType<&'a V, T>: Serialize,
+    {
+        TaggedType::<&V, T>::new_raw(v).serialize(serializer)
+    }
+
+    /// Deserialize `V` as `TaggedType<V, T>`.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of the deserializer.
+    #[inline]
+    pub fn deserialize<'de, T, V, D>(deserializer: D) -> Result<V, D::Error>
+    where
+        D: Deserializer<'de>,
+        TaggedType<V, T>: Deserialize<'de>,
+    {
+        TaggedType::<V, T>::deserialize(deserializer).map(TaggedType::into_value)
+    }
+
+    /// Serialize / deserialize `Option<V>` as `Option<TaggedType<V, T>>`.
+    pub mod option {
+        use crate::TaggedType;
+        use serde::Deserialize;
+        use serde::Deserializer;
+        use serde::Serialize;
+        use serde::Serializer;
+
+        /// Serialize `Option<V>` as `Option<TaggedType<V, T>>`.
+        ///
+        /// # Errors
+        ///
+        /// Will return error of the serializer.
+        #[inline]
+        pub fn serialize<T, V, S>(v: &Option<V>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+            for<'a> TaggedType<&'a V, T>: Serialize,
+        {
+            v.as_ref()
+                .map(TaggedType::<&V, T>::new_raw)
+                .serialize(serializer)
+        }
+
+        /// Deserialize `Option<V>` as `Option<TaggedType<V, T>>`.
+        ///
+        /// # Errors
+        ///
+        /// Will return error of the deserializer.
+        #[inline]
+        pub fn deserialize<'de, T, V, D>(deserializer: D) -> Result<Option<V>, D::Error>
+        where
+            D: Deserializer<'de>,
+            TaggedType<V, T>: Deserialize<'de>,
+        {
+            Option::<TaggedType<V, T>>::deserialize(deserializer)
+                .map(|v| v.map(TaggedType::into_value))
+        }
+    }
+
+    /// Serialize / deserialize `Vec<V>` as `Vec<TaggedType<V, T>>`.
+    pub mod vec {
+        use crate::TaggedType;
+        use serde::Deserialize;
+        use serde::Deserializer;
+        use serde::Serialize;
+        use serde::Serializer;
+
+        /// Serialize `Vec<V>` as `Vec<TaggedType<V, T>>`.
+        ///
+        /// # Errors
+        ///
+        /// Will return error of the serializer.
+        #[inline]
+        pub fn serialize<T, V, S>(v: &[V], serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+            for<'a> TaggedType<&'a V, T>: Serialize,
+        {
+            serializer.collect_seq(v.iter().map(TaggedType::<&V, T>::new_raw))
+        }
+
+        /// Deserialize `Vec<V>` as `Vec<TaggedType<V, T>>`.
+        ///
+        /// # Errors
+        ///
+        /// Will return error of the deserializer.
+        #[inline]
+        pub fn deserialize<'de, T, V, D>(deserializer: D) -> Result<Vec<V>, D::Error>
+        where
+            D: Deserializer<'de>,
+            TaggedType<V, T>: Deserialize<'de>,
+        {
+            Vec::<TaggedType<V, T>>::deserialize(deserializer)
+                .map(|v| v.into_iter().map(TaggedType::into_value).collect())
+        }
+    }
+}
+
+/// Declare module for `#[serde(with = "...")]` that (de)serializes raw
+/// `V` field as `TaggedType<V, Tag>` (`as_tagged` with fixed tag).
+///
+/// Functions of `as_tagged` take the tag as type parameter that serde
+/// cannot infer, so `#[serde(with = "...")]` needs module per tag. The
+/// module sees items of the parent module, its `option` and `vec`
+/// submodules handle `Option<V>` and `Vec<V>` fields.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{Plain, TransparentDeserialize, TransparentSerialize};
+/// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {}
+/// impl TransparentSerialize for UsernameTag {}
+/// impl TransparentDeserialize for UsernameTag {}
+///
+/// tagged_types::serde_as_tagged!(mod username: UsernameTag);
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct User {
+///     #[serde(with = "username")]
+///     name: String,
+///     #[serde(with = "username::option")]
+///     deputy: Option<String>,
+///     #[serde(with = "username::vec")]
+///     aliases: Vec<String>,
+/// }
+///
+/// # fn main() {
+/// let user: User =
+///     serde_json::from_str(r#"{"name":"admin","deputy":null,"aliases":["root"]}"#).unwrap();
+/// assert_eq!(user.name, "admin");
+/// assert_eq!(user.aliases, ["root"]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! serde_as_tagged {
+    ($vis:vis mod $name:ident: $tag:ty) => {
+        $vis mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            /// Serialize `V` as `TaggedType<V, Tag>`.
+            ///
+            /// # Errors
+            ///
+            /// Will return error of the serializer.
+            #[inline]
+            pub fn serialize<V, S>(
+                v: &V,
+                serializer: S,
+            ) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__serde::Serializer,
+                for<'a> $crate::TaggedType<&'a V, $tag>: $crate::__serde::Serialize,
+            {
+                $crate::serde_helpers::as_tagged::serialize::<$tag, V, S>(v, serializer)
+            }
+
+            /// Deserialize `V` as `TaggedType<V, Tag>`.
+            ///
+            /// # Errors
+            ///
+            /// Will return error of the deserializer.
+            #[inline]
+            pub fn deserialize<'de, V, D>(deserializer: D) -> ::core::result::Result<V, D::Error>
+            where
+                D: $crate::__serde::Deserializer<'de>,
+                $crate::TaggedType<V, $tag>: $crate::__serde::Deserialize<'de>,
+            {
+                $crate::serde_helpers::as_tagged::deserialize::<$tag, V, D>(deserializer)
+            }
+
+            /// Serialize / deserialize `Option<V>` as `Option<TaggedType<V, Tag>>`.
+            pub mod option {
+                #[allow(unused_imports)]
+                use super::*;
+
+                /// Serialize `Option<V>` as `Option<TaggedType<V, Tag>>`.
+                ///
+                /// # Errors
+                ///
+                /// Will return error of the serializer.
+                #[inline]
+                #[allow(clippy::ref_option)]
+                pub fn serialize<V, S>(
+                    v: &::core::option::Option<V>,
+                    serializer: S,
+                ) -> ::core::result::Result<S::Ok, S::Error>
+                where
+                    S: $crate::__serde::Serializer,
+                    for<'a> $crate::TaggedType<&'a V, $tag>: $crate::__serde::Serialize,
+                {
+                    $crate::serde_helpers::as_tagged::option::serialize::<$tag, V, S>(v, serializer)
+                }
+
+                /// Deserialize `Option<V>` as `Option<TaggedType<V, Tag>>`.
+                ///
+                /// # Errors
+                ///
+                /// Will return error of the deserializer.
+                #[inline]
+                pub fn deserialize<'de, V, D>(
+                    deserializer: D,
+                ) -> ::core::result::Result<::core::option::Option<V>, D::Error>
+                where
+                    D: $crate::__serde::Deserializer<'de>,
+                    $crate::TaggedType<V, $tag>: $crate::__serde::Deserialize<'de>,
+                {
+                    $crate::serde_helpers::as_tagged::option::deserialize::<$tag, V, D>(deserializer)
+                }
+            }
+
+            /// Serialize / deserialize `Vec<V>` as `Vec<TaggedType<V, Tag>>`.
+            pub mod vec {
+                #[allow(unused_imports)]
+                use super::*;
+
+                /// Serialize `Vec<V>` as `Vec<TaggedType<V, Tag>>`.
+                ///
+                /// # Errors
+                ///
+                /// Will return error of the serializer.
+                #[inline]
+                pub fn serialize<V, S>(
+                    v: &[V],
+                    serializer: S,
+                ) -> ::core::result::Result<S::Ok, S::Error>
+                where
+                    S: $crate::__serde::Serializer,
+                    for<'a> $crate::TaggedType<&'a V, $tag>: $crate::__serde::Serialize,
+                {
+                    $crate::serde_helpers::as_tagged::vec::serialize::<$tag, V, S>(v, serializer)
+                }
+
+                /// Deserialize `Vec<V>` as `Vec<TaggedType<V, Tag>>`.
+                ///
+                /// # Errors
+                ///
+                /// Will return error of the deserializer.
+                #[inline]
+                #[allow(clippy::std_instead_of_alloc)]
+                pub fn deserialize<'de, V, D>(
+                    deserializer: D,
+                ) -> ::core::result::Result<::std::vec::Vec<V>, D::Error>
+                where
+                    D: $crate::__serde::Deserializer<'de>,
+                    $crate::TaggedType<V, $tag>: $crate::__serde::Deserialize<'de>,
+                {
+                    $crate::serde_helpers::as_tagged::vec::deserialize::<$tag, V, D>(deserializer)
+                }
+            }
+        }
+    };
+}
+
+/// Serialize / deserialize `TaggedType<V, T>` field as raw `V`.
+///
+/// Serialization requires `InnerAccess` for the tag and
+/// deserialization requires `FromInner` for the tag. Deserialized
+/// value is normalized as by `From<V>`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, FromInner, InnerAccess, Plain};
+/// pub type Username = TaggedType<String, UsernameTag>;
+/// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {};
+/// impl InnerAccess for UsernameTag {};
+/// impl FromInner for UsernameTag {};
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct User {
+///     #[serde(with = "tagged_types::serde_helpers::inner")]
+///     name: Username,
+/// }
+///
+/// let user: User = serde_json::from_str(r#"{"name":"admin"}"#).unwrap();
+/// assert_eq!(user.name.inner(), "admin");
+/// ```
+pub mod inner {
+    use crate::FromInner;
+    use crate::InnerAccess;
+    use crate::Normalize;
+    use crate::TaggedType;
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serialize;
+    use serde::Serializer;
+
+    /// Serialize `TaggedType<V, T>` as `V`.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of the serializer.
+    #[inline]
+    pub fn serialize<V, T, S>(v: &TaggedType<V, T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        V: Serialize,
+        T: InnerAccess,
+        S: Serializer,
+    {
+        v.inner().serialize(serializer)
+    }
+
+    /// Deserialize `TaggedType<V, T>` as `V`.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of the deserializer.
+    #[inline]
+    pub fn deserialize<'de, V, T, D>(deserializer: D) -> Result<TaggedType<V, T>, D::Error>
+    where
+        V: Deserialize<'de>,
+        T: FromInner + Normalize<V>,
+        D: Deserializer<'de>,
+    {
+        V::deserialize(deserializer).map(TaggedType::from)
+    }
+
+    /// Serialize / deserialize `Option<TaggedType<V, T>>` as `Option<V>`.
+    pub mod option {
+        use crate::FromInner;
+        use crate::InnerAccess;
+        use crate::Normalize;
+        use crate::TaggedType;
+        use serde::Deserialize;
+        use serde::Deserializer;
+        use serde::Serialize;
+        use serde::Serializer;
+
+        /// Serialize `Option<TaggedType<V, T>>` as `Option<V>`.
+        ///
+        /// # Errors
+        ///
+        /// Will return error of the serializer.
+        #[inline]
+        pub fn serialize<V, T, S>(
+            v: &Option<TaggedType<V, T>>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            V: Serialize,
+            T: InnerAccess,
+            S: Serializer,
+        {
+            v.as_ref().map(TaggedType::inner).serialize(serializer)
+        }
+
+        /// Deserialize `Option<TaggedType<V, T>>` as `Option<V>`.
+        ///
+        /// # Errors
+        ///
+        /// Will return error of the deserializer.
+        #[inline]
+        pub fn deserialize<'de, V, T, D>(
+            deserializer: D,
+        ) -> Result<Option<TaggedType<V, T>>, D::Error>
+        where
+            V: Deserialize<'de>,
+            T: FromInner + Normalize<V>,
+            D: Deserializer<'de>,
+        {
+            Option::<V>::deserialize(deserializer).map(|v| v.map(TaggedType::from))
+        }
+    }
+
+    /// Serialize / deserialize `Vec<TaggedType<V, T>>` as `Vec<V>`.
+    pub mod vec {
+        use crate::FromInner;
+        use crate::InnerAccess;
+        use crate::Normalize;
+        use crate::TaggedType;
+        use serde::Deserialize;
+        use serde::Deserializer;
+        use serde::Serialize;
+        use serde::Serializer;
+
+        /// Serialize `Vec<TaggedType<V, T>>` as `Vec<V>`.
+        ///
+        /// # Errors
+        ///
+        /// Will return error of the serializer.
+        #[inline]
+        pub fn serialize<V, T, S>(v: &[TaggedType<V, T>], serializer: S) -> Result<S::Ok, S::Error>
+        where
+            V: Serialize,
+            T: InnerAccess,
+            S: Serializer,
+        {
+            serializer.collect_seq(v.iter().map(TaggedType::inner))
+        }
+
+        /// Deserialize `Vec<TaggedType<V, T>>` as `Vec<V>`.
+        ///
+        /// # Errors
+        ///
+        /// Will return error of the deserializer.
+        #[inline]
+        pub fn deserialize<'de, V, T, D>(deserializer: D) -> Result<Vec<TaggedType<V, T>>, D::Error>
+        where
+            V: Deserialize<'de>,
+            T: FromInner + Normalize<V>,
+            D: Deserializer<'de>,
+        {
+            Vec::<V>::deserialize(deserializer)
+                .map(|v| v.into_iter().map(TaggedType::from).collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    enum UsernameTag {}
+    impl Plain for UsernameTag {}
+    impl TransparentSerialize for UsernameTag {}
+    impl TransparentDeserialize for UsernameTag {}
+    serde_as_tagged!(mod username: UsernameTag);
+
+    #[test]
+    fn test_as_tagged() {
+        enum UsernameTag {}
+        impl Plain for UsernameTag {}
+        impl TransparentSerialize for UsernameTag {}
+        impl TransparentDeserialize for UsernameTag {}
+        #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+        struct Group {
+            #[serde(
+                serialize_with = "crate::serde_helpers::as_tagged::serialize::<UsernameTag, _, _>",
+                deserialize_with = "crate::serde_helpers::as_tagged::deserialize::<UsernameTag, _, _>"
+            )]
+            owner: String,
+            #[serde(
+                serialize_with = "crate::serde_helpers::as_tagged::option::serialize::<UsernameTag, _, _>",
+                deserialize_with = "crate::serde_helpers::as_tagged::option::deserialize::<UsernameTag, _, _>"
+            )]
+            deputy: Option<String>,
+            #[serde(
+                serialize_with = "crate::serde_helpers::as_tagged::vec::serialize::<UsernameTag, _, _>",
+                deserialize_with = "crate::serde_helpers::as_tagged::vec::deserialize::<UsernameTag, _, _>"
+            )]
+            members: Vec<String>,
+        }
+        const JSON: &str = r#"{"owner":"admin","deputy":null,"members":["alice","bob"]}"#;
+        let group: Group = serde_json::from_str(JSON).expect("valid JSON");
+        assert_eq!(
+            group,
+            Group {
+                owner: "admin".into(),
+                deputy: None,
+                members: vec!["alice".into(), "bob".into()],
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&group).expect("serializable value"),
+            JSON
+        );
+    }
+
+    #[test]
+    fn test_serde_as_tagged() {
+        #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+        struct Group {
+            #[serde(with = "username")]
+            owner: String,
+            #[serde(with = "username::option")]
+            deputy: Option<String>,
+            #[serde(with = "username::vec")]
+            members: Vec<String>,
+        }
+        const JSON: &str = r#"{"owner":"admin","deputy":"root","members":["alice"]}"#;
+        let group: Group = serde_json::from_str(JSON).expect("valid JSON");
+        assert_eq!(group.deputy.as_deref(), Some("root"));
+        assert_eq!(
+            serde_json::to_string(&group).expect("serializable value"),
+            JSON
+        );
+    }
+
+    #[test]
+    fn test_inner() {
+        enum UsernameTag {}
+        type Username = TaggedType<String, UsernameTag>;
+        impl Plain for UsernameTag {}
+        impl InnerAccess for UsernameTag {}
+        impl FromInner for UsernameTag {}
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Group {
+            #[serde(with = "crate::serde_helpers::inner")]
+            owner: Username,
+            #[serde(with = "crate::serde_helpers::inner::option")]
+            deputy: Option<Username>,
+            #[serde(with = "crate::serde_helpers::inner::vec")]
+            members: Vec<Username>,
+        }
+        const JSON: &str = r#"{"owner":"admin","deputy":"root","members":["alice"]}"#;
+        let group: Group = serde_json::from_str(JSON).expect("valid JSON");
+        assert_eq!(group.owner.inner(), "admin");
+        assert_eq!(
+            group
+                .deputy
+                .as_ref()
+                .map(Username::inner)
+                .expect("deputy is set"),
+            "root"
+        );
+        assert_eq!(group.members.len(), 1);
+        assert_eq!(
+            serde_json::to_string(&group).expect("serializable value"),
+            JSON
+        );
+    }
+}
@@ -0,0 +1,290 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::hash::Hasher;
+
+const FNV64_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV64_PRIME: u64 = 0x0000_0100_0000_01b3;
+const FNV128_OFFSET: u128 = 0x6c62_272e_07bb_0142_62b8_2175_6295_c58d;
+const FNV128_PRIME: u128 = 0x0000_0000_0100_0000_0000_0000_0000_013b;
+
+/// FNV-1a hasher (64 and 128 bit) that produces the same result on
+/// every platform and in every process.
+///
+/// Algorithm is identified by `VERSION`. Result is stable across
+/// versions of the crate and of Rust only for values fed by
+/// `StableEncode`: `Hash` implementations of `core` / `std` types
+/// (`str`, slices, tuples) are not guaranteed to write the same bytes
+/// in future Rust versions.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::stable_hash::StableHasher;
+/// use core::hash::Hasher;
+/// let mut hasher = StableHasher::new();
+/// hasher.write(b"a");
+/// assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+/// ```
+#[derive(Clone, Debug)]
+pub struct StableHasher {
+    h64: u64,
+    h128: u128,
+}
+
+impl StableHasher {
+    /// Version of the hash algorithm.
+    pub const VERSION: u32 = 1;
+
+    /// Create hasher with initial state.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            h64: FNV64_OFFSET,
+            h128: FNV128_OFFSET,
+        }
+    }
+
+    /// Returns 128-bit hash of values written so far.
+    #[inline]
+    #[must_use]
+    pub const fn finish128(&self) -> u128 {
+        self.h128
+    }
+}
+
+impl Default for StableHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hasher for StableHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.h64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.h64 = (self.h64 ^ u64::from(*b)).wrapping_mul(FNV64_PRIME);
+            self.h128 = (self.h128 ^ u128::from(*b)).wrapping_mul(FNV128_PRIME);
+        }
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline]
+    fn write_i16(&mut self, i: i16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i: i32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_i64(&mut self, i: i64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_i128(&mut self, i: i128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        self.write_i64(i as i64);
+    }
+}
+
+/// Encoding of value written to `StableHasher` that is fixed by the
+/// crate (version 1 of `StableHasher::VERSION`):
+/// - integers are written in little-endian byte order, `usize` /
+///   `isize` as `u64` / `i64`, `bool` as one byte, `char` as `u32`;
+/// - strings, slices and vectors are written as number of elements
+///   (`u64`) followed by elements (UTF-8 bytes for strings);
+/// - `Option` is written as `0u8` for `None` and `1u8` followed by
+///   value for `Some`; tuples as their elements in order.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::stable_hash::{StableEncode, StableHasher};
+/// use core::hash::Hasher;
+/// pub struct Point { x: i32, y: i32 }
+/// impl StableEncode for Point {
+///     fn stable_encode(&self, hasher: &mut StableHasher) {
+///         self.x.stable_encode(hasher);
+///         self.y.stable_encode(hasher);
+///     }
+/// }
+/// ```
+pub trait StableEncode {
+    /// Write value to hasher.
+    fn stable_encode(&self, hasher: &mut StableHasher);
+}
+
+macro_rules! stable_encode_le_bytes {
+    ($($t:ty),*) => {
+        $(
+            impl StableEncode for $t {
+                #[inline]
+                fn stable_encode(&self, hasher: &mut StableHasher) {
+                    hasher.write(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+stable_encode_le_bytes!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl StableEncode for usize {
+    #[inline]
+    fn stable_encode(&self, hasher: &mut StableHasher) {
+        (*self as u64).stable_encode(hasher);
+    }
+}
+
+impl StableEncode for isize {
+    #[inline]
+    fn stable_encode(&self, hasher: &mut StableHasher) {
+        (*self as i64).stable_encode(hasher);
+    }
+}
+
+impl StableEncode for bool {
+    #[inline]
+    fn stable_encode(&self, hasher: &mut StableHasher) {
+        u8::from(*self).stable_encode(hasher);
+    }
+}
+
+impl StableEncode for char {
+    #[inline]
+    fn stable_encode(&self, hasher: &mut StableHasher) {
+        u32::from(*self).stable_encode(hasher);
+    }
+}
+
+impl StableEncode for str {
+    #[inline]
+    fn stable_encode(&self, hasher: &mut StableHasher) {
+        self.len().stable_encode(hasher);
+        hasher.write(self.as_bytes());
+    }
+}
+
+impl StableEncode for String {
+    #[inline]
+    fn stable_encode(&self, hasher: &mut StableHasher) {
+        self.as_str().stable_encode(hasher);
+    }
+}
+
+impl<V: StableEncode> StableEncode for [V] {
+    #[inline]
+    fn stable_encode(&self, hasher: &mut StableHasher) {
+        self.len().stable_encode(hasher);
+        for v in self {
+            v.stable_encode(hasher);
+        }
+    }
+}
+
+impl<V: StableEncode, const N: usize> StableEncode for [V; N] {
+    #[inline]
+    fn stable_encode(&self, hasher: &mut StableHasher) {
+        self.as_slice().stable_encode(hasher);
+    }
+}
+
+impl<V: StableEncode> StableEncode for Vec<V> {
+    #[inline]
+    fn stable_encode(&self, hasher: &mut StableHasher) {
+        self.as_slice().stable_encode(hasher);
+    }
+}
+
+impl<V: StableEncode + ?Sized> StableEncode for &V {
+    #[inline]
+    fn stable_encode(&self, hasher: &mut StableHasher) {
+        (**self).stable_encode(hasher);
+    }
+}
+
+impl<V: StableEncode> StableEncode for Option<V> {
+    #[inline]
+    fn stable_encode(&self, hasher: &mut StableHasher) {
+        match self {
+            None => 0_u8.stable_encode(hasher),
+            Some(v) => {
+                1_u8.stable_encode(hasher);
+                v.stable_encode(hasher);
+            }
+        }
+    }
+}
+
+impl<A: StableEncode, B: StableEncode> StableEncode for (A, B) {
+    #[inline]
+    fn stable_encode(&self, hasher: &mut StableHasher) {
+        self.0.stable_encode(hasher);
+        self.1.stable_encode(hasher);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fnv1a() {
+        let mut hasher = StableHasher::new();
+        assert_eq!(hasher.finish(), FNV64_OFFSET);
+        hasher.write(b"a");
+        assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(
+            hasher.finish128(),
+            0xd228_cb69_6f1a_8caf_7891_2b70_4e4a_8964
+        );
+    }
+
+    #[test]
+    fn test_stable_encode() {
+        let mut hasher = StableHasher::new();
+        "a".stable_encode(&mut hasher);
+        let mut expected = StableHasher::new();
+        expected.write(&[1, 0, 0, 0, 0, 0, 0, 0, b'a']);
+        assert_eq!(hasher.finish(), expected.finish());
+        assert_eq!(hasher.finish(), 0x529a_4ddc_8ff5_6bbf);
+    }
+}
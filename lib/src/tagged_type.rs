@@ -1,39 +1,158 @@
 // SPDX-License-Identifier: MIT
 
+use crate::error::FromOsStrError;
+use crate::error::FromUtf8Error;
+use crate::stable_hash::StableEncode;
+use crate::stable_hash::StableHasher;
+use crate::AllowRetagFrom;
+use crate::AsDeref;
 use crate::AsRef;
+use crate::AsRefTag;
+use crate::ByteConversion;
 use crate::Cloned;
+use crate::ConvertTag;
+use crate::Copied;
+use crate::DebugTag;
+use crate::DefaultValue;
+use crate::DisplayTag;
+use crate::Ffi;
 use crate::FromInner;
+use crate::FromOsStr;
+use crate::FromUtf8;
 use crate::ImplementAdd;
+use crate::ImplementAddAssign;
+use crate::ImplementAsMut;
+use crate::ImplementBitAnd;
+use crate::ImplementBitAndAssign;
+use crate::ImplementBitOr;
+use crate::ImplementBitOrAssign;
+use crate::ImplementBitXor;
+use crate::ImplementBitXorAssign;
+use crate::ImplementBorrow;
 use crate::ImplementClone;
 use crate::ImplementCopy;
-use crate::ImplementDefault;
 use crate::ImplementDeref;
+use crate::ImplementDerefMut;
 use crate::ImplementDiv;
+use crate::ImplementDivAssign;
 use crate::ImplementHash;
 use crate::ImplementMul;
+use crate::ImplementMulAssign;
+use crate::ImplementNeg;
+use crate::ImplementNot;
+use crate::ImplementRem;
+use crate::ImplementShl;
+use crate::ImplementShr;
 use crate::ImplementSub;
+use crate::ImplementSubAssign;
 use crate::InnerAccess;
-use crate::TransparentDebug;
-use crate::TransparentDisplay;
-use crate::TransparentFromStr;
+use crate::MutInnerAccess;
+use crate::Normalize;
+use crate::NotValidated;
+use crate::ParseTag;
+use crate::Plain;
+use crate::PrimitiveInteger;
+use crate::StableHash;
+use crate::StepRange;
+use crate::SubtagOf;
+use crate::TagDiv;
+use crate::TagMul;
+use crate::TagSubOutput;
+use crate::TransparentBinary;
+use crate::TransparentError;
+use crate::TransparentExtend;
+use crate::TransparentFmtWrite;
+use crate::TransparentFromIterator;
+use crate::TransparentFuture;
+use crate::TransparentIndex;
+use crate::TransparentIndexMut;
+use crate::TransparentIntoIterator;
+use crate::TransparentIterator;
+use crate::TransparentLowerExp;
+use crate::TransparentLowerHex;
+use crate::TransparentOctal;
+use crate::TransparentTryFrom;
+use crate::TransparentUpperExp;
+use crate::TransparentUpperHex;
+use crate::Validate;
 use crate::ValueMap;
+use alloc::borrow::Cow;
+use core::borrow::Borrow;
+use core::convert;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::Arguments;
+use core::fmt::Binary;
 use core::fmt::Debug;
 use core::fmt::Display;
 use core::fmt::Formatter;
+use core::fmt::LowerExp;
+use core::fmt::LowerHex;
+use core::fmt::Octal;
 use core::fmt::Result as FmtResult;
+use core::fmt::UpperExp;
+use core::fmt::UpperHex;
+use core::fmt::Write as FmtWrite;
+use core::future::Future;
 use core::hash::Hash;
 use core::hash::Hasher;
+use core::iter::FromIterator;
+use core::iter::FusedIterator;
+use core::iter::Map;
 use core::marker::PhantomData;
+use core::mem;
 use core::ops::Add;
+use core::ops::AddAssign;
+use core::ops::BitAnd;
+use core::ops::BitAndAssign;
+use core::ops::BitOr;
+use core::ops::BitOrAssign;
+use core::ops::BitXor;
+use core::ops::BitXorAssign;
 use core::ops::Deref;
+use core::ops::DerefMut;
 use core::ops::Div;
+use core::ops::DivAssign;
+use core::ops::Index;
+use core::ops::IndexMut;
 use core::ops::Mul;
+use core::ops::MulAssign;
+use core::ops::Neg;
+use core::ops::Not;
+use core::ops::Range;
+use core::ops::RangeInclusive;
+use core::ops::Rem;
+use core::ops::Shl;
+use core::ops::Shr;
 use core::ops::Sub;
+use core::ops::SubAssign;
+#[cfg(feature = "track_construction")]
+use core::panic::Location;
+use core::pin::Pin;
+use core::str::from_utf8;
 use core::str::FromStr;
+use core::task::Context;
+use core::task::Poll;
+use std::ffi::OsStr;
+use std::ffi::OsString;
 
 /// Implmentation of comparison traits for `TaggedType`.
 pub mod cmp;
 
+/// Forwarding of collection methods for `TaggedType`.
+pub mod collections;
+
+/// Helpers for `NonZero*` inner types.
+///
+/// `Option<TaggedType<NonZeroU32, T>>` has the same size as
+/// `TaggedType<NonZeroU32, T>`: wrapper keeps the niche of the inner
+/// type.
+pub mod num;
+
+/// Implementation of `std::io` traits for `support_io` feature.
+#[cfg(feature = "support_io")]
+pub mod io;
+
 /// Implmentation of `serde::Serialize` and `serde::Deserialize` for
 /// `support_serde` feature.
 #[cfg(feature = "support_serde")]
@@ -41,18 +160,20 @@ pub mod serde;
 
 /// Example for a password type:
 /// ```rust
-/// use tagged_types::TaggedType;
+/// use tagged_types::{TaggedType, Plain};
 /// pub type Password = TaggedType<String, PasswordTag>;
 /// pub enum PasswordTag {}
+/// impl Plain for PasswordTag {}
 ///
 /// let password = Password::new("my-secret".into());
 /// ```
 ///
 /// Cannot assign to the base type:
 /// ```rust,compile_fail
-/// use tagged_types::TaggedType;
+/// use tagged_types::{TaggedType, Plain};
 /// pub type Password = TaggedType<String, PasswordTag>;
 /// pub enum PasswordTag {}
+/// impl Plain for PasswordTag {}
 ///
 /// let password = Password::new("supersecret".into());
 /// let copy: String = password; // does not compile: expected String
@@ -60,13 +181,15 @@ pub mod serde;
 ///
 /// Cannot assign between types with different tags:
 /// ```rust,compile_fail
-/// use tagged_types::TaggedType;
+/// use tagged_types::{TaggedType, Plain};
 /// pub type Password = TaggedType<String, PasswordTag>;
 /// pub enum PasswordTag {}
+/// impl Plain for PasswordTag {}
 ///
-/// use tagged_types::TaggedType;
+/// use tagged_types::{TaggedType, Plain};
 /// pub type Username = TaggedType<String, UsernameTag>;
 /// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {}
 ///
 /// let password = Password::new("my-secret".into());
 /// fn foo(user: &Username, password: &Password) {
@@ -79,9 +202,10 @@ pub mod serde;
 ///
 /// The Display and Debug traits are implemented only when `TransparentDisplay` / `TransparentDebug` are implemented:
 /// ```rust,compile_fail
-/// use tagged_types::TaggedType;
+/// use tagged_types::{TaggedType, Plain};
 /// pub type Password = TaggedType<String, PasswordTag>;
 /// pub enum PasswordTag {}
+/// impl Plain for PasswordTag {}
 ///
 /// let password = Password::new("my-secret".into());
 /// format!("{}", password); // does not compile because TransparentDisplay is not implemented
@@ -90,258 +214,1931 @@ pub mod serde;
 ///
 /// The Display and Debug traits are implemented only when `TransparentDisplay` / `TransparentDebug` are implemented:
 /// ```rust
-/// use tagged_types::{TaggedType, TransparentDebug, TransparentDisplay};
+/// use tagged_types::{TaggedType, TransparentDebug, TransparentDisplay, Plain};
 /// pub type Username = TaggedType<String, UsernameTag>;
 /// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {};
 /// impl TransparentDebug for UsernameTag {};
 /// impl TransparentDisplay for UsernameTag {};
 ///
 /// format!("{:?}", Username::new("admin".into()));
 /// format!("{}", Username::new("admin".into()));
 /// ```
+///
+/// `TaggedType` has the same layout and ABI as `Value`, so cbindgen
+/// exports it as inner type. `track_construction` feature adds location
+/// of construction, so it is incompatible with `Ffi`.
+///
+/// Auto traits (`Send`, `Sync`, `Unpin`, `UnwindSafe`) depend only on
+/// `Value`, tag is never stored:
+/// ```rust
+/// use tagged_types::{TaggedType, Plain};
+/// use std::rc::Rc;
+/// pub type Counter = TaggedType<u64, CounterTag>;
+/// pub struct CounterTag(Rc<()>);
+/// impl Plain for CounterTag {}
+///
+/// fn spawn_with<T: Send + Sync + Unpin + 'static>(_: T) {}
+/// spawn_with(Counter::new(1));
+/// ```
+#[cfg_attr(not(feature = "track_construction"), repr(transparent))]
 pub struct TaggedType<Value, Tag> {
     v: Value,
-    _marker: PhantomData<Tag>,
+    _marker: PhantomData<fn() -> Tag>,
+    #[cfg(feature = "track_construction")]
+    location: &'static Location<'static>,
+}
+
+impl<V, T: NotValidated + Normalize<V>> TaggedType<V, T> {
+    /// Create `TaggedType` from normalized inner type.
+    ///
+    /// Available only for tags that do not restrict inner value
+    /// (`NotValidated`). Tags with `Validate` are created by `try_new`.
+    #[inline]
+    #[cfg_attr(feature = "track_construction", track_caller)]
+    pub fn new(v: V) -> Self {
+        Self::new_raw(T::normalize(v))
+    }
+}
+
+impl<V, T: Plain> TaggedType<V, T> {
+    /// Create `TaggedType` from inner type in constant context. `Plain`
+    /// tag does not normalize, so it is the same as `new`.
+    #[inline]
+    #[cfg_attr(feature = "track_construction", track_caller)]
+    pub const fn new_const(v: V) -> Self {
+        Self::new_raw(v)
+    }
 }
 
 impl<V, T> TaggedType<V, T> {
-    /// Create `TaggedType` from inner type.
+    /// Create `TaggedType` from inner type regardless of validation of
+    /// the tag.
     #[inline]
-    pub const fn new(v: V) -> Self {
+    #[cfg_attr(feature = "track_construction", track_caller)]
+    pub(crate) const fn new_raw(v: V) -> Self {
         Self {
             v,
             _marker: PhantomData,
+            #[cfg(feature = "track_construction")]
+            location: Location::caller(),
         }
     }
-}
 
-impl<V, T: InnerAccess> TaggedType<V, T> {
-    /// Provides reference to inner data.
+    /// Location of the code that created the value. Values produced by
+    /// `Clone` and arithmetic operators keep location of the original
+    /// value.
+    #[cfg(feature = "track_construction")]
     #[inline]
-    pub const fn inner(&self) -> &V {
-        &self.v
+    pub const fn construction_location(&self) -> &'static Location<'static> {
+        self.location
     }
 
-    /// Convert `TaggedType` to inner data.
+    /// Unwraps inner data regardless of capabilities of the tag.
     #[inline]
-    pub fn into_inner(self) -> V {
+    pub(crate) fn into_value(self) -> V {
         self.v
     }
-}
 
-impl<V: Clone, T: Cloned> TaggedType<&V, T> {
-    /// Transform to owning `TaggedType`.
+    /// Provides reference to inner data regardless of capabilities of the tag.
     #[inline]
-    #[must_use]
-    pub fn cloned(self) -> TaggedType<V, T> {
-        TaggedType::new(self.v.clone())
+    pub(crate) const fn value(&self) -> &V {
+        &self.v
     }
-}
 
-impl<V, T: ValueMap> TaggedType<V, T> {
-    /// Converts inner type using function f.
+    /// Changes tag keeping inner value (and construction location).
     #[inline]
-    #[must_use]
-    pub fn map<F, U>(self, f: F) -> TaggedType<U, T>
-    where
-        F: FnOnce(V) -> U,
-    {
-        TaggedType::<U, T>::new(f(self.v))
+    pub(crate) fn with_tag<U>(self) -> TaggedType<V, U> {
+        TaggedType {
+            v: self.v,
+            _marker: PhantomData,
+            #[cfg(feature = "track_construction")]
+            location: self.location,
+        }
     }
 
-    /// Converts inner type using function f that returns Result.
+    /// Converts to tagged type of parent tag. Free: only the tag changes.
     ///
-    /// # Errors
+    /// Relies on `SubtagOf` contract: every value valid for the subtag
+    /// is valid for the parent. Parent validation is not re-run in
+    /// release builds.
     ///
-    /// Will return E the same as Result of f.
+    /// # Panics
+    ///
+    /// In debug builds, if value does not pass `Validate` of the parent
+    /// tag (broken `SubtagOf` contract).
+    ///
+    /// Example:
+    /// ```rust
+    /// use tagged_types::{TaggedType, SubtagOf, Plain};
+    /// pub type Username = TaggedType<String, UsernameTag>;
+    /// pub type AdminUsername = TaggedType<String, AdminUsernameTag>;
+    /// pub enum UsernameTag {}
+    /// impl Plain for UsernameTag {}
+    /// pub enum AdminUsernameTag {}
+    /// impl Plain for AdminUsernameTag {}
+    /// impl SubtagOf<UsernameTag> for AdminUsernameTag {}
+    ///
+    /// fn greet(_: &Username) {}
+    /// greet(&AdminUsername::new("root".into()).upcast());
+    /// ```
     #[inline]
-    pub fn try_map<F, U, E>(self, f: F) -> Result<TaggedType<U, T>, E>
+    #[must_use]
+    pub fn upcast<P>(self) -> TaggedType<V, P>
     where
-        F: FnOnce(V) -> Result<U, E>,
+        T: SubtagOf<P>,
+        P: Validate<V>,
     {
-        f(self.v).map(TaggedType::<U, T>::new)
+        debug_assert!(
+            P::validate(&self.v).is_ok(),
+            "upcast: value does not pass validation of parent tag"
+        );
+        self.with_tag()
     }
-}
 
-impl<V, T: AsRef> TaggedType<V, T> {
-    /// Converts from `&TaggedType<V, T>` to `TaggedType<&V, T>`.
+    /// Converts to tagged type of other tag that allows it by
+    /// `AllowRetagFrom`. Only the tag changes.
     ///
     /// Example:
     /// ```rust
-    /// use tagged_types::{TaggedType, AsRef, TransparentDisplay};
-    /// pub type Username = TaggedType<String, UsernameTag>;
-    /// pub type UsernameRef<'a> = TaggedType<&'a String, UsernameTag>;
-    /// pub enum UsernameTag {}
-    /// impl AsRef for UsernameTag {};
-    /// impl TransparentDisplay for UsernameTag {};
+    /// use tagged_types::{TaggedType, AllowRetagFrom, Plain};
+    /// pub type RequestId = TaggedType<String, RequestIdTag>;
+    /// pub type CorrelationId = TaggedType<String, CorrelationIdTag>;
+    /// pub enum RequestIdTag {}
+    /// impl Plain for RequestIdTag {}
+    /// pub enum CorrelationIdTag {}
+    /// impl Plain for CorrelationIdTag {}
+    /// impl AllowRetagFrom<RequestIdTag> for CorrelationIdTag {}
     ///
-    /// pub fn print_username(username: UsernameRef<'_>) {
-    ///     println!("username is {username}");
+    /// let id: CorrelationId = RequestId::new("req-1".into()).retag();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn retag<U: AllowRetagFrom<T>>(self) -> TaggedType<V, U> {
+        self.with_tag()
+    }
+
+    /// Converts to tagged type of other tag using conversion declared
+    /// by `ConvertTag` of that tag.
+    ///
+    /// Example:
+    /// ```rust
+    /// use tagged_types::{TaggedType, ConvertTag, InnerAccess, Plain};
+    /// pub type Seconds = TaggedType<u64, SecondsTag>;
+    /// pub type Millis = TaggedType<u64, MillisTag>;
+    /// pub enum SecondsTag {}
+    /// impl Plain for SecondsTag {}
+    /// pub enum MillisTag {}
+    /// impl Plain for MillisTag {}
+    /// impl InnerAccess for MillisTag {}
+    /// impl ConvertTag<SecondsTag, u64> for MillisTag {
+    ///     fn convert(seconds: u64) -> u64 {
+    ///         seconds * 1000
+    ///     }
     /// }
     ///
-    /// let username = Username::new("admin".into());
-    /// print_username(username.as_ref());
+    /// assert_eq!(*Seconds::new(3).convert::<MillisTag>().inner(), 3000);
     /// ```
     #[inline]
-    pub const fn as_ref(&self) -> TaggedType<&V, T> {
-        TaggedType::<&V, T>::new(&self.v)
+    #[must_use]
+    pub fn convert<U: ConvertTag<T, V>>(self) -> TaggedType<V, U> {
+        TaggedType::new_raw(U::convert(self.v))
     }
 }
 
-impl<V, T: ImplementDeref> Deref for TaggedType<V, T> {
-    type Target = V;
+impl<T: Plain> TaggedType<&'static str, T> {
+    /// Create `TaggedType` from static string.
+    ///
+    /// Example:
+    /// ```rust
+    /// use tagged_types::{TaggedType, Plain};
+    /// pub type HeaderName = TaggedType<&'static str, HeaderNameTag>;
+    /// pub enum HeaderNameTag {}
+    /// impl Plain for HeaderNameTag {}
+    ///
+    /// pub const CONTENT_TYPE: HeaderName = HeaderName::from_static("content-type");
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg_attr(feature = "track_construction", track_caller)]
+    pub const fn from_static(v: &'static str) -> Self {
+        Self::new_raw(v)
+    }
+}
 
+impl<T: Plain> TaggedType<Cow<'static, str>, T> {
+    /// Create `TaggedType` that borrows static string.
+    ///
+    /// Example:
+    /// ```rust
+    /// use tagged_types::{TaggedType, Plain};
+    /// use std::borrow::Cow;
+    /// pub type Route = TaggedType<Cow<'static, str>, RouteTag>;
+    /// pub enum RouteTag {}
+    /// impl Plain for RouteTag {}
+    ///
+    /// pub const HEALTH: Route = Route::from_static("/health");
+    /// ```
     #[inline]
-    fn deref(&self) -> &Self::Target {
-        &self.v
+    #[must_use]
+    #[cfg_attr(feature = "track_construction", track_caller)]
+    pub const fn from_static(v: &'static str) -> Self {
+        Self::new_raw(Cow::Borrowed(v))
     }
 }
 
-impl<V: Clone, T: ImplementClone> Clone for TaggedType<V, T> {
+impl<T: NotValidated + Normalize<String>> TaggedType<String, T> {
+    /// Create `TaggedType` from normalized copy of static string.
     #[inline]
-    fn clone(&self) -> Self {
-        Self {
-            v: self.v.clone(),
-            _marker: PhantomData,
-        }
+    #[must_use]
+    #[cfg_attr(feature = "track_construction", track_caller)]
+    pub fn from_static(v: &'static str) -> Self {
+        Self::new(v.into())
     }
 }
 
-impl<V: Copy, T: ImplementCopy + ImplementClone> Copy for TaggedType<V, T> {}
+impl<V, T: InnerAccess> TaggedType<V, T> {
+    /// Provides reference to inner data.
+    #[inline]
+    pub const fn inner(&self) -> &V {
+        &self.v
+    }
 
-impl<V: Hash, T: ImplementHash> Hash for TaggedType<V, T> {
+    /// Convert `TaggedType` to inner data.
     #[inline]
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.v.hash(state);
+    pub fn into_inner(self) -> V {
+        self.v
     }
-}
 
-impl<V: Default, T: ImplementDefault> Default for TaggedType<V, T> {
+    /// Calls function f with reference to inner data and returns
+    /// `self`.
+    ///
+    /// Example:
+    /// ```rust
+    /// use tagged_types::{TaggedType, InnerAccess, ValueMap, Plain};
+    /// pub type Port = TaggedType<u16, PortTag>;
+    /// pub enum PortTag {}
+    /// impl Plain for PortTag {};
+    /// impl InnerAccess for PortTag {};
+    /// impl ValueMap for PortTag {};
+    ///
+    /// let port = Port::new(8080)
+    ///     .inspect(|v| println!("configured port: {v}"))
+    ///     .map(|v| v + 1);
+    /// ```
     #[inline]
-    fn default() -> Self {
-        Self {
-            _marker: PhantomData,
-            v: V::default(),
-        }
+    #[must_use]
+    pub fn inspect<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&V),
+    {
+        f(&self.v);
+        self
     }
 }
 
-impl<V: Debug, T: TransparentDebug> Debug for TaggedType<V, T> {
+impl<V, T: MutInnerAccess> TaggedType<V, T> {
+    /// Provides mutable reference to inner data.
     #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        self.v.fmt(f)
+    pub const fn inner_mut(&mut self) -> &mut V {
+        &mut self.v
     }
-}
 
-impl<V: Display, T: TransparentDisplay> Display for TaggedType<V, T> {
+    /// Modifies inner data in place using function f.
     #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        self.v.fmt(f)
+    pub fn modify<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut V),
+    {
+        f(&mut self.v);
     }
-}
 
-impl<V: FromStr, T: TransparentFromStr> FromStr for TaggedType<V, T> {
-    type Err = <V as FromStr>::Err;
+    /// Replaces inner data with result of function f.
+    ///
+    /// Inner data is `V::default()` while f is running (and if f
+    /// panics).
+    ///
+    /// Example:
+    /// ```rust
+    /// use tagged_types::{TaggedType, InnerAccess, MutInnerAccess, Plain};
+    /// pub type Path = TaggedType<String, PathTag>;
+    /// pub enum PathTag {}
+    /// impl Plain for PathTag {};
+    /// impl InnerAccess for PathTag {};
+    /// impl MutInnerAccess for PathTag {};
+    ///
+    /// let mut path = Path::new("/usr/".into());
+    /// path.modify(|v| v.push_str("local/"));
+    /// path.update(|v| v.replace("/usr", ""));
+    /// assert_eq!(path.inner(), "/local/");
+    /// ```
+    #[inline]
+    pub fn update<F>(&mut self, f: F)
+    where
+        V: Default,
+        F: FnOnce(V) -> V,
+    {
+        self.v = f(mem::take(&mut self.v));
+    }
 
+    /// Replaces inner data with v and returns previous inner data.
     #[inline]
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            v: V::from_str(s)?,
-            _marker: PhantomData,
-        })
+    pub const fn replace(&mut self, v: V) -> V {
+        mem::replace(&mut self.v, v)
     }
-}
 
-impl<V, T: FromInner> From<V> for TaggedType<V, T> {
+    /// Replaces inner data with default value of the tag (see
+    /// `DefaultValue`) and returns previous inner data.
+    ///
+    /// Example:
+    /// ```rust
+    /// use tagged_types::{TaggedType, ImplementDefault, MutInnerAccess, Plain};
+    /// pub type Buffer = TaggedType<Vec<u8>, BufferTag>;
+    /// pub enum BufferTag {}
+    /// impl Plain for BufferTag {};
+    /// impl ImplementDefault for BufferTag {};
+    /// impl MutInnerAccess for BufferTag {};
+    ///
+    /// let mut buffer = Buffer::new(vec![1, 2]);
+    /// assert_eq!(buffer.take(), vec![1, 2]);
+    /// assert!(buffer.inner_mut().is_empty());
+    /// ```
     #[inline]
-    fn from(v: V) -> Self {
-        Self {
-            v,
-            _marker: PhantomData,
-        }
+    pub fn take(&mut self) -> V
+    where
+        T: DefaultValue<V>,
+    {
+        mem::replace(&mut self.v, T::default_value())
     }
-}
 
-impl<Rhs, V: Add<Rhs, Output = V>, T: ImplementAdd> Add<Rhs> for TaggedType<V, T> {
-    type Output = Self;
+    /// Calls function f with mutable reference to inner data and
+    /// returns `self`.
     #[inline]
-    fn add(self, v: Rhs) -> Self {
-        Self {
-            v: self.v + v,
-            _marker: PhantomData,
-        }
+    #[must_use]
+    pub fn inspect_mut<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        f(&mut self.v);
+        self
     }
-}
 
-impl<Rhs, V: Sub<Rhs, Output = V>, T: ImplementSub> Sub<Rhs> for TaggedType<V, T> {
-    type Output = Self;
+    /// Swaps inner data of two values.
     #[inline]
-    fn sub(self, v: Rhs) -> Self {
-        Self {
-            v: self.v - v,
-            _marker: PhantomData,
-        }
+    pub const fn swap(&mut self, other: &mut Self) {
+        mem::swap(&mut self.v, &mut other.v);
     }
 }
 
-impl<Rhs, V: Mul<Rhs, Output = V>, T: ImplementMul> Mul<Rhs> for TaggedType<V, T> {
-    type Output = Self;
+impl<V: Clone, T: Cloned> TaggedType<&V, T> {
+    /// Transform to owning `TaggedType`.
     #[inline]
-    fn mul(self, v: Rhs) -> Self {
-        Self {
-            v: self.v * v,
-            _marker: PhantomData,
-        }
+    #[must_use]
+    pub fn cloned(self) -> TaggedType<V, T> {
+        TaggedType::new_raw(self.v.clone())
     }
 }
 
-impl<Rhs, V: Div<Rhs, Output = V>, T: ImplementDiv> Div<Rhs> for TaggedType<V, T> {
-    type Output = Self;
+impl<V: Clone, T: Cloned> TaggedType<&mut V, T> {
+    /// Transform to owning `TaggedType`.
     #[inline]
-    fn div(self, v: Rhs) -> Self {
-        Self {
-            v: self.v / v,
-            _marker: PhantomData,
-        }
+    #[must_use]
+    pub fn cloned(self) -> TaggedType<V, T> {
+        TaggedType::new_raw(self.v.clone())
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::*;
-    use core::net::IpAddr;
-    type TaggedString<T> = TaggedType<String, T>;
-    const URL: &str = "http://example.com";
+impl<V: Copy, T: Copied> TaggedType<&V, T> {
+    /// Transform to owning `TaggedType` by copying inner value.
+    #[inline]
+    #[must_use]
+    pub const fn copied(self) -> TaggedType<V, T> {
+        TaggedType::new_raw(*self.v)
+    }
+}
 
-    #[test]
-    fn test_deref() {
-        enum UrlStringTag {}
-        type UrlString = TaggedString<UrlStringTag>;
-        impl ImplementDeref for UrlStringTag {}
-        let url = UrlString::new(URL.into());
-        assert_eq!(url.to_string(), URL);
-        assert!(url.contains("http"));
-        assert_eq!(url.as_str(), URL);
+impl<V, T: ValueMap> TaggedType<V, T> {
+    /// Converts inner type using function f.
+    #[inline]
+    #[must_use]
+    pub fn map<F, U>(self, f: F) -> TaggedType<U, T>
+    where
+        F: FnOnce(V) -> U,
+    {
+        TaggedType::<U, T>::new_raw(f(self.v))
     }
 
-    #[test]
-    fn test_default() {
-        enum CounterU64Tag {}
-        type CounterU64 = TaggedType<u64, CounterU64Tag>;
-        impl InnerAccess for CounterU64Tag {}
-        impl ImplementDefault for CounterU64Tag {}
-        let c = CounterU64::default();
-        assert_eq!(*c.inner(), 0);
+    /// Adjusts inner value using function f keeping inner type.
+    ///
+    /// Unlike `map` type of result is always `Self`, so it does not
+    /// need annotations in builder chains.
+    ///
+    /// Example:
+    /// ```rust
+    /// use tagged_types::{TaggedType, InnerAccess, ValueMap, Plain};
+    /// pub type Url = TaggedType<String, UrlTag>;
+    /// pub enum UrlTag {}
+    /// impl Plain for UrlTag {};
+    /// impl InnerAccess for UrlTag {};
+    /// impl ValueMap for UrlTag {};
+    ///
+    /// let url = Url::new("http://example.com".into())
+    ///     .with(|v| v + "/api")
+    ///     .with(|v| v.replace("http:", "https:"));
+    /// assert_eq!(url.inner(), "https://example.com/api");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with<F>(self, f: F) -> Self
+    where
+        F: FnOnce(V) -> V,
+    {
+        Self {
+            v: f(self.v),
+            ..self
+        }
     }
 
-    #[test]
-    fn test_copy() {
-        enum CounterU64Tag {}
-        type CounterU64 = TaggedType<u64, CounterU64Tag>;
-        impl ImplementCopy for CounterU64Tag {}
-        impl ImplementClone for CounterU64Tag {}
-        impl TransparentDebug for CounterU64Tag {}
-        impl ImplementDefault for CounterU64Tag {}
+    /// Converts inner type using function f that returns Result.
+    ///
+    /// # Errors
+    ///
+    /// Will return E the same as Result of f.
+    #[inline]
+    pub fn try_map<F, U, E>(self, f: F) -> Result<TaggedType<U, T>, E>
+    where
+        F: FnOnce(V) -> Result<U, E>,
+    {
+        f(self.v).map(TaggedType::<U, T>::new_raw)
+    }
+
+    /// Creates new `TaggedType` from reference to inner data using
+    /// function f.
+    ///
+    /// Example:
+    /// ```rust
+    /// use tagged_types::{TaggedType, InnerAccess, ValueMap, Plain};
+    /// pub type Email = TaggedType<String, EmailTag>;
+    /// pub enum EmailTag {}
+    /// impl Plain for EmailTag {};
+    /// impl InnerAccess for EmailTag {};
+    /// impl ValueMap for EmailTag {};
+    ///
+    /// let email = Email::new("Admin@Example.com".into());
+    /// let key = email.map_ref(|v| v.to_lowercase());
+    /// assert_eq!(key.inner(), "admin@example.com");
+    /// assert_eq!(email.inner(), "Admin@Example.com");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn map_ref<F, U>(&self, f: F) -> TaggedType<U, T>
+    where
+        F: FnOnce(&V) -> U,
+    {
+        TaggedType::<U, T>::new_raw(f(&self.v))
+    }
+
+    /// Creates new `TaggedType` from reference to inner data using
+    /// function f that returns Result.
+    ///
+    /// # Errors
+    ///
+    /// Will return E the same as Result of f.
+    #[inline]
+    pub fn try_map_ref<F, U, E>(&self, f: F) -> Result<TaggedType<U, T>, E>
+    where
+        F: FnOnce(&V) -> Result<U, E>,
+    {
+        f(&self.v).map(TaggedType::<U, T>::new_raw)
+    }
+
+    /// Converts inner type using async function f.
+    ///
+    /// Example:
+    /// ```rust
+    /// use tagged_types::{TaggedType, ValueMap, Plain};
+    /// pub type UserId = TaggedType<u64, UserTag>;
+    /// pub type UserName = TaggedType<String, UserTag>;
+    /// pub enum UserTag {}
+    /// impl Plain for UserTag {};
+    /// impl ValueMap for UserTag {};
+    ///
+    /// async fn fetch_name(id: u64) -> String {
+    ///     format!("user{id}")
+    /// }
+    ///
+    /// async fn user_name(id: UserId) -> UserName {
+    ///     id.async_map(fetch_name).await
+    /// }
+    /// ```
+    #[inline]
+    pub async fn async_map<F, U>(self, f: F) -> TaggedType<U, T>
+    where
+        F: AsyncFnOnce(V) -> U,
+    {
+        TaggedType::<U, T>::new_raw(f(self.v).await)
+    }
+
+    /// Converts inner type using async function f that returns Result.
+    ///
+    /// # Errors
+    ///
+    /// Will return E the same as Result of f.
+    #[inline]
+    pub async fn try_async_map<F, U, E>(self, f: F) -> Result<TaggedType<U, T>, E>
+    where
+        F: AsyncFnOnce(V) -> Result<U, E>,
+    {
+        f(self.v).await.map(TaggedType::<U, T>::new_raw)
+    }
+
+    /// Wraps `TaggedType` into another `TaggedType` of the same tag.
+    #[inline]
+    #[must_use]
+    pub const fn nest(self) -> TaggedType<Self, T> {
+        TaggedType::new_raw(self)
+    }
+}
+
+impl<V, T: ValueMap> TaggedType<TaggedType<V, T>, T> {
+    /// Removes one level of nesting of `TaggedType` of the same tag.
+    ///
+    /// Example:
+    /// ```rust
+    /// use tagged_types::{TaggedType, ValueMap, Plain};
+    /// pub type UserId<V> = TaggedType<V, UserIdTag>;
+    /// pub enum UserIdTag {}
+    /// impl Plain for UserIdTag {};
+    /// impl ValueMap for UserIdTag {};
+    ///
+    /// let nested: UserId<UserId<u64>> = UserId::new(UserId::new(1));
+    /// let id: UserId<u64> = nested.flatten();
+    /// ```
+    #[inline]
+    pub fn flatten(self) -> TaggedType<V, T> {
+        self.v
+    }
+}
+
+impl<V, T: ValueMap> TaggedType<Option<V>, T> {
+    /// Converts `TaggedType` of `Option` to `Option` of `TaggedType`.
+    ///
+    /// Example:
+    /// ```rust
+    /// use tagged_types::{TaggedType, ValueMap, Plain};
+    /// pub type MiddleName<V> = TaggedType<V, MiddleNameTag>;
+    /// pub enum MiddleNameTag {}
+    /// impl Plain for MiddleNameTag {};
+    /// impl ValueMap for MiddleNameTag {};
+    ///
+    /// let name: MiddleName<Option<&str>> = MiddleName::new(None);
+    /// assert!(name.transpose().is_none());
+    /// let name = MiddleName::from_option(Some(MiddleName::new("Lee")));
+    /// assert!(name.transpose().is_some());
+    /// ```
+    #[inline]
+    pub fn transpose(self) -> Option<TaggedType<V, T>> {
+        self.v.map(TaggedType::<V, T>::new_raw)
+    }
+
+    /// Converts `Option` of `TaggedType` to `TaggedType` of `Option`.
+    #[inline]
+    #[must_use]
+    pub fn from_option(v: Option<TaggedType<V, T>>) -> Self {
+        Self::new_raw(v.map(|v| v.v))
+    }
+}
+
+impl<V, E, T: ValueMap> TaggedType<Result<V, E>, T> {
+    /// Converts `TaggedType` of `Result` to `Result` of `TaggedType`.
+    ///
+    /// # Errors
+    ///
+    /// Will return E if inner value is error.
+    ///
+    /// Example:
+    /// ```rust
+    /// use tagged_types::{TaggedType, ValueMap, Plain};
+    /// pub type Port<V> = TaggedType<V, PortTag>;
+    /// pub enum PortTag {}
+    /// impl Plain for PortTag {};
+    /// impl ValueMap for PortTag {};
+    ///
+    /// fn parse(v: Port<&str>) -> Result<Port<u16>, core::num::ParseIntError> {
+    ///     v.map(str::parse).transpose()
+    /// }
+    /// assert!(parse(Port::new("8080")).is_ok());
+    /// ```
+    #[inline]
+    pub fn transpose(self) -> Result<TaggedType<V, T>, E> {
+        self.v.map(TaggedType::<V, T>::new_raw)
+    }
+}
+
+impl<A, B, T: ValueMap> TaggedType<(A, B), T> {
+    /// Combines two values of the same tag into pair.
+    ///
+    /// Example:
+    /// ```rust
+    /// use tagged_types::{TaggedType, ValueMap, Plain};
+    /// pub type Coordinates<V> = TaggedType<V, CoordinatesTag>;
+    /// pub enum CoordinatesTag {}
+    /// impl Plain for CoordinatesTag {};
+    /// impl ValueMap for CoordinatesTag {};
+    ///
+    /// let point = Coordinates::zip(Coordinates::new(51.5), Coordinates::new(-0.12));
+    /// let (lat, lon) = point.unzip();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn zip(a: TaggedType<A, T>, b: TaggedType<B, T>) -> Self {
+        Self::new_raw((a.v, b.v))
+    }
+
+    /// Splits pair into two values of the same tag.
+    #[inline]
+    pub fn unzip(self) -> (TaggedType<A, T>, TaggedType<B, T>) {
+        let (a, b) = self.v;
+        (TaggedType::new_raw(a), TaggedType::new_raw(b))
+    }
+}
+
+impl<V: PrimitiveInteger, T: ValueMap> TaggedType<V, T> {
+    /// Converts inner integer to other integer type keeping the tag.
+    /// Returns `None` if value does not fit `U`.
+    ///
+    /// Example:
+    /// ```rust
+    /// use tagged_types::{TaggedType, ValueMap, Plain};
+    /// pub type Meters<V> = TaggedType<V, MetersTag>;
+    /// pub enum MetersTag {}
+    /// impl Plain for MetersTag {};
+    /// impl ValueMap for MetersTag {};
+    ///
+    /// assert!(Meters::new(300_u32).cast::<u16>().is_some());
+    /// assert!(Meters::new(300_u32).cast::<u8>().is_none());
+    /// ```
+    #[inline]
+    pub fn cast<U: PrimitiveInteger + TryFrom<V>>(self) -> Option<TaggedType<U, T>> {
+        U::try_from(self.v).ok().map(TaggedType::<U, T>::new_raw)
+    }
+
+    /// Converts inner integer to `i64` keeping the tag if it fits.
+    #[inline]
+    pub fn as_i64(&self) -> Option<TaggedType<i64, T>>
+    where
+        i64: TryFrom<V>,
+    {
+        i64::try_from(self.v).ok().map(TaggedType::new_raw)
+    }
+
+    /// Converts inner integer to nearest `f64` keeping the tag.
+    #[inline]
+    pub fn as_f64(&self) -> TaggedType<f64, T> {
+        TaggedType::new_raw(self.v.to_f64())
+    }
+}
+
+impl<V, T: StepRange> TaggedType<V, T>
+where
+    Range<V>: Iterator<Item = V>,
+{
+    /// Iterator over tagged values from `start` (inclusive) to `end`
+    /// (exclusive).
+    #[inline]
+    pub fn range(start: Self, end: Self) -> Map<Range<V>, fn(V) -> Self> {
+        (start.v..end.v).map(Self::new_raw)
+    }
+}
+
+impl<V, T: StepRange> TaggedType<V, T>
+where
+    RangeInclusive<V>: Iterator<Item = V>,
+{
+    /// Iterator over tagged values from `start` to `end` (both
+    /// inclusive).
+    #[inline]
+    pub fn range_inclusive(start: Self, end: Self) -> Map<RangeInclusive<V>, fn(V) -> Self> {
+        (start.v..=end.v).map(Self::new_raw)
+    }
+}
+
+impl<V, T: AsRef> TaggedType<V, T> {
+    /// Converts from `&TaggedType<V, T>` to `TaggedType<&V, T>`.
+    ///
+    /// Example:
+    /// ```rust
+    /// use tagged_types::{TaggedType, AsRef, TransparentDisplay, Plain};
+    /// pub type Username = TaggedType<String, UsernameTag>;
+    /// pub type UsernameRef<'a> = TaggedType<&'a String, UsernameTag>;
+    /// pub enum UsernameTag {}
+    /// impl Plain for UsernameTag {};
+    /// impl AsRef for UsernameTag {};
+    /// impl TransparentDisplay for UsernameTag {};
+    ///
+    /// pub fn print_username(username: UsernameRef<'_>) {
+    ///     println!("username is {username}");
+    /// }
+    ///
+    /// let username = Username::new("admin".into());
+    /// print_username(username.as_ref());
+    /// ```
+    #[inline]
+    pub const fn as_ref(&self) -> TaggedType<&V, T> {
+        TaggedType::<&V, T>::new_raw(&self.v)
+    }
+}
+
+impl<V: ?Sized, T: AsRef> TaggedType<&mut V, T> {
+    /// Reborrows `TaggedType<&mut V, T>` for shorter lifetime so it
+    /// can be passed to function without being moved.
+    ///
+    /// Example:
+    /// ```rust
+    /// use tagged_types::{TaggedType, AsRef, Plain};
+    /// pub type BufferMut<'a> = TaggedType<&'a mut Vec<u8>, BufferTag>;
+    /// pub enum BufferTag {}
+    /// impl Plain for BufferTag {};
+    /// impl AsRef for BufferTag {};
+    ///
+    /// fn append(buffer: BufferMut<'_>) {}
+    ///
+    /// let mut data = vec![];
+    /// let mut buffer = BufferMut::new(&mut data);
+    /// append(buffer.reborrow());
+    /// append(buffer.reborrow());
+    /// let view: TaggedType<&Vec<u8>, BufferTag> = buffer.as_shared();
+    /// ```
+    #[inline]
+    pub const fn reborrow(&mut self) -> TaggedType<&mut V, T> {
+        TaggedType::new_raw(&mut *self.v)
+    }
+
+    /// Converts from `&TaggedType<&mut V, T>` to `TaggedType<&V, T>`.
+    #[inline]
+    #[must_use]
+    pub const fn as_shared(&self) -> TaggedType<&V, T> {
+        TaggedType::new_raw(&*self.v)
+    }
+}
+
+impl<V: Deref, T: AsDeref> TaggedType<V, T> {
+    /// Converts from `&TaggedType<V, T>` to `TaggedType<&V::Target, T>`.
+    #[inline]
+    pub fn as_deref(&self) -> TaggedType<&V::Target, T> {
+        TaggedType::new_raw(&*self.v)
+    }
+}
+
+impl<V: PrimitiveInteger, T: ByteConversion> TaggedType<V, T> {
+    /// Return memory representation of inner integer as byte array
+    /// in big-endian byte order.
+    #[inline]
+    pub fn to_be_bytes(&self) -> V::Bytes {
+        self.v.to_be_bytes()
+    }
+
+    /// Return memory representation of inner integer as byte array
+    /// in little-endian byte order.
+    #[inline]
+    pub fn to_le_bytes(&self) -> V::Bytes {
+        self.v.to_le_bytes()
+    }
+
+    /// Create `TaggedType` from its representation as byte array in
+    /// big-endian byte order.
+    #[inline]
+    pub fn from_be_bytes(bytes: V::Bytes) -> Self {
+        Self::new_raw(V::from_be_bytes(bytes))
+    }
+
+    /// Create `TaggedType` from its representation as byte array in
+    /// little-endian byte order.
+    #[inline]
+    pub fn from_le_bytes(bytes: V::Bytes) -> Self {
+        Self::new_raw(V::from_le_bytes(bytes))
+    }
+}
+
+impl<V: StableEncode, T: StableHash> TaggedType<V, T> {
+    /// Returns 64-bit hash of inner value calculated by `StableHasher`.
+    #[inline]
+    pub fn stable_hash64(&self) -> u64 {
+        let mut hasher = StableHasher::new();
+        self.v.stable_encode(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns 128-bit hash of inner value calculated by `StableHasher`.
+    #[inline]
+    pub fn stable_hash128(&self) -> u128 {
+        let mut hasher = StableHasher::new();
+        self.v.stable_encode(&mut hasher);
+        hasher.finish128()
+    }
+}
+
+impl<V, T: Ffi> TaggedType<V, T> {
+    /// Create `TaggedType` from value received over FFI boundary.
+    #[inline]
+    #[cfg_attr(feature = "track_construction", track_caller)]
+    pub const fn from_ffi(v: V) -> Self {
+        Self::new_raw(v)
+    }
+
+    /// Unwraps inner value to pass it over FFI boundary.
+    #[inline]
+    pub fn into_ffi(self) -> V {
+        self.v
+    }
+}
+
+impl<U: ?Sized, V, T: AsRefTag<V, U>> convert::AsRef<U> for TaggedType<V, T> {
+    #[inline]
+    fn as_ref(&self) -> &U {
+        T::as_ref(&self.v)
+    }
+}
+
+impl<V, T: ImplementAsMut> convert::AsMut<V> for TaggedType<V, T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut V {
+        &mut self.v
+    }
+}
+
+impl<V, T: ImplementBorrow> Borrow<V> for TaggedType<V, T> {
+    #[inline]
+    fn borrow(&self) -> &V {
+        &self.v
+    }
+}
+
+impl<T: ImplementBorrow> Borrow<str> for TaggedType<String, T> {
+    #[inline]
+    fn borrow(&self) -> &str {
+        &self.v
+    }
+}
+
+impl<V, T: ImplementDeref> Deref for TaggedType<V, T> {
+    type Target = V;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.v
+    }
+}
+
+impl<V, T: ImplementDerefMut> DerefMut for TaggedType<V, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.v
+    }
+}
+
+impl<Idx, V: Index<Idx>, T: TransparentIndex> Index<Idx> for TaggedType<V, T> {
+    type Output = V::Output;
+    #[inline]
+    fn index(&self, index: Idx) -> &Self::Output {
+        &self.v[index]
+    }
+}
+
+impl<Idx, V: IndexMut<Idx>, T: TransparentIndexMut> IndexMut<Idx> for TaggedType<V, T> {
+    #[inline]
+    fn index_mut(&mut self, index: Idx) -> &mut Self::Output {
+        &mut self.v[index]
+    }
+}
+
+impl<V, T: TransparentIntoIterator> TaggedType<V, T> {
+    /// Iterator over references to items of inner collection.
+    #[inline]
+    pub fn iter<'a>(&'a self) -> <&'a V as IntoIterator>::IntoIter
+    where
+        &'a V: IntoIterator,
+    {
+        (&self.v).into_iter()
+    }
+}
+
+impl<V, T: TransparentIntoIterator + NotValidated> TaggedType<V, T> {
+    /// Iterator over mutable references to items of inner collection.
+    ///
+    /// Available only for tags that do not restrict inner value
+    /// (`NotValidated`).
+    #[inline]
+    pub fn iter_mut<'a>(&'a mut self) -> <&'a mut V as IntoIterator>::IntoIter
+    where
+        &'a mut V: IntoIterator,
+    {
+        (&mut self.v).into_iter()
+    }
+}
+
+impl<V: Iterator, T: TransparentIterator + NotValidated> TaggedType<V, T> {
+    /// Iterator over items of inner iterator that does not consume
+    /// `TaggedType` (same as `Iterator::by_ref`).
+    ///
+    /// Advancing changes inner value, so it is available only for tags
+    /// that do not restrict it (`NotValidated`).
+    #[inline]
+    pub const fn by_ref(&mut self) -> ByRef<'_, V, T> {
+        ByRef { tagged: self }
+    }
+}
+
+/// Iterator returned by `TaggedType::by_ref`.
+///
+/// Advances inner iterator of borrowed `TaggedType` but does not give
+/// access to the iterator itself, so it cannot be replaced.
+/// `DoubleEndedIterator`, `ExactSizeIterator` and `FusedIterator` are
+/// implemented if inner iterator implements them.
+pub struct ByRef<'a, V, T> {
+    tagged: &'a mut TaggedType<V, T>,
+}
+
+impl<V: Iterator, T> Iterator for ByRef<'_, V, T> {
+    type Item = V::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tagged.v.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.tagged.v.size_hint()
+    }
+}
+
+impl<V: DoubleEndedIterator, T> DoubleEndedIterator for ByRef<'_, V, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.tagged.v.next_back()
+    }
+}
+
+impl<V: ExactSizeIterator, T> ExactSizeIterator for ByRef<'_, V, T> {}
+
+impl<V: FusedIterator, T> FusedIterator for ByRef<'_, V, T> {}
+
+impl<V: IntoIterator, T: TransparentIntoIterator> IntoIterator for TaggedType<V, T> {
+    type Item = V::Item;
+    type IntoIter = V::IntoIter;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.v.into_iter()
+    }
+}
+
+impl<'a, V, T: TransparentIntoIterator> IntoIterator for &'a TaggedType<V, T>
+where
+    &'a V: IntoIterator,
+{
+    type Item = <&'a V as IntoIterator>::Item;
+    type IntoIter = <&'a V as IntoIterator>::IntoIter;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        (&self.v).into_iter()
+    }
+}
+
+impl<'a, V, T: TransparentIntoIterator + NotValidated> IntoIterator for &'a mut TaggedType<V, T>
+where
+    &'a mut V: IntoIterator,
+{
+    type Item = <&'a mut V as IntoIterator>::Item;
+    type IntoIter = <&'a mut V as IntoIterator>::IntoIter;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        (&mut self.v).into_iter()
+    }
+}
+
+impl<A, V: FromIterator<A>, T: TransparentFromIterator> FromIterator<A> for TaggedType<V, T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = A>>(iter: I) -> Self {
+        Self::new_raw(V::from_iter(iter))
+    }
+}
+
+impl<A, V: Extend<A>, T: TransparentExtend> Extend<A> for TaggedType<V, T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = A>>(&mut self, iter: I) {
+        self.v.extend(iter);
+    }
+}
+
+impl<V: Future, T: TransparentFuture> Future for TaggedType<V, T> {
+    type Output = V::Output;
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `v` is structurally pinned. `TaggedType` does not
+        // implement `Drop`, is not `repr(packed)` and is `Unpin` only
+        // if `V` is `Unpin`, so `v` is never moved out of pinned
+        // `TaggedType`.
+        unsafe { self.map_unchecked_mut(|s| &mut s.v) }.poll(cx)
+    }
+}
+
+impl<V: Clone, T: ImplementClone> Clone for TaggedType<V, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            v: self.v.clone(),
+            ..*self
+        }
+    }
+}
+
+impl<V: Copy, T: ImplementCopy> Copy for TaggedType<V, T> {}
+
+impl<V: Hash, T: ImplementHash> Hash for TaggedType<V, T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.v.hash(state);
+    }
+}
+
+impl<V, T: DefaultValue<V>> Default for TaggedType<V, T> {
+    #[inline]
+    #[cfg_attr(feature = "track_construction", track_caller)]
+    fn default() -> Self {
+        Self::new_raw(T::default_value())
+    }
+}
+
+impl<V, T: DebugTag<V>> Debug for TaggedType<V, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        T::fmt(&self.v, f)
+    }
+}
+
+impl<V, T: DisplayTag<V>> Display for TaggedType<V, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        T::fmt(&self.v, f)
+    }
+}
+
+impl<V: LowerHex, T: TransparentLowerHex> LowerHex for TaggedType<V, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.v.fmt(f)
+    }
+}
+
+impl<V: UpperHex, T: TransparentUpperHex> UpperHex for TaggedType<V, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.v.fmt(f)
+    }
+}
+
+impl<V: Octal, T: TransparentOctal> Octal for TaggedType<V, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.v.fmt(f)
+    }
+}
+
+impl<V: Binary, T: TransparentBinary> Binary for TaggedType<V, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.v.fmt(f)
+    }
+}
+
+impl<V: LowerExp, T: TransparentLowerExp> LowerExp for TaggedType<V, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.v.fmt(f)
+    }
+}
+
+impl<V: UpperExp, T: TransparentUpperExp> UpperExp for TaggedType<V, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.v.fmt(f)
+    }
+}
+
+impl<V: FmtWrite, T: TransparentFmtWrite> FmtWrite for TaggedType<V, T> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        self.v.write_str(s)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> FmtResult {
+        self.v.write_char(c)
+    }
+
+    #[inline]
+    fn write_fmt(&mut self, args: Arguments<'_>) -> FmtResult {
+        self.v.write_fmt(args)
+    }
+}
+
+impl<V: Error, T: TransparentError + DebugTag<V> + DisplayTag<V>> Error for TaggedType<V, T> {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.v.source()
+    }
+}
+
+impl<V, T: ParseTag<V>> FromStr for TaggedType<V, T> {
+    type Err = T::Err;
+
+    #[inline]
+    #[cfg_attr(feature = "track_construction", track_caller)]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new_raw(T::parse(s)?))
+    }
+}
+
+impl<V, T: FromInner + Normalize<V>> From<V> for TaggedType<V, T> {
+    #[inline]
+    #[cfg_attr(feature = "track_construction", track_caller)]
+    fn from(v: V) -> Self {
+        Self::new(v)
+    }
+}
+
+impl<V, T: TransparentTryFrom> TaggedType<V, T> {
+    /// Create `TaggedType` by fallible conversion of `s` to inner
+    /// type (`V::try_from(s)`).
+    ///
+    /// # Errors
+    ///
+    /// Will return error of conversion to inner type.
+    #[inline]
+    #[cfg_attr(feature = "track_construction", track_caller)]
+    pub fn try_new_from<S>(s: S) -> Result<Self, V::Error>
+    where
+        V: TryFrom<S>,
+    {
+        V::try_from(s).map(Self::new_raw)
+    }
+}
+
+impl<V: FromStr, T: FromUtf8> TaggedType<V, T> {
+    /// Create `TaggedType` by parsing UTF-8 bytes.
+    ///
+    /// # Errors
+    ///
+    /// Will return error if bytes are not valid UTF-8 or inner type
+    /// fails to parse the string.
+    #[inline]
+    pub fn from_utf8(v: &[u8]) -> Result<Self, FromUtf8Error<T, V::Err>> {
+        from_utf8(v)
+            .map_err(FromUtf8Error::utf8)?
+            .parse()
+            .map(Self::new_raw)
+            .map_err(FromUtf8Error::Parse)
+    }
+}
+
+impl<'a, T: FromUtf8> TryFrom<&'a [u8]> for TaggedType<&'a str, T> {
+    type Error = FromUtf8Error<T>;
+
+    #[inline]
+    fn try_from(v: &'a [u8]) -> Result<Self, Self::Error> {
+        from_utf8(v).map(Self::new_raw).map_err(FromUtf8Error::utf8)
+    }
+}
+
+impl<T: FromUtf8> TryFrom<&[u8]> for TaggedType<String, T> {
+    type Error = FromUtf8Error<T>;
+
+    #[inline]
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        from_utf8(v)
+            .map(|v| Self::new_raw(v.into()))
+            .map_err(FromUtf8Error::utf8)
+    }
+}
+
+impl<V: FromStr, T: FromOsStr> TaggedType<V, T> {
+    /// Create `TaggedType` by parsing OS string.
+    ///
+    /// # Errors
+    ///
+    /// Will return error if OS string is not valid Unicode or inner
+    /// type fails to parse the string.
+    #[inline]
+    pub fn from_os_str(v: &OsStr) -> Result<Self, FromOsStrError<T, V::Err>> {
+        v.to_str()
+            .ok_or_else(|| FromOsStrError::not_unicode(v.to_os_string()))?
+            .parse()
+            .map(Self::new_raw)
+            .map_err(FromOsStrError::Parse)
+    }
+}
+
+impl<'a, T: FromOsStr> TryFrom<&'a OsStr> for TaggedType<&'a str, T> {
+    type Error = FromOsStrError<T>;
+
+    #[inline]
+    fn try_from(v: &'a OsStr) -> Result<Self, Self::Error> {
+        v.to_str()
+            .map(Self::new_raw)
+            .ok_or_else(|| FromOsStrError::not_unicode(v.to_os_string()))
+    }
+}
+
+impl<T: FromOsStr> TryFrom<&OsStr> for TaggedType<String, T> {
+    type Error = FromOsStrError<T>;
+
+    #[inline]
+    fn try_from(v: &OsStr) -> Result<Self, Self::Error> {
+        v.to_str()
+            .map(|v| Self::new_raw(v.into()))
+            .ok_or_else(|| FromOsStrError::not_unicode(v.to_os_string()))
+    }
+}
+
+impl<T: FromOsStr> TryFrom<OsString> for TaggedType<String, T> {
+    type Error = FromOsStrError<T>;
+
+    #[inline]
+    fn try_from(v: OsString) -> Result<Self, Self::Error> {
+        v.into_string()
+            .map(Self::new_raw)
+            .map_err(FromOsStrError::not_unicode)
+    }
+}
+
+impl<Rhs, V: Add<Rhs, Output = V>, T: ImplementAdd> Add<Rhs> for TaggedType<V, T> {
+    type Output = Self;
+    #[inline]
+    fn add(self, v: Rhs) -> Self {
+        Self {
+            v: self.v + v,
+            ..self
+        }
+    }
+}
+
+impl<Rhs, V: Sub<Rhs, Output = V>, T: ImplementSub> Sub<Rhs> for TaggedType<V, T> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, v: Rhs) -> Self {
+        Self {
+            v: self.v - v,
+            ..self
+        }
+    }
+}
+
+impl<Rhs, V: Mul<Rhs, Output = V>, T: ImplementMul> Mul<Rhs> for TaggedType<V, T> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, v: Rhs) -> Self {
+        Self {
+            v: self.v * v,
+            ..self
+        }
+    }
+}
+
+impl<Rhs, V: Div<Rhs, Output = V>, T: ImplementDiv> Div<Rhs> for TaggedType<V, T> {
+    type Output = Self;
+    #[inline]
+    fn div(self, v: Rhs) -> Self {
+        Self {
+            v: self.v / v,
+            ..self
+        }
+    }
+}
+
+impl<'a, 'b, V, T: ImplementAdd> Add<&'b TaggedType<V, T>> for &'a TaggedType<V, T>
+where
+    &'a V: Add<&'b V, Output = V>,
+{
+    type Output = TaggedType<V, T>;
+    #[inline]
+    fn add(self, v: &'b TaggedType<V, T>) -> Self::Output {
+        TaggedType::new_raw(&self.v + &v.v)
+    }
+}
+
+impl<'a, 'b, V, T: TagSubOutput> Sub<&'b TaggedType<V, T>> for &'a TaggedType<V, T>
+where
+    &'a V: Sub<&'b V, Output = V>,
+{
+    type Output = TaggedType<V, T::Output>;
+    #[inline]
+    fn sub(self, v: &'b TaggedType<V, T>) -> Self::Output {
+        TaggedType::new_raw(&self.v - &v.v)
+    }
+}
+
+impl<'a, 'b, V, T: TagMul<U>, U> Mul<&'b TaggedType<V, U>> for &'a TaggedType<V, T>
+where
+    &'a V: Mul<&'b V, Output = V>,
+{
+    type Output = TaggedType<V, T::Output>;
+    #[inline]
+    fn mul(self, v: &'b TaggedType<V, U>) -> Self::Output {
+        TaggedType::new_raw(&self.v * &v.v)
+    }
+}
+
+impl<'a, 'b, V, T: TagDiv<U>, U> Div<&'b TaggedType<V, U>> for &'a TaggedType<V, T>
+where
+    &'a V: Div<&'b V, Output = V>,
+{
+    type Output = TaggedType<V, T::Output>;
+    #[inline]
+    fn div(self, v: &'b TaggedType<V, U>) -> Self::Output {
+        TaggedType::new_raw(&self.v / &v.v)
+    }
+}
+
+impl<Rhs, V: Rem<Rhs, Output = V>, T: ImplementRem> Rem<Rhs> for TaggedType<V, T> {
+    type Output = Self;
+    #[inline]
+    fn rem(self, v: Rhs) -> Self {
+        Self {
+            v: self.v % v,
+            ..self
+        }
+    }
+}
+
+impl<Rhs, V: AddAssign<Rhs>, T: ImplementAddAssign> AddAssign<Rhs> for TaggedType<V, T> {
+    #[inline]
+    fn add_assign(&mut self, v: Rhs) {
+        self.v += v;
+    }
+}
+
+impl<Rhs, V: SubAssign<Rhs>, T: ImplementSubAssign> SubAssign<Rhs> for TaggedType<V, T> {
+    #[inline]
+    fn sub_assign(&mut self, v: Rhs) {
+        self.v -= v;
+    }
+}
+
+impl<Rhs, V: MulAssign<Rhs>, T: ImplementMulAssign> MulAssign<Rhs> for TaggedType<V, T> {
+    #[inline]
+    fn mul_assign(&mut self, v: Rhs) {
+        self.v *= v;
+    }
+}
+
+impl<Rhs, V: DivAssign<Rhs>, T: ImplementDivAssign> DivAssign<Rhs> for TaggedType<V, T> {
+    #[inline]
+    fn div_assign(&mut self, v: Rhs) {
+        self.v /= v;
+    }
+}
+
+impl<V: Neg<Output = V>, T: ImplementNeg> Neg for TaggedType<V, T> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self { v: -self.v, ..self }
+    }
+}
+
+impl<V: Not<Output = V>, T: ImplementNot> Not for TaggedType<V, T> {
+    type Output = Self;
+    #[inline]
+    fn not(self) -> Self {
+        Self { v: !self.v, ..self }
+    }
+}
+
+impl<Rhs, V: BitAnd<Rhs, Output = V>, T: ImplementBitAnd> BitAnd<Rhs> for TaggedType<V, T> {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, v: Rhs) -> Self {
+        Self {
+            v: self.v & v,
+            ..self
+        }
+    }
+}
+
+impl<Rhs, V: BitOr<Rhs, Output = V>, T: ImplementBitOr> BitOr<Rhs> for TaggedType<V, T> {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, v: Rhs) -> Self {
+        Self {
+            v: self.v | v,
+            ..self
+        }
+    }
+}
+
+impl<Rhs, V: BitXor<Rhs, Output = V>, T: ImplementBitXor> BitXor<Rhs> for TaggedType<V, T> {
+    type Output = Self;
+    #[inline]
+    fn bitxor(self, v: Rhs) -> Self {
+        Self {
+            v: self.v ^ v,
+            ..self
+        }
+    }
+}
+
+impl<Rhs, V: BitAndAssign<Rhs>, T: ImplementBitAndAssign> BitAndAssign<Rhs> for TaggedType<V, T> {
+    #[inline]
+    fn bitand_assign(&mut self, v: Rhs) {
+        self.v &= v;
+    }
+}
+
+impl<Rhs, V: BitOrAssign<Rhs>, T: ImplementBitOrAssign> BitOrAssign<Rhs> for TaggedType<V, T> {
+    #[inline]
+    fn bitor_assign(&mut self, v: Rhs) {
+        self.v |= v;
+    }
+}
+
+impl<Rhs, V: BitXorAssign<Rhs>, T: ImplementBitXorAssign> BitXorAssign<Rhs> for TaggedType<V, T> {
+    #[inline]
+    fn bitxor_assign(&mut self, v: Rhs) {
+        self.v ^= v;
+    }
+}
+
+impl<Rhs, V: Shl<Rhs, Output = V>, T: ImplementShl> Shl<Rhs> for TaggedType<V, T> {
+    type Output = Self;
+    #[inline]
+    fn shl(self, v: Rhs) -> Self {
+        Self {
+            v: self.v << v,
+            ..self
+        }
+    }
+}
+
+impl<Rhs, V: Shr<Rhs, Output = V>, T: ImplementShr> Shr<Rhs> for TaggedType<V, T> {
+    type Output = Self;
+    #[inline]
+    fn shr(self, v: Rhs) -> Self {
+        Self {
+            v: self.v >> v,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use core::net::IpAddr;
+    type TaggedString<T> = TaggedType<String, T>;
+    const URL: &str = "http://example.com";
+
+    #[test]
+    fn test_deref() {
+        enum UrlStringTag {}
+        type UrlString = TaggedString<UrlStringTag>;
+        impl Plain for UrlStringTag {}
+        impl ImplementDeref for UrlStringTag {}
+        let url = UrlString::new(URL.into());
+        assert_eq!(url.to_string(), URL);
+        assert!(url.contains("http"));
+        assert_eq!(url.as_str(), URL);
+    }
+
+    #[test]
+    fn test_deref_mut() {
+        enum BufferTag {}
+        type Buffer = TaggedType<Vec<u8>, BufferTag>;
+        impl Plain for BufferTag {}
+        impl ImplementDeref for BufferTag {}
+        impl ImplementDerefMut for BufferTag {}
+        let mut buffer = Buffer::new(vec![0; 2]);
+        buffer[0] = 1;
+        buffer.push(2);
+        assert_eq!(&buffer[..], &[1, 0, 2]);
+    }
+
+    #[test]
+    fn test_transparent_as_ref() {
+        use core::convert;
+        use std::path::Path;
+        use std::path::PathBuf;
+        fn len(v: impl convert::AsRef<[u8]>) -> usize {
+            v.as_ref().len()
+        }
+        enum ConfigPathTag {}
+        type ConfigPath = TaggedType<PathBuf, ConfigPathTag>;
+        impl Plain for ConfigPathTag {}
+        impl TransparentAsRef for ConfigPathTag {}
+        enum UsernameTag {}
+        type Username = TaggedString<UsernameTag>;
+        impl Plain for UsernameTag {}
+        impl TransparentAsRef for UsernameTag {}
+        let path = ConfigPath::new("/etc/app.toml".into());
+        let path: &Path = path.as_ref();
+        assert_eq!(path.extension().expect("path with extension"), "toml");
+        let username = Username::new("admin".into());
+        let username: &str = username.as_ref();
+        assert_eq!(username, "admin");
+        assert_eq!(len(Username::new("admin".into())), 5);
+    }
+
+    #[test]
+    fn test_as_ref_as_mut_inner() {
+        use core::convert;
+        fn push(mut v: impl convert::AsMut<Vec<u8>>, x: u8) -> usize {
+            v.as_mut().push(x);
+            v.as_mut().len()
+        }
+        fn len(v: &impl convert::AsRef<Vec<u8>>) -> usize {
+            v.as_ref().len()
+        }
+        enum BufferTag {}
+        type Buffer = TaggedType<Vec<u8>, BufferTag>;
+        impl Plain for BufferTag {}
+        impl ImplementAsMut for BufferTag {}
+        impl<V> AsRefTag<V, V> for BufferTag {
+            fn as_ref(v: &V) -> &V {
+                v
+            }
+        }
+        let mut buffer = Buffer::new(vec![]);
+        assert_eq!(push(&mut buffer, 1), 1);
+        assert_eq!(len(&buffer), 1);
+    }
+
+    #[test]
+    fn test_borrow() {
+        use alloc::collections::BTreeSet;
+        use std::collections::HashMap;
+        enum UsernameTag {}
+        type Username = TaggedString<UsernameTag>;
+        impl Plain for UsernameTag {}
+        impl ImplementBorrow for UsernameTag {}
+        impl ImplementHash for UsernameTag {}
+        impl ImplementPartialEq for UsernameTag {}
+        impl ImplementEq for UsernameTag {}
+        impl ImplementPartialOrd for UsernameTag {}
+        impl ImplementOrd for UsernameTag {}
+        let mut roles = HashMap::new();
+        roles.insert(Username::new("admin".into()), 1);
+        assert_eq!(roles.get("admin"), Some(&1));
+        assert_eq!(roles.get(&String::from("admin")), Some(&1));
+        let users: BTreeSet<_> = vec![Username::new("admin".into())].into_iter().collect();
+        assert!(users.contains("admin"));
+    }
+
+    #[test]
+    fn test_transparent_index() {
+        enum NameTag {}
+        type Name = TaggedString<NameTag>;
+        impl Plain for NameTag {}
+        impl TransparentIndex for NameTag {}
+        impl TransparentIndexMut for NameTag {}
+        let mut name = Name::new("admin".into());
+        assert_eq!(&name[1..3], "dm");
+        name[..].make_ascii_uppercase();
+        assert_eq!(&name[..], "ADMIN");
+    }
+
+    #[test]
+    fn test_transparent_into_iterator() {
+        enum PortsTag {}
+        type Ports = TaggedType<Vec<u16>, PortsTag>;
+        impl Plain for PortsTag {}
+        impl TransparentIntoIterator for PortsTag {}
+        let mut ports = Ports::new(vec![80, 443]);
+        for port in &mut ports {
+            *port += 8000;
+        }
+        assert_eq!(ports.iter().max(), Some(&8443));
+        ports.iter_mut().for_each(|p| *p -= 8000);
+        assert_eq!((&ports).into_iter().max(), Some(&443));
+        assert_eq!(ports.into_iter().collect::<Vec<_>>(), vec![80, 443]);
+    }
+
+    #[test]
+    fn test_transparent_iterator() {
+        use core::str;
+        enum LinesTag {}
+        type Lines<'a> = TaggedType<str::Lines<'a>, LinesTag>;
+        impl Plain for LinesTag {}
+        impl TransparentIntoIterator for LinesTag {}
+        impl TransparentIterator for LinesTag {}
+        let mut lines = Lines::new("a\nb\nc".lines());
+        assert_eq!(lines.by_ref().next(), Some("a"));
+        assert_eq!(lines.by_ref().next_back(), Some("c"));
+        assert_eq!(lines.into_iter().collect::<Vec<_>>(), vec!["b"]);
+    }
+
+    #[test]
+    fn test_transparent_from_iterator_extend() {
+        enum PortsTag {}
+        type Ports = TaggedType<Vec<u16>, PortsTag>;
+        impl Plain for PortsTag {}
+        impl TransparentFromIterator for PortsTag {}
+        impl TransparentExtend for PortsTag {}
+        impl InnerAccess for PortsTag {}
+        let mut ports: Ports = (80..82).collect();
+        ports.extend([443]);
+        assert_eq!(ports.inner(), &[80, 81, 443]);
+    }
+
+    #[test]
+    fn test_transparent_future() {
+        use crate::test_utils::block_on;
+        use core::future::ready;
+        enum JobTag {}
+        impl Plain for JobTag {}
+        impl TransparentFuture for JobTag {}
+        let job = TaggedType::<_, JobTag>::new(async { ready(21).await * 2 });
+        assert_eq!(block_on(job), 42);
+    }
+
+    #[test]
+    fn test_transparent_error() {
+        use crate::error::FromUtf8Error;
+        use core::error::Error;
+        use core::num::ParseIntError;
+        enum PortErrorTag {}
+        type PortError = TaggedType<FromUtf8Error<u16, ParseIntError>, PortErrorTag>;
+        impl Plain for PortErrorTag {}
+        impl TransparentDebug for PortErrorTag {}
+        impl TransparentDisplay for PortErrorTag {}
+        impl TransparentError for PortErrorTag {}
+        let parse_error = "http".parse::<u16>().expect_err("not a number");
+        let e = PortError::new(FromUtf8Error::Parse(parse_error.clone()));
+        let e: &dyn Error = &e;
+        assert_eq!(e.to_string(), format!("u16: {parse_error}"));
+        assert_eq!(
+            e.source().map(ToString::to_string),
+            Some(parse_error.to_string())
+        );
+    }
+
+    #[test]
+    fn test_transparent_radix_fmt() {
+        enum DeviceIdTag {}
+        type DeviceId = TaggedType<u16, DeviceIdTag>;
+        impl Plain for DeviceIdTag {}
+        impl TransparentLowerHex for DeviceIdTag {}
+        impl TransparentUpperHex for DeviceIdTag {}
+        impl TransparentOctal for DeviceIdTag {}
+        impl TransparentBinary for DeviceIdTag {}
+        let id = DeviceId::new(0xbeef);
+        assert_eq!(format!("{id:#x} {id:06X}"), "0xbeef 00BEEF");
+        assert_eq!(format!("{id:o} {id:b}"), "137357 1011111011101111");
+    }
+
+    #[test]
+    fn test_transparent_exp_fmt() {
+        enum WavelengthTag {}
+        type Wavelength = TaggedType<f64, WavelengthTag>;
+        impl Plain for WavelengthTag {}
+        impl TransparentLowerExp for WavelengthTag {}
+        impl TransparentUpperExp for WavelengthTag {}
+        let w = Wavelength::new(532e-9);
+        assert_eq!(format!("{w:e} {w:.1E}"), "5.32e-7 5.3E-7");
+    }
+
+    #[test]
+    fn test_transparent_fmt_write() {
+        use core::fmt::Write as _;
+        enum ReportTag {}
+        type Report = TaggedString<ReportTag>;
+        impl Plain for ReportTag {}
+        impl TransparentFmtWrite for ReportTag {}
+        impl InnerAccess for ReportTag {}
+        let mut report = Report::new(String::new());
+        let port = 80;
+        write!(report, "host:{port}").expect("in-memory IO");
+        report.write_char('\n').expect("in-memory IO");
+        assert_eq!(report.inner(), "host:80\n");
+    }
+
+    #[test]
+    fn test_transparent_try_from() {
+        enum InitialTag {}
+        type Initial = TaggedType<char, InitialTag>;
+        impl Plain for InitialTag {}
+        impl TransparentTryFrom for InitialTag {}
+        impl InnerAccess for InitialTag {}
+        assert_eq!(
+            *Initial::try_new_from(0x41u32).expect("valid char").inner(),
+            'A'
+        );
+        assert!(Initial::try_new_from(0xd800u32).is_err());
+    }
+
+    #[test]
+    fn test_partial_eq_inner() {
+        enum UsernameTag {}
+        type Username = TaggedString<UsernameTag>;
+        impl Plain for UsernameTag {}
+        impl ImplementPartialEqInner for UsernameTag {}
+        let username = Username::new("admin".into());
+        assert!(username == String::from("admin"));
+        assert!(username != String::from("root"));
+    }
+
+    #[test]
+    fn test_partial_ord_inner() {
+        enum LimitTag {}
+        type Limit = TaggedType<u32, LimitTag>;
+        impl Plain for LimitTag {}
+        impl ImplementPartialEqInner for LimitTag {}
+        impl ImplementPartialOrdInner for LimitTag {}
+        let limit = Limit::new(100);
+        assert!(limit > 99 && limit <= 100);
+        assert!(limit < 101);
+    }
+
+    #[test]
+    fn test_cross_representation_eq() {
+        use alloc::borrow::Cow;
+        enum HostTag {}
+        impl Plain for HostTag {}
+        impl ImplementPartialEq for HostTag {}
+        let owned = TaggedString::<HostTag>::new("example.com".into());
+        let view = TaggedType::<&str, HostTag>::new("example.com");
+        assert!(owned == view);
+        assert!(view == owned);
+        let cow = TaggedType::<Cow<'_, str>, HostTag>::new(Cow::Borrowed("example.com"));
+        assert!(owned == cow && cow == view);
+
+        let bytes = TaggedType::<Vec<u8>, HostTag>::new(vec![1, 2]);
+        assert!(bytes == TaggedType::<&[u8], HostTag>::new(&[1, 2]));
+    }
+
+    #[test]
+    fn test_step_range() {
+        enum PageTag {}
+        type Page = TaggedType<u32, PageTag>;
+        impl Plain for PageTag {}
+        impl StepRange for PageTag {}
+        impl InnerAccess for PageTag {}
+        let pages: Vec<u32> = Page::range(Page::new(1), Page::new(4))
+            .rev()
+            .map(Page::into_inner)
+            .collect();
+        assert_eq!(pages, vec![3, 2, 1]);
+        assert_eq!(Page::range_inclusive(Page::new(1), Page::new(4)).count(), 4);
+    }
+
+    #[test]
+    fn test_auto_traits_ignore_tag() {
+        use alloc::rc::Rc;
+        use core::cell::Cell;
+        use core::marker::PhantomPinned;
+        use core::panic::RefUnwindSafe;
+        use core::panic::UnwindSafe;
+        fn assert_auto<T: Send + Sync + Unpin + UnwindSafe + RefUnwindSafe>() {}
+        assert_auto::<TaggedType<u32, Rc<Cell<u32>>>>();
+        assert_auto::<TaggedType<String, PhantomPinned>>();
+    }
+
+    #[test]
+    fn test_assign_ops() {
+        enum CounterTag {}
+        type Counter = TaggedType<i64, CounterTag>;
+        impl Plain for CounterTag {}
+        impl ImplementAddAssign for CounterTag {}
+        impl ImplementSubAssign for CounterTag {}
+        impl ImplementMulAssign for CounterTag {}
+        impl ImplementDivAssign for CounterTag {}
+        impl InnerAccess for CounterTag {}
+        let mut c = Counter::new(1);
+        c += 2;
+        c *= 10;
+        c -= 6;
+        c /= 8;
+        assert_eq!(*c.inner(), 3);
+    }
+
+    #[test]
+    fn test_neg() {
+        enum BalanceTag {}
+        type Balance = TaggedType<i64, BalanceTag>;
+        impl Plain for BalanceTag {}
+        impl ImplementNeg for BalanceTag {}
+        impl InnerAccess for BalanceTag {}
+        let debt = -Balance::new(100);
+        assert_eq!(*debt.inner(), -100);
+        assert_eq!(*(-debt).inner(), 100);
+    }
+
+    #[test]
+    fn test_rem() {
+        enum SlotTag {}
+        type Slot = TaggedType<usize, SlotTag>;
+        impl Plain for SlotTag {}
+        impl ImplementRem for SlotTag {}
+        impl InnerAccess for SlotTag {}
+        assert_eq!(*(Slot::new(17) % 5).inner(), 2);
+    }
+
+    #[test]
+    fn test_bit_ops() {
+        enum PermissionsTag {}
+        type Permissions = TaggedType<u8, PermissionsTag>;
+        impl Plain for PermissionsTag {}
+        impl ImplementNot for PermissionsTag {}
+        impl ImplementBitAnd for PermissionsTag {}
+        impl ImplementBitOr for PermissionsTag {}
+        impl ImplementBitXor for PermissionsTag {}
+        impl ImplementBitAndAssign for PermissionsTag {}
+        impl ImplementBitOrAssign for PermissionsTag {}
+        impl ImplementBitXorAssign for PermissionsTag {}
+        impl InnerAccess for PermissionsTag {}
+        impl ImplementClone for PermissionsTag {}
+        impl ImplementCopy for PermissionsTag {}
+        let p = (Permissions::new(0b0101) | 0b0010) & 0b0110;
+        assert_eq!(*p.inner(), 0b0110);
+        assert_eq!(*(p ^ 0b0011).inner(), 0b0101);
+        assert_eq!(*(!p).inner(), 0b1111_1001);
+        let mut p = Permissions::new(0b0110);
+        p |= 0b1000;
+        p &= 0b1100;
+        p ^= 0b0001;
+        assert_eq!(*p.inner(), 0b1101);
+    }
+
+    #[test]
+    fn test_shift_ops() {
+        enum RegisterTag {}
+        type Register = TaggedType<u32, RegisterTag>;
+        impl Plain for RegisterTag {}
+        impl ImplementShl for RegisterTag {}
+        impl ImplementShr for RegisterTag {}
+        impl InnerAccess for RegisterTag {}
+        assert_eq!(*(Register::new(1) << 4).inner(), 0x10);
+        assert_eq!(*(Register::new(0x80) >> 3u8).inner(), 0x10);
+    }
+
+    #[test]
+    fn test_default() {
+        enum CounterU64Tag {}
+        type CounterU64 = TaggedType<u64, CounterU64Tag>;
+        impl Plain for CounterU64Tag {}
+        impl InnerAccess for CounterU64Tag {}
+        impl ImplementDefault for CounterU64Tag {}
+        let c = CounterU64::default();
+        assert_eq!(*c.inner(), 0);
+    }
+
+    #[test]
+    fn test_inner_mut() {
+        type Counter = TaggedType<u64, CounterTag>;
+        enum CounterTag {}
+        impl Plain for CounterTag {}
+        impl InnerAccess for CounterTag {}
+        impl MutInnerAccess for CounterTag {}
+        let mut c = Counter::new(1);
+        *c.inner_mut() += 1;
+        assert_eq!(*c.inner(), 2);
+        c.modify(|v| *v *= 10);
+        c.update(|v| v + 1);
+        assert_eq!(*c.inner(), 21);
+        let mut seen = 0;
+        let c = c.inspect(|v| seen = *v).inspect_mut(|v| *v += 1);
+        assert_eq!((seen, *c.inner()), (21, 22));
+    }
+
+    #[test]
+    fn test_replace_take_swap() {
+        type Counter = TaggedType<u64, CounterTag>;
+        enum CounterTag {}
+        impl Plain for CounterTag {}
+        impl InnerAccess for CounterTag {}
+        impl MutInnerAccess for CounterTag {}
+        impl DefaultValue<u64> for CounterTag {
+            fn default_value() -> u64 {
+                5
+            }
+        }
+        let mut a = Counter::new(1);
+        let mut b = Counter::new(2);
+        assert_eq!(a.replace(3), 1);
+        a.swap(&mut b);
+        assert_eq!((*a.inner(), *b.inner()), (2, 3));
+        assert_eq!(a.take(), 2);
+        assert_eq!(*a.inner(), 5);
+    }
+
+    #[test]
+    fn test_copy() {
+        enum CounterU64Tag {}
+        type CounterU64 = TaggedType<u64, CounterU64Tag>;
+        impl Plain for CounterU64Tag {}
+        impl ImplementCopy for CounterU64Tag {}
+        impl ImplementClone for CounterU64Tag {}
+        impl TransparentDebug for CounterU64Tag {}
+        impl ImplementDefault for CounterU64Tag {}
         impl ImplementPartialEq for CounterU64Tag {}
         let c = CounterU64::default();
         let v = c;
@@ -352,6 +2149,7 @@ mod tests {
     fn test_clone() {
         enum UsernameTag {}
         type Username = TaggedType<String, UsernameTag>;
+        impl Plain for UsernameTag {}
         impl TransparentDebug for UsernameTag {}
         impl ImplementPartialEq for UsernameTag {}
         impl ImplementClone for UsernameTag {}
@@ -363,29 +2161,477 @@ mod tests {
     #[test]
     fn test_transparent_display() {
         enum UrlStringTag {}
+        impl Plain for UrlStringTag {}
         impl TransparentDisplay for UrlStringTag {}
         type UrlString = TaggedString<UrlStringTag>;
         let url = UrlString::new(URL.into());
         assert_eq!(format!("url: {url}"), format!("url: {URL}"));
+        assert_eq!(format!("{:>4}", UrlString::new("a".into())), "   a");
     }
 
     #[test]
     fn test_transparent_debug() {
         enum UrlStringTag {}
+        impl Plain for UrlStringTag {}
         impl TransparentDebug for UrlStringTag {}
         type UrlString = TaggedString<UrlStringTag>;
         let url = UrlString::new(URL.into());
         assert_eq!(format!("url: {url:?}"), format!("url: {URL:?}"));
     }
 
+    #[test]
+    fn test_byte_conversion() {
+        enum SeqNoTag {}
+        type SeqNo = TaggedType<i16, SeqNoTag>;
+        impl Plain for SeqNoTag {}
+        impl ByteConversion for SeqNoTag {}
+        impl InnerAccess for SeqNoTag {}
+        let seq = SeqNo::new(-2);
+        assert_eq!(seq.to_be_bytes(), [0xff, 0xfe]);
+        assert_eq!(seq.to_le_bytes(), [0xfe, 0xff]);
+        assert_eq!(*SeqNo::from_be_bytes(seq.to_be_bytes()).inner(), -2);
+        assert_eq!(*SeqNo::from_le_bytes(seq.to_le_bytes()).inner(), -2);
+    }
+
+    #[test]
+    fn test_from_static() {
+        use alloc::borrow::Cow;
+        enum RouteTag {}
+        type Route = TaggedType<Cow<'static, str>, RouteTag>;
+        type RouteStr = TaggedType<&'static str, RouteTag>;
+        impl Plain for RouteTag {}
+        impl InnerAccess for RouteTag {}
+        const HEALTH: Route = Route::from_static("/health");
+        const METRICS: RouteStr = RouteStr::from_static("/metrics");
+        assert!(matches!(HEALTH.inner(), Cow::Borrowed("/health")));
+        assert_eq!(*METRICS.inner(), "/metrics");
+        assert_eq!(TaggedString::<RouteTag>::from_static("/").inner(), "/");
+    }
+
+    #[test]
+    fn test_stable_hash() {
+        enum TenantIdTag {}
+        type TenantId = TaggedString<TenantIdTag>;
+        impl Plain for TenantIdTag {}
+        impl StableHash for TenantIdTag {}
+        let id = TenantId::new("acme".into());
+        // FNV-1a of 4u64 (little-endian) followed by b"acme".
+        assert_eq!(id.stable_hash64(), 0xbbad_6543_cdaa_0c9b);
+        assert_eq!(
+            id.stable_hash128(),
+            0x7cbd_b2a8_1c34_002f_e20a_3d03_9112_be03
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_from_os_str() {
+        use crate::error::FromOsStrError;
+        use core::convert::TryFrom as _;
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt as _;
+        enum PortTag {}
+        type Port = TaggedType<u16, PortTag>;
+        type PortStr<'a> = TaggedType<&'a str, PortTag>;
+        impl Plain for PortTag {}
+        impl FromOsStr for PortTag {}
+        impl InnerAccess for PortTag {}
+        let invalid = OsStr::from_bytes(b"8\xff");
+        assert_eq!(
+            *Port::from_os_str(OsStr::new("22"))
+                .expect("valid port")
+                .inner(),
+            22
+        );
+        assert!(matches!(
+            Port::from_os_str(OsStr::new("x")),
+            Err(FromOsStrError::Parse(_))
+        ));
+        assert!(matches!(
+            Port::from_os_str(invalid),
+            Err(FromOsStrError::NotUnicode(v, _)) if v == invalid
+        ));
+        assert_eq!(
+            *PortStr::try_from(OsStr::new("22"))
+                .expect("valid UTF-8")
+                .inner(),
+            "22"
+        );
+        assert!(TaggedString::<PortTag>::try_from(invalid.to_os_string()).is_err());
+        assert!(TaggedString::<PortTag>::try_from(invalid).is_err());
+    }
+
+    #[cfg(feature = "track_construction")]
+    #[test]
+    fn test_construction_location() {
+        enum CounterTag {}
+        type Counter = TaggedType<u32, CounterTag>;
+        impl Plain for CounterTag {}
+        impl ImplementAdd for CounterTag {}
+        impl FromInner for CounterTag {}
+        let line = line!();
+        let c = Counter::new(1);
+        let c2: Counter = 2.into();
+        assert_eq!(c.construction_location().file(), file!());
+        assert_eq!(c.construction_location().line(), line + 1);
+        assert_eq!(c2.construction_location().line(), line + 2);
+        assert_eq!((c + 1).construction_location().line(), line + 1);
+    }
+
+    #[test]
+    fn test_map_ref() {
+        enum PortTag {}
+        type Port = TaggedType<String, PortTag>;
+        impl Plain for PortTag {}
+        impl ValueMap for PortTag {}
+        impl InnerAccess for PortTag {}
+        let port = Port::new("8080".into());
+        assert_eq!(*port.map_ref(String::len).inner(), 4);
+        assert_eq!(
+            *port
+                .try_map_ref(|v| v.parse::<u16>())
+                .expect("valid port")
+                .inner(),
+            8080
+        );
+        assert!(port.try_map_ref(|v| v.parse::<u8>()).is_err());
+        assert_eq!(port.inner(), "8080");
+    }
+
+    #[test]
+    fn test_transpose_option() {
+        enum PortTag {}
+        type Port<V> = TaggedType<V, PortTag>;
+        impl Plain for PortTag {}
+        impl ValueMap for PortTag {}
+        impl InnerAccess for PortTag {}
+        assert_eq!(
+            *Port::new(Some(22))
+                .transpose()
+                .expect("value is present")
+                .inner(),
+            22
+        );
+        assert!(Port::<Option<u16>>::new(None).transpose().is_none());
+        assert_eq!(*Port::from_option(Some(Port::new(80))).inner(), Some(80));
+        assert_eq!(*Port::<Option<u16>>::from_option(None).inner(), None);
+    }
+
+    #[test]
+    fn test_transpose_result() {
+        enum PortTag {}
+        type Port<V> = TaggedType<V, PortTag>;
+        impl Plain for PortTag {}
+        impl ValueMap for PortTag {}
+        impl InnerAccess for PortTag {}
+        let port: Port<Result<u16, &str>> = Port::new(Ok(22));
+        assert_eq!(*port.transpose().expect("value is present").inner(), 22);
+        let port: Port<Result<u16, &str>> = Port::new(Err("invalid"));
+        assert_eq!(port.transpose().map(Port::into_inner), Err("invalid"));
+    }
+
+    #[test]
+    fn test_zip() {
+        enum CoordinatesTag {}
+        type Coordinates<V> = TaggedType<V, CoordinatesTag>;
+        impl Plain for CoordinatesTag {}
+        impl ValueMap for CoordinatesTag {}
+        impl InnerAccess for CoordinatesTag {}
+        let point = Coordinates::zip(Coordinates::new(1), Coordinates::new("2"));
+        assert_eq!(*point.inner(), (1, "2"));
+        let (a, b) = point.unzip();
+        assert_eq!((*a.inner(), *b.inner()), (1, "2"));
+    }
+
+    #[test]
+    fn test_flatten() {
+        enum UserIdTag {}
+        type UserId<V> = TaggedType<V, UserIdTag>;
+        impl Plain for UserIdTag {}
+        impl ValueMap for UserIdTag {}
+        impl InnerAccess for UserIdTag {}
+        let nested = UserId::new(1).nest();
+        assert_eq!(*nested.inner().inner(), 1);
+        assert_eq!(*nested.flatten().inner(), 1);
+    }
+
+    #[test]
+    fn test_as_deref() {
+        enum UsernameTag {}
+        type Username = TaggedString<UsernameTag>;
+        impl Plain for UsernameTag {}
+        impl AsDeref for UsernameTag {}
+        impl InnerAccess for UsernameTag {}
+        let username = Username::new("admin".into());
+        let username_ref: TaggedType<&str, UsernameTag> = username.as_deref();
+        assert_eq!(*username_ref.inner(), "admin");
+    }
+
+    #[test]
+    fn test_copied() {
+        enum PortTag {}
+        type Port<V> = TaggedType<V, PortTag>;
+        impl Plain for PortTag {}
+        impl Copied for PortTag {}
+        impl InnerAccess for PortTag {}
+        let port = 8080_u16;
+        assert_eq!(*Port::new(&port).copied().inner(), 8080);
+    }
+
+    #[test]
+    fn test_mut_ref() {
+        enum BufferTag {}
+        type Buffer<V> = TaggedType<V, BufferTag>;
+        impl Plain for BufferTag {}
+        impl AsRef for BufferTag {}
+        impl Cloned for BufferTag {}
+        impl InnerAccess for BufferTag {}
+        fn push(buffer: Buffer<&mut Vec<u8>>, v: u8) {
+            buffer.into_inner().push(v);
+        }
+        let mut data = vec![];
+        let mut buffer = Buffer::new(&mut data);
+        push(buffer.reborrow(), 1);
+        push(buffer.reborrow(), 2);
+        assert_eq!(*buffer.as_shared().inner(), &vec![1, 2]);
+        assert_eq!(*buffer.cloned().inner(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_cmp_ref() {
+        enum UsernameTag {}
+        type Username = TaggedString<UsernameTag>;
+        impl Plain for UsernameTag {}
+        impl AsRef for UsernameTag {}
+        impl ImplementPartialEq for UsernameTag {}
+        impl ImplementPartialOrd for UsernameTag {}
+        let admin = Username::new("admin".into());
+        let guest = Username::new("guest".into());
+        assert!(admin.as_ref() == admin);
+        assert!(admin == admin.as_ref());
+        assert!(admin.as_ref() != guest);
+        assert!(admin.as_ref() < guest);
+        assert!(guest > admin.as_ref());
+    }
+
+    #[test]
+    fn test_with() {
+        enum CounterTag {}
+        type Counter = TaggedType<u64, CounterTag>;
+        impl Plain for CounterTag {}
+        impl ValueMap for CounterTag {}
+        impl InnerAccess for CounterTag {}
+        assert_eq!(
+            *Counter::new(1).with(|v| v + 1).with(|v| v * 10).inner(),
+            20
+        );
+    }
+
+    #[test]
+    fn test_async_map() {
+        use crate::test_utils::block_on;
+        enum UserTag {}
+        type UserId = TaggedType<u64, UserTag>;
+        impl Plain for UserTag {}
+        impl ValueMap for UserTag {}
+        impl InnerAccess for UserTag {}
+        let name = block_on(UserId::new(1).async_map(async |id| format!("user{id}")));
+        assert_eq!(name.inner(), "user1");
+        let res =
+            block_on(UserId::new(0).try_async_map(
+                async |id| {
+                    if id == 0 {
+                        Err("not found")
+                    } else {
+                        Ok(id)
+                    }
+                },
+            ));
+        assert_eq!(res.map(TaggedType::into_inner), Err("not found"));
+    }
+
+    #[test]
+    fn test_cast() {
+        enum MetersTag {}
+        type Meters<V> = TaggedType<V, MetersTag>;
+        impl Plain for MetersTag {}
+        impl ValueMap for MetersTag {}
+        impl InnerAccess for MetersTag {}
+        impl ImplementPartialEq for MetersTag {}
+        assert_eq!(
+            *Meters::new(-1_i32)
+                .cast::<i8>()
+                .expect("value fits target type")
+                .inner(),
+            -1
+        );
+        assert!(Meters::new(-1_i32).cast::<u64>().is_none());
+        assert_eq!(
+            *Meters::new(u128::MAX)
+                .cast::<u128>()
+                .expect("value fits target type")
+                .inner(),
+            u128::MAX
+        );
+        assert!(Meters::new(u64::MAX).as_i64().is_none());
+        assert_eq!(Meters::new(7_u8).as_i64().map(Meters::into_inner), Some(7));
+        assert!(Meters::new(1_u64 << 53).as_f64() == Meters::new(9_007_199_254_740_992.0));
+    }
+
+    #[test]
+    fn test_try_from_utf8() {
+        use core::convert::TryFrom as _;
+        enum UsernameTag {}
+        type Username = TaggedString<UsernameTag>;
+        impl Plain for UsernameTag {}
+        impl FromUtf8 for UsernameTag {}
+        impl InnerAccess for UsernameTag {}
+        impl TransparentDebug for UsernameTag {}
+        assert_eq!(
+            Username::try_from(&b"admin"[..])
+                .expect("valid UTF-8")
+                .inner(),
+            "admin"
+        );
+        let err = Username::try_from(&b"\xffadmin"[..]).expect_err("invalid UTF-8");
+        assert!(err.to_string().contains("UsernameTag: invalid UTF-8"));
+    }
+
+    #[test]
+    fn test_from_utf8() {
+        enum PortTag {}
+        type Port = TaggedType<u16, PortTag>;
+        impl Plain for PortTag {}
+        impl FromUtf8 for PortTag {}
+        impl InnerAccess for PortTag {}
+        assert_eq!(*Port::from_utf8(b"22").expect("valid port").inner(), 22);
+        assert!(matches!(
+            Port::from_utf8(b"-1"),
+            Err(error::FromUtf8Error::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_truncated_display() {
+        enum BodyTag {}
+        type Body = TaggedString<BodyTag>;
+        impl Plain for BodyTag {}
+        impl TruncatedDisplay for BodyTag {
+            const MAX_LEN: usize = 4;
+            const ELLIPSIS: &'static str = "...";
+        }
+        impl TruncatedDebug for BodyTag {
+            const MAX_LEN: usize = 4;
+            const ELLIPSIS: &'static str = "...";
+        }
+        assert_eq!(Body::new("абв".into()).to_string(), "абв");
+        assert_eq!(Body::new("абвг".into()).to_string(), "абвг");
+        assert_eq!(Body::new("абвгд".into()).to_string(), "абвг...");
+        assert_eq!(format!("{:?}", Body::new("abc".into())), r#""abc..."#);
+    }
+
+    #[test]
+    fn test_release_redacted() {
+        enum EmailTag {}
+        impl Plain for EmailTag {}
+        impl ReleaseRedacted for EmailTag {
+            const REVEAL: bool = false;
+        }
+        let email = TaggedString::<EmailTag>::new("admin@example.com".into());
+        assert_eq!(format!("{email} {email:?}"), "*** ***");
+        let score = TaggedType::<f64, NotNan<EmailTag>>::new_checked(1.5).expect("non-NaN value");
+        assert_eq!(format!("{score} {score:?}"), "*** ***");
+        #[cfg(feature = "provide_presets")]
+        {
+            let key = TaggedType::<[u8; 2], presets::bytes::ByteArray<EmailTag>>::from_hex("00ff")
+                .expect("valid hex");
+            assert_eq!(format!("{key} {key:?}"), "*** ***");
+        }
+    }
+
     #[test]
     fn test_transparent_from_str() {
         type DefaultGateway = TaggedType<IpAddr, DefaultGatewayTag>;
         enum DefaultGatewayTag {}
+        impl Plain for DefaultGatewayTag {}
         impl InnerAccess for DefaultGatewayTag {}
         impl TransparentFromStr for DefaultGatewayTag {}
         const IP: &str = "192.168.0.1";
-        let gw: DefaultGateway = IP.parse().unwrap();
-        assert_eq!(gw.inner(), &IP.parse::<IpAddr>().unwrap());
+        let gw: DefaultGateway = IP.parse().expect("valid IP address");
+        assert_eq!(gw.inner(), &IP.parse::<IpAddr>().expect("valid IP address"));
+    }
+
+    #[cfg(not(feature = "track_construction"))]
+    #[test]
+    fn test_ffi() {
+        use core::mem::size_of;
+        enum PortTag {}
+        type Port = TaggedType<u16, PortTag>;
+        impl Plain for PortTag {}
+        impl Ffi for PortTag {}
+        extern "C" fn next_port(port: Port) -> Port {
+            Port::from_ffi(port.into_ffi() + 1)
+        }
+        assert_eq!(size_of::<Port>(), size_of::<u16>());
+        assert_eq!(next_port(Port::from_ffi(8080)).into_ffi(), 8081);
+    }
+
+    #[test]
+    fn test_tag_sub_output() {
+        enum SequenceTag {}
+        impl Plain for SequenceTag {}
+        enum OffsetTag {}
+        impl Plain for OffsetTag {}
+        impl TagSubOutput for SequenceTag {
+            type Output = OffsetTag;
+        }
+        impl InnerAccess for OffsetTag {}
+        let first = TaggedType::<u32, SequenceTag>::new(3);
+        let last = TaggedType::<u32, SequenceTag>::new(10);
+        let offset: TaggedType<u32, OffsetTag> = &last - &first;
+        assert_eq!(*offset.inner(), 7);
+    }
+
+    #[test]
+    fn test_min_max_clamp() {
+        enum LimitTag {}
+        type Limit = TaggedType<f64, LimitTag>;
+        impl Plain for LimitTag {}
+        impl ImplementPartialEq for LimitTag {}
+        impl ImplementPartialOrd for LimitTag {}
+        let limit = Limit::new;
+        assert!(limit(3.0).min(limit(2.0)) == limit(2.0));
+        assert!(limit(3.0).max(limit(2.0)) == limit(3.0));
+        assert!(limit(20.0).clamp(limit(1.0), limit(10.0)) == limit(10.0));
+        assert!(limit(-1.0).clamp(limit(1.0), limit(10.0)) == limit(1.0));
+        assert!(limit(5.0).clamp(limit(1.0), limit(10.0)) == limit(5.0));
+    }
+
+    #[test]
+    fn test_retag() {
+        enum RequestIdTag {}
+        impl Plain for RequestIdTag {}
+        enum CorrelationIdTag {}
+        impl Plain for CorrelationIdTag {}
+        impl AllowRetagFrom<RequestIdTag> for CorrelationIdTag {}
+        impl InnerAccess for CorrelationIdTag {}
+        let id = TaggedType::<u64, RequestIdTag>::new(7);
+        assert_eq!(*id.retag::<CorrelationIdTag>().inner(), 7);
+    }
+
+    #[test]
+    fn test_convert() {
+        enum KibibytesTag {}
+        impl Plain for KibibytesTag {}
+        enum BytesTag {}
+        impl Plain for BytesTag {}
+        impl InnerAccess for BytesTag {}
+        impl ConvertTag<KibibytesTag, usize> for BytesTag {
+            fn convert(v: usize) -> usize {
+                v << 10
+            }
+        }
+        let size = TaggedType::<usize, KibibytesTag>::new(4);
+        assert_eq!(*size.convert::<BytesTag>().inner(), 4096);
     }
 }
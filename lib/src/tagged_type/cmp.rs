@@ -3,9 +3,14 @@
 use crate::ImplementEq;
 use crate::ImplementOrd;
 use crate::ImplementPartialEq;
+use crate::ImplementPartialEqInner;
 use crate::ImplementPartialOrd;
+use crate::ImplementPartialOrdInner;
 use crate::TaggedType;
+use alloc::borrow::Cow;
 use core::cmp::Ordering;
+use std::path::Path;
+use std::path::PathBuf;
 
 impl<V: PartialEq, T: ImplementPartialEq> PartialEq for TaggedType<V, T> {
     #[inline]
@@ -14,11 +19,11 @@ impl<V: PartialEq, T: ImplementPartialEq> PartialEq for TaggedType<V, T> {
     }
 }
 
-impl<V: Eq, T> Eq for TaggedType<V, T> where T: ImplementEq + ImplementPartialEq {}
+impl<V: Eq, T> Eq for TaggedType<V, T> where T: ImplementEq {}
 
 impl<V: PartialOrd, T> PartialOrd for TaggedType<V, T>
 where
-    T: ImplementPartialOrd + ImplementPartialEq,
+    T: ImplementPartialOrd,
 {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
@@ -28,10 +33,150 @@ where
 
 impl<V: Ord, T> Ord for TaggedType<V, T>
 where
-    T: ImplementOrd + ImplementPartialOrd + ImplementPartialEq + ImplementEq,
+    T: ImplementOrd,
 {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
         self.v.cmp(&other.v)
     }
 }
+
+impl<V: PartialOrd, T: ImplementPartialOrd> TaggedType<V, T> {
+    /// Returns the minimum of two values keeping the tag. Same as
+    /// `Ord::min` but also for partially ordered inner types (floats):
+    /// `self` is returned unless `other` is less.
+    ///
+    /// Example:
+    /// ```rust
+    /// use tagged_types::{TaggedType, ImplementPartialOrd, ImplementPartialEq, InnerAccess, Plain};
+    /// pub type Rate = TaggedType<f64, RateTag>;
+    /// pub enum RateTag {}
+    /// impl Plain for RateTag {};
+    /// impl ImplementPartialEq for RateTag {};
+    /// impl ImplementPartialOrd for RateTag {};
+    /// impl InnerAccess for RateTag {};
+    ///
+    /// assert_eq!(*Rate::new(0.5).min(Rate::new(0.25)).inner(), 0.25);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn min(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the maximum of two values keeping the tag. Same as
+    /// `Ord::max` but also for partially ordered inner types (floats):
+    /// `other` is returned unless it is less than `self`.
+    #[inline]
+    #[must_use]
+    pub fn max(self, other: Self) -> Self {
+        if other < self {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Restricts value to `min..=max` keeping the tag. Same as
+    /// `Ord::clamp` but also for partially ordered inner types.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max` (or they are not comparable).
+    #[inline]
+    #[must_use]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min <= max, "clamp: min must not be greater than max");
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+}
+
+impl<V: PartialEq, T: ImplementPartialEqInner> PartialEq<V> for TaggedType<V, T> {
+    #[inline]
+    fn eq(&self, other: &V) -> bool {
+        self.v.eq(other)
+    }
+}
+
+impl<V: PartialOrd, T: ImplementPartialOrdInner> PartialOrd<V> for TaggedType<V, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &V) -> Option<Ordering> {
+        self.v.partial_cmp(other)
+    }
+}
+
+impl<V: PartialEq, T: ImplementPartialEq> PartialEq<TaggedType<V, T>> for TaggedType<&V, T> {
+    #[inline]
+    fn eq(&self, other: &TaggedType<V, T>) -> bool {
+        (*self.v).eq(&other.v)
+    }
+}
+
+impl<V: PartialEq, T: ImplementPartialEq> PartialEq<TaggedType<&V, T>> for TaggedType<V, T> {
+    #[inline]
+    fn eq(&self, other: &TaggedType<&V, T>) -> bool {
+        self.v.eq(other.v)
+    }
+}
+
+impl<V: PartialOrd, T: ImplementPartialOrd> PartialOrd<TaggedType<V, T>> for TaggedType<&V, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &TaggedType<V, T>) -> Option<Ordering> {
+        (*self.v).partial_cmp(&other.v)
+    }
+}
+
+impl<V: PartialOrd, T: ImplementPartialOrd> PartialOrd<TaggedType<&V, T>> for TaggedType<V, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &TaggedType<&V, T>) -> Option<Ordering> {
+        self.v.partial_cmp(other.v)
+    }
+}
+
+/// `PartialEq` in both directions between owned and borrowed
+/// representations of the same value with the same tag.
+///
+/// Generic `PartialEq<TaggedType<U, T>>` for all `V: PartialEq<U>`
+/// would conflict with `PartialEq` of `TaggedType<&V, T>` and
+/// `TaggedType<V, T>`, so pairs are listed explicitly.
+macro_rules! cross_eq {
+    ($(<$($g:ident),*> $a:ty, $b:ty);* $(;)?) => {
+        $(
+            impl<'a, $($g: PartialEq,)* T: ImplementPartialEq> PartialEq<TaggedType<$b, T>>
+                for TaggedType<$a, T>
+            {
+                #[inline]
+                fn eq(&self, other: &TaggedType<$b, T>) -> bool {
+                    self.v == other.v
+                }
+            }
+
+            impl<'a, $($g: PartialEq,)* T: ImplementPartialEq> PartialEq<TaggedType<$a, T>>
+                for TaggedType<$b, T>
+            {
+                #[inline]
+                fn eq(&self, other: &TaggedType<$a, T>) -> bool {
+                    self.v == other.v
+                }
+            }
+        )*
+    };
+}
+
+cross_eq!(
+    <> String, &'a str;
+    <> String, Cow<'a, str>;
+    <> &'a str, Cow<'a, str>;
+    <> PathBuf, &'a Path;
+    <X> Vec<X>, &'a [X];
+);
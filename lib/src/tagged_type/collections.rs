@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: MIT
+
+use crate::KeyedAccess;
+use crate::TaggedType;
+use alloc::collections::BTreeMap;
+use core::borrow::Borrow;
+use core::hash::BuildHasher;
+use core::hash::Hash;
+use std::collections::HashMap;
+
+impl<K: Eq + Hash, V, S: BuildHasher, T: KeyedAccess> TaggedType<HashMap<K, V, S>, T> {
+    /// Returns a reference to the value corresponding to the key
+    /// tagged by the tag of the map.
+    #[inline]
+    pub fn get<Q>(&self, k: &Q) -> Option<TaggedType<&V, T>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.v.get(k).map(TaggedType::new_raw)
+    }
+
+    /// Returns true if the map contains a value for the specified key.
+    #[inline]
+    pub fn contains_key<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.v.contains_key(k)
+    }
+
+    /// Inserts a key-value pair into the map and returns the old
+    /// value tagged by the tag of the map if present.
+    #[inline]
+    pub fn insert(&mut self, k: K, v: V) -> Option<TaggedType<V, T>> {
+        self.v.insert(k, v).map(TaggedType::new_raw)
+    }
+
+    /// Returns the number of elements in the map.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.v.len()
+    }
+
+    /// Returns true if the map contains no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.v.is_empty()
+    }
+}
+
+impl<K: Ord, V, T: KeyedAccess> TaggedType<BTreeMap<K, V>, T> {
+    /// Returns a reference to the value corresponding to the key
+    /// tagged by the tag of the map.
+    #[inline]
+    pub fn get<Q>(&self, k: &Q) -> Option<TaggedType<&V, T>>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.v.get(k).map(TaggedType::new_raw)
+    }
+
+    /// Returns true if the map contains a value for the specified key.
+    #[inline]
+    pub fn contains_key<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.v.contains_key(k)
+    }
+
+    /// Inserts a key-value pair into the map and returns the old
+    /// value tagged by the tag of the map if present.
+    #[inline]
+    pub fn insert(&mut self, k: K, v: V) -> Option<TaggedType<V, T>> {
+        self.v.insert(k, v).map(TaggedType::new_raw)
+    }
+
+    /// Returns the number of elements in the map.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.v.len()
+    }
+
+    /// Returns true if the map contains no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.v.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use alloc::collections::BTreeMap;
+    use core::net::IpAddr;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_btree_map_keyed_access() {
+        enum RouteTableTag {}
+        type RouteTable = TaggedType<BTreeMap<IpAddr, u32>, RouteTableTag>;
+        impl Plain for RouteTableTag {}
+        impl KeyedAccess for RouteTableTag {}
+        impl ImplementDefault for RouteTableTag {}
+        impl InnerAccess for RouteTableTag {}
+        let gw: IpAddr = "192.168.0.1".parse().expect("valid IP address");
+        let mut routes = RouteTable::default();
+        assert!(routes.is_empty());
+        assert!(routes.insert(gw, 10).is_none());
+        assert_eq!(routes.insert(gw, 20).map(TaggedType::into_inner), Some(10));
+        assert!(routes.contains_key(&gw));
+        assert_eq!(routes.get(&gw).map(TaggedType::into_inner), Some(&20));
+        assert_eq!(routes.len(), 1);
+    }
+
+    #[test]
+    fn test_hash_map_keyed_access() {
+        enum FeatureFlagsTag {}
+        type FeatureFlags = TaggedType<HashMap<String, bool>, FeatureFlagsTag>;
+        impl Plain for FeatureFlagsTag {}
+        impl KeyedAccess for FeatureFlagsTag {}
+        impl ImplementDefault for FeatureFlagsTag {}
+        impl InnerAccess for FeatureFlagsTag {}
+        let mut flags = FeatureFlags::default();
+        assert!(flags.is_empty());
+        assert!(flags.insert("dark_mode".into(), false).is_none());
+        assert_eq!(
+            flags
+                .insert("dark_mode".into(), true)
+                .map(TaggedType::into_inner),
+            Some(false)
+        );
+        assert!(flags.contains_key("dark_mode"));
+        assert!(!flags.contains_key("beta"));
+        assert_eq!(
+            flags.get("dark_mode").map(TaggedType::into_inner),
+            Some(&true)
+        );
+        assert_eq!(flags.len(), 1);
+    }
+}
@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+
+use crate::TaggedType;
+use crate::TransparentRead;
+use crate::TransparentSeek;
+use crate::TransparentWrite;
+use core::fmt::Arguments;
+use std::io::IoSlice;
+use std::io::IoSliceMut;
+use std::io::Read;
+use std::io::Result as IoResult;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+
+impl<V: Read, T: TransparentRead> Read for TaggedType<V, T> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        self.v.read(buf)
+    }
+
+    #[inline]
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> IoResult<usize> {
+        self.v.read_vectored(bufs)
+    }
+
+    #[inline]
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> IoResult<usize> {
+        self.v.read_to_end(buf)
+    }
+
+    #[inline]
+    fn read_to_string(&mut self, buf: &mut String) -> IoResult<usize> {
+        self.v.read_to_string(buf)
+    }
+
+    #[inline]
+    fn read_exact(&mut self, buf: &mut [u8]) -> IoResult<()> {
+        self.v.read_exact(buf)
+    }
+}
+
+impl<V: Write, T: TransparentWrite> Write for TaggedType<V, T> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        self.v.write(buf)
+    }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> IoResult<usize> {
+        self.v.write_vectored(bufs)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> IoResult<()> {
+        self.v.flush()
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> IoResult<()> {
+        self.v.write_all(buf)
+    }
+
+    #[inline]
+    fn write_fmt(&mut self, args: Arguments<'_>) -> IoResult<()> {
+        self.v.write_fmt(args)
+    }
+}
+
+impl<V: Seek, T: TransparentSeek> Seek for TaggedType<V, T> {
+    #[inline]
+    fn seek(&mut self, pos: SeekFrom) -> IoResult<u64> {
+        self.v.seek(pos)
+    }
+
+    #[inline]
+    fn stream_position(&mut self) -> IoResult<u64> {
+        self.v.stream_position()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::io::Cursor;
+    use std::io::Read as _;
+    use std::io::Seek as _;
+    use std::io::SeekFrom;
+    use std::io::Write as _;
+
+    #[test]
+    fn test_transparent_io() {
+        type AuditLog = TaggedType<Cursor<Vec<u8>>, AuditLogTag>;
+        enum AuditLogTag {}
+        impl Plain for AuditLogTag {}
+        impl TransparentRead for AuditLogTag {}
+        impl TransparentWrite for AuditLogTag {}
+        impl TransparentSeek for AuditLogTag {}
+        let mut log = AuditLog::new(Cursor::new(vec![]));
+        writeln!(log, "login admin").expect("in-memory IO");
+        log.flush().expect("in-memory IO");
+        assert_eq!(log.seek(SeekFrom::Start(6)).expect("in-memory IO"), 6);
+        let mut user = String::new();
+        log.read_to_string(&mut user).expect("in-memory IO");
+        assert_eq!(user, "admin\n");
+    }
+}
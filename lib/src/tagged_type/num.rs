@@ -0,0 +1,209 @@
+// SPDX-License-Identifier: MIT
+
+use crate::error::ArithmeticOverflow;
+use crate::CheckedArithmetic;
+use crate::InnerAccess;
+use crate::Plain;
+use crate::SaturatingArithmetic;
+use crate::TaggedType;
+use crate::WrappingArithmetic;
+use core::num::NonZeroI128;
+use core::num::NonZeroI16;
+use core::num::NonZeroI32;
+use core::num::NonZeroI64;
+use core::num::NonZeroI8;
+use core::num::NonZeroIsize;
+use core::num::NonZeroU128;
+use core::num::NonZeroU16;
+use core::num::NonZeroU32;
+use core::num::NonZeroU64;
+use core::num::NonZeroU8;
+use core::num::NonZeroUsize;
+
+macro_rules! non_zero {
+    ($($nz:ty: $raw:ty),*) => {
+        $(
+            impl<T: Plain> TaggedType<$nz, T> {
+                /// Create `TaggedType` from raw integer if it is not zero.
+                #[inline]
+                #[must_use]
+                #[cfg_attr(feature = "track_construction", track_caller)]
+                pub const fn new_non_zero(v: $raw) -> Option<Self> {
+                    match <$nz>::new(v) {
+                        Some(v) => Some(Self::new_raw(v)),
+                        None => None,
+                    }
+                }
+            }
+
+            impl<T: InnerAccess> TaggedType<$nz, T> {
+                /// Raw integer value.
+                #[inline]
+                #[must_use]
+                pub const fn get(&self) -> $raw {
+                    self.v.get()
+                }
+            }
+        )*
+    };
+}
+
+non_zero!(
+    NonZeroU8: u8,
+    NonZeroU16: u16,
+    NonZeroU32: u32,
+    NonZeroU64: u64,
+    NonZeroU128: u128,
+    NonZeroUsize: usize,
+    NonZeroI8: i8,
+    NonZeroI16: i16,
+    NonZeroI32: i32,
+    NonZeroI64: i64,
+    NonZeroI128: i128,
+    NonZeroIsize: isize
+);
+
+macro_rules! integer_arithmetic {
+    ($($t:ty),*) => {
+        $(
+            impl<T: CheckedArithmetic> TaggedType<$t, T> {
+                integer_arithmetic!(@checked $t, [
+                    /// Checked integer addition. `None` on overflow.
+                    checked_add,
+                    /// Checked integer subtraction. `None` on overflow.
+                    checked_sub,
+                    /// Checked integer multiplication. `None` on overflow.
+                    checked_mul,
+                    /// Checked integer division. `None` if `rhs` is zero or on overflow.
+                    checked_div,
+                    /// Checked integer remainder. `None` if `rhs` is zero or on overflow.
+                    checked_rem
+                ]);
+                integer_arithmetic!(@try $t, [
+                    /// Integer addition that names tagged type on overflow.
+                    ///
+                    /// # Errors
+                    ///
+                    /// Will return error on overflow.
+                    try_add: checked_add, "addition",
+                    /// Integer subtraction that names tagged type on overflow.
+                    ///
+                    /// # Errors
+                    ///
+                    /// Will return error on overflow.
+                    try_sub: checked_sub, "subtraction",
+                    /// Integer multiplication that names tagged type on overflow.
+                    ///
+                    /// # Errors
+                    ///
+                    /// Will return error on overflow.
+                    try_mul: checked_mul, "multiplication"
+                ]);
+            }
+
+            impl<T: SaturatingArithmetic> TaggedType<$t, T> {
+                integer_arithmetic!(@total $t, [
+                    /// Saturating integer addition. Clamps at numeric bounds.
+                    saturating_add,
+                    /// Saturating integer subtraction. Clamps at numeric bounds.
+                    saturating_sub,
+                    /// Saturating integer multiplication. Clamps at numeric bounds.
+                    saturating_mul
+                ]);
+            }
+
+            impl<T: WrappingArithmetic> TaggedType<$t, T> {
+                integer_arithmetic!(@total $t, [
+                    /// Wrapping (modular) integer addition.
+                    wrapping_add,
+                    /// Wrapping (modular) integer subtraction.
+                    wrapping_sub,
+                    /// Wrapping (modular) integer multiplication.
+                    wrapping_mul
+                ]);
+            }
+        )*
+    };
+    (@checked $t:ty, [$($(#[$meta:meta])* $f:ident),*]) => {
+        $(
+            $(#[$meta])*
+            #[inline]
+            #[must_use]
+            pub const fn $f(self, rhs: $t) -> Option<Self> {
+                match self.v.$f(rhs) {
+                    Some(v) => Some(Self { v, ..self }),
+                    None => None,
+                }
+            }
+        )*
+    };
+    (@try $t:ty, [$($(#[$meta:meta])* $f:ident: $checked:ident, $operation:literal),*]) => {
+        $(
+            $(#[$meta])*
+            #[inline]
+            pub const fn $f(self, rhs: $t) -> Result<Self, ArithmeticOverflow<T>> {
+                match self.$checked(rhs) {
+                    Some(v) => Ok(v),
+                    None => Err(ArithmeticOverflow::new($operation)),
+                }
+            }
+        )*
+    };
+    (@total $t:ty, [$($(#[$meta:meta])* $f:ident),*]) => {
+        $(
+            $(#[$meta])*
+            #[inline]
+            #[must_use]
+            pub const fn $f(self, rhs: $t) -> Self {
+                Self {
+                    v: self.v.$f(rhs),
+                    ..self
+                }
+            }
+        )*
+    };
+}
+
+integer_arithmetic!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use core::mem::size_of;
+    use core::num::NonZeroU32;
+
+    #[test]
+    fn test_non_zero() {
+        enum UserIdTag {}
+        type UserId = TaggedType<NonZeroU32, UserIdTag>;
+        impl Plain for UserIdTag {}
+        impl InnerAccess for UserIdTag {}
+        assert_eq!(UserId::new_non_zero(42).map(|id| id.get()), Some(42));
+        assert!(UserId::new_non_zero(0).is_none());
+        assert_eq!(size_of::<Option<UserId>>(), size_of::<UserId>());
+        #[cfg(not(feature = "track_construction"))]
+        assert_eq!(size_of::<Option<UserId>>(), size_of::<u32>());
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        enum BalanceTag {}
+        type Balance = TaggedType<u8, BalanceTag>;
+        impl Plain for BalanceTag {}
+        impl CheckedArithmetic for BalanceTag {}
+        impl InnerAccess for BalanceTag {}
+        let balance = Balance::new(200);
+        assert_eq!(balance.checked_add(55).map(Balance::into_inner), Some(255));
+        assert!(Balance::new(200).checked_add(56).is_none());
+        assert!(Balance::new(1).checked_sub(2).is_none());
+        assert_eq!(
+            Balance::new(10).checked_mul(3).map(Balance::into_inner),
+            Some(30)
+        );
+        assert!(Balance::new(10).checked_div(0).is_none());
+        assert_eq!(
+            Balance::new(10).checked_rem(3).map(Balance::into_inner),
+            Some(1)
+        );
+    }
+}
@@ -1,26 +1,54 @@
 // SPDX-License-Identifier: MIT
 
+use crate::DeserializeTag;
+use crate::RedactedSerialize;
+use crate::SerializeTag;
 use crate::TaggedType;
-use crate::TransparentDeserialize;
-use crate::TransparentSerialize;
-use serde::Deserialize;
 use serde::Deserializer;
 use serde::Serialize;
 use serde::Serializer;
 
-impl<V: Serialize, T: TransparentSerialize> Serialize for TaggedType<V, T> {
+impl<V, T: SerializeTag<V>> Serialize for TaggedType<V, T> {
     #[inline]
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        self.v.serialize(serializer)
+        T::serialize(&self.v, serializer)
     }
 }
 
-impl<'de, V: Deserialize<'de>, T: TransparentDeserialize> serde::Deserialize<'de>
-    for TaggedType<V, T>
-{
+/// Serializes real inner value of `TaggedType` with `RedactedSerialize` tag.
+pub struct Unredacted<'a, V, T>(&'a TaggedType<V, T>);
+
+impl<V: Serialize, T: RedactedSerialize> Serialize for Unredacted<'_, V, T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.v.serialize(serializer)
+    }
+}
+
+impl<V, T: RedactedSerialize> TaggedType<V, T> {
+    /// Explicitly serialize real inner value instead of placeholder.
+    #[inline]
+    pub const fn unredacted(&self) -> Unredacted<'_, V, T> {
+        Unredacted(self)
+    }
+}
+
+/// Inner types that borrow from input (`&'de str`, `&'de [u8]`) are
+/// deserialized without allocation. Fields of such tagged types need
+/// `#[serde(borrow)]` because serde borrows implicitly only for plain
+/// `&str` / `&[u8]` fields.
+impl<'de, V, T: DeserializeTag<'de, V>> serde::Deserialize<'de> for TaggedType<V, T> {
     #[inline]
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        V::deserialize(deserializer).map(Self::new)
+        T::deserialize(deserializer).map(Self::new_raw)
+    }
+
+    #[inline]
+    fn deserialize_in_place<D: Deserializer<'de>>(
+        deserializer: D,
+        place: &mut Self,
+    ) -> Result<(), D::Error> {
+        T::deserialize_in_place(deserializer, &mut place.v)
     }
 }
 
@@ -28,36 +56,115 @@ impl<'de, V: Deserialize<'de>, T: TransparentDeserialize> serde::Deserialize<'de
 mod tests {
     use crate::*;
     use core::net::IpAddr;
+    use core::ptr;
+
+    #[test]
+    fn test_deserialize_in_place() {
+        type Username = TaggedType<String, UsernameTag>;
+        enum UsernameTag {}
+        impl Plain for UsernameTag {}
+        impl TransparentDeserialize for UsernameTag {}
+        impl InnerAccess for UsernameTag {}
+        let mut username = Username::new(String::with_capacity(64));
+        let mut de = serde_json::Deserializer::from_str(r#""admin""#);
+        serde::Deserialize::deserialize_in_place(&mut de, &mut username).expect("valid JSON");
+        assert_eq!(username.inner(), "admin");
+        assert!(username.inner().capacity() >= 64);
+    }
+
+    #[test]
+    fn test_borrowed_deserialize() {
+        #[derive(serde::Deserialize)]
+        struct Record<'a> {
+            #[serde(borrow)]
+            name: TaggedType<&'a str, FieldTag>,
+            #[serde(borrow)]
+            payload: TaggedType<&'a [u8], FieldTag>,
+        }
+        enum FieldTag {}
+        impl Plain for FieldTag {}
+        impl TransparentDeserialize for FieldTag {}
+        impl InnerAccess for FieldTag {}
+        let input = String::from(r#"{"name":"admin","payload":"raw"}"#);
+        let record: Record<'_> = serde_json::from_str(&input).expect("valid JSON");
+        assert_eq!(*record.name.inner(), "admin");
+        assert_eq!(*record.payload.inner(), b"raw");
+        assert!(ptr::eq(record.name.inner().as_ptr(), input[9..].as_ptr()));
+    }
 
     #[test]
     fn test_serializer() {
         type DefaultGateway = TaggedType<IpAddr, DefaultGatewayTag>;
         enum DefaultGatewayTag {}
+        impl Plain for DefaultGatewayTag {}
         impl TransparentSerialize for DefaultGatewayTag {}
         const IP: &str = "192.168.0.1";
-        let gw = DefaultGateway::new(IP.parse().unwrap());
-        assert_eq!(serde_json::to_string(&gw).unwrap(), r#""192.168.0.1""#);
+        let gw = DefaultGateway::new(IP.parse().expect("valid IP address"));
+        assert_eq!(
+            serde_json::to_string(&gw).expect("serializable value"),
+            r#""192.168.0.1""#
+        );
     }
 
     #[test]
     fn test_derializer() {
         type DefaultGateway = TaggedType<IpAddr, DefaultGatewayTag>;
         enum DefaultGatewayTag {}
+        impl Plain for DefaultGatewayTag {}
         impl TransparentDeserialize for DefaultGatewayTag {}
         impl TransparentDebug for DefaultGatewayTag {}
         impl ImplementPartialEq for DefaultGatewayTag {}
-        const IP: &str = "192.168.0.1";
-        let expected_gw = DefaultGateway::new(IP.parse().unwrap());
         #[derive(serde::Deserialize)]
         struct Route {
             gateway: DefaultGateway,
         }
+        const IP: &str = "192.168.0.1";
+        let expected_gw = DefaultGateway::new(IP.parse().expect("valid IP address"));
         assert_eq!(
             serde_json::from_str::<Route>(r#"{"gateway":"192.168.0.1"}"#)
-                .unwrap()
+                .expect("valid JSON")
                 .gateway,
             expected_gw,
-        )
+        );
+    }
+
+    #[test]
+    fn test_redacted_serializer() {
+        type Password = TaggedType<String, PasswordTag>;
+        enum PasswordTag {}
+        impl Plain for PasswordTag {}
+        impl RedactedSerialize for PasswordTag {
+            const PLACEHOLDER: &'static str = "<redacted>";
+        }
+        #[derive(serde::Serialize)]
+        struct Credentials {
+            password: Password,
+        }
+        let credentials = Credentials {
+            password: Password::new("secret".into()),
+        };
+        assert_eq!(
+            serde_json::to_string(&credentials).expect("serializable value"),
+            r#"{"password":"<redacted>"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&credentials.password.unredacted()).expect("serializable value"),
+            r#""secret""#
+        );
+    }
+
+    #[cfg(feature = "provide_derive")]
+    #[test]
+    fn test_redacted_serializer_derive() {
+        type Password = TaggedType<String, PasswordTag>;
+        #[derive(Tag)]
+        #[redacted(Serialize)]
+        enum PasswordTag {}
+        let password = Password::new("secret".into());
+        assert_eq!(
+            serde_json::to_string(&password).expect("serializable value"),
+            r#""***""#
+        );
     }
 
     #[cfg(feature = "provide_derive")]
@@ -68,23 +175,23 @@ mod tests {
         #[transparent(Serialize, Deserialize, Debug)]
         #[implement(PartialEq, Clone, Copy)]
         enum DefaultGatewayTag {}
-        const IP: &str = "192.168.0.1";
-        let expected_gw = DefaultGateway::new(IP.parse().unwrap());
         #[derive(serde::Deserialize, serde::Serialize)]
         struct Route {
             gateway: DefaultGateway,
         }
+        const IP: &str = "192.168.0.1";
+        let expected_gw = DefaultGateway::new(IP.parse().expect("valid IP address"));
 
         assert_eq!(
             serde_json::from_str::<Route>(
                 &serde_json::to_string(&Route {
                     gateway: expected_gw
                 })
-                .unwrap()
+                .expect("serializable value")
             )
-            .unwrap()
+            .expect("valid JSON")
             .gateway,
             expected_gw,
-        )
+        );
     }
 }
@@ -2,6 +2,16 @@
 
 /// Define traits related to `core::cmp` traits.
 pub mod cmp;
+/// Define traits related to `core::convert` traits.
+pub mod convert;
+/// Define traits related to `core::fmt` traits.
+pub mod fmt;
+/// Defines traits related to `std::io` traits if `support_io`
+/// feature is defined.
+#[cfg(feature = "support_io")]
+pub mod io;
+/// Defines traits of primitive numeric types.
+pub mod num;
 /// Defines Permissive trait if `provide_permissive` feature is
 /// defined.
 #[cfg(feature = "provide_permissive")]
@@ -14,15 +24,35 @@ pub mod serde;
 pub use cmp::ImplementEq;
 pub use cmp::ImplementOrd;
 pub use cmp::ImplementPartialEq;
+pub use cmp::ImplementPartialEqInner;
 pub use cmp::ImplementPartialOrd;
+pub use cmp::ImplementPartialOrdInner;
+pub use convert::ImplementAsMut;
+pub use convert::TransparentAsRef;
+pub use convert::TransparentTryFrom;
+pub use fmt::TransparentBinary;
+pub use fmt::TransparentFmtWrite;
+pub use fmt::TransparentLowerExp;
+pub use fmt::TransparentLowerHex;
+pub use fmt::TransparentOctal;
+pub use fmt::TransparentUpperExp;
+pub use fmt::TransparentUpperHex;
+
+use crate::NotValidated;
+use crate::Plain;
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
 
 /// Enables `TaggedType` to implement access to inner data
 ///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, InnerAccess};
+/// use tagged_types::{TaggedType, InnerAccess, Plain};
 /// pub type Username = TaggedType<String, UsernameTag>;
 /// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {};
 /// impl InnerAccess for UsernameTag {};
 ///
 /// format!("{}", Username::new("admin".into()).inner());
@@ -30,22 +60,58 @@ pub use cmp::ImplementPartialOrd;
 /// ```
 pub trait InnerAccess {}
 
+/// Enables `TaggedType` to implement mutable access to inner data.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, InnerAccess, MutInnerAccess, Plain};
+/// pub type Counter = TaggedType<u64, CounterTag>;
+/// pub enum CounterTag {}
+/// impl Plain for CounterTag {};
+/// impl InnerAccess for CounterTag {};
+/// impl MutInnerAccess for CounterTag {};
+///
+/// let mut counter = Counter::new(1);
+/// *counter.inner_mut() += 1;
+/// assert_eq!(*counter.inner(), 2);
+/// ```
+pub trait MutInnerAccess: NotValidated {}
+
 /// Enables `TaggedType` to implement `cloned()` method
 /// that converts from `TaggedType<&V, T>` to `TaggedType<V, T>`.
 ///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, Cloned};
+/// use tagged_types::{TaggedType, Cloned, Plain};
 /// let user = "admin".to_string();
 /// pub type UsernameRef<'a> = TaggedType<&'a String, UsernameTag>;
 /// pub type Username = TaggedType<String, UsernameTag>;
 /// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {};
 /// impl Cloned for UsernameTag {};
 ///
 /// let username: Username = UsernameRef::new(&user).cloned();
 /// ```
 pub trait Cloned {}
 
+/// Enables `TaggedType` to implement `copied()` method
+/// that converts from `TaggedType<&V, T>` to `TaggedType<V, T>` if
+/// `V` implements `Copy`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, Copied, Plain};
+/// let port = 8080;
+/// pub type PortRef<'a> = TaggedType<&'a u16, PortTag>;
+/// pub type Port = TaggedType<u16, PortTag>;
+/// pub enum PortTag {}
+/// impl Plain for PortTag {};
+/// impl Copied for PortTag {};
+///
+/// let port: Port = PortRef::new(&port).copied();
+/// ```
+pub trait Copied {}
+
 /// Enables `TaggedType` to implement `map` of inner data
 ///
 /// This can be useful if Tag is used as braning mechanism
@@ -53,9 +119,10 @@ pub trait Cloned {}
 ///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, InnerAccess, ValueMap};
+/// use tagged_types::{TaggedType, InnerAccess, ValueMap, Plain};
 /// pub type Meters<T> = TaggedType<T, MetersTag>;
 /// pub enum MetersTag {}
+/// impl Plain for MetersTag {};
 /// impl ValueMap for MetersTag {};
 /// impl InnerAccess for MetersTag {};
 ///
@@ -64,15 +131,16 @@ pub trait Cloned {}
 /// println!("{}", distance.inner())
 ///
 /// ```
-pub trait ValueMap {}
+pub trait ValueMap: NotValidated {}
 
 /// Enables `TaggedType<V, T>` to implement `fn as_ref(&self) -> TaggedType<&V, T>`.
 ///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, AsRef};
+/// use tagged_types::{TaggedType, AsRef, Plain};
 /// pub type Username = TaggedType<String, UsernameTag>;
 /// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {};
 /// impl AsRef for UsernameTag {};
 ///
 /// let username = Username::new("admin".into());
@@ -80,6 +148,65 @@ pub trait ValueMap {}
 /// ```
 pub trait AsRef {}
 
+/// Enables `TaggedType<V, T>` to implement
+/// `fn as_deref(&self) -> TaggedType<&V::Target, T>` if `V` implements `Deref`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, AsDeref, Plain};
+/// pub type Username = TaggedType<String, UsernameTag>;
+/// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {};
+/// impl AsDeref for UsernameTag {};
+///
+/// let username = Username::new("admin".into());
+/// let username_ref: TaggedType<&str, UsernameTag> = username.as_deref();
+/// ```
+pub trait AsDeref {}
+
+/// Enables `TaggedType` to forward `get`, `contains_key`, `insert`,
+/// `len` and `is_empty` to inner `HashMap` or `BTreeMap`.
+///
+/// Values returned by `get` and `insert` keep the tag of the map.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, KeyedAccess, ImplementDefault, InnerAccess, Plain};
+/// use std::collections::HashMap;
+/// pub type FeatureFlags = TaggedType<HashMap<String, bool>, FeatureFlagsTag>;
+/// pub enum FeatureFlagsTag {}
+/// impl Plain for FeatureFlagsTag {};
+/// impl KeyedAccess for FeatureFlagsTag {};
+/// impl ImplementDefault for FeatureFlagsTag {};
+/// impl InnerAccess for FeatureFlagsTag {};
+///
+/// let mut flags = FeatureFlags::default();
+/// flags.insert("dark_mode".into(), true);
+/// assert!(flags.contains_key("dark_mode"));
+/// assert_eq!(flags.get("dark_mode").map(|v| **v.inner()), Some(true));
+/// assert_eq!(flags.len(), 1);
+/// ```
+pub trait KeyedAccess: NotValidated {}
+
+/// Enables `TaggedType` with integer inner type to implement conversion
+/// to / from big-endian and little-endian byte arrays:
+/// `to_be_bytes` / `to_le_bytes` / `from_be_bytes` / `from_le_bytes`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ByteConversion, InnerAccess, Plain};
+/// pub type UserId = TaggedType<u32, UserIdTag>;
+/// pub enum UserIdTag {}
+/// impl Plain for UserIdTag {};
+/// impl ByteConversion for UserIdTag {};
+/// impl InnerAccess for UserIdTag {};
+///
+/// let id = UserId::new(0x0102_0304);
+/// assert_eq!(id.to_be_bytes(), [1, 2, 3, 4]);
+/// assert_eq!(*UserId::from_le_bytes([4, 3, 2, 1]).inner(), 0x0102_0304);
+/// ```
+pub trait ByteConversion: NotValidated {}
+
 /// Enables `TaggedType` to implement Deref to inner data.
 ///
 /// Note that this is considered bad practice for tagged type
@@ -87,62 +214,142 @@ pub trait AsRef {}
 ///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, ImplementDeref};
+/// use tagged_types::{TaggedType, ImplementDeref, Plain};
 /// pub type Username = TaggedType<String, UsernameTag>;
 /// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {};
 /// impl ImplementDeref for UsernameTag {};
 ///
 /// assert!(Username::new("admin".into()).contains("admin"));
 /// ```
 pub trait ImplementDeref {}
 
+/// Enables `TaggedType` to implement `DerefMut` to inner data.
+///
+/// Requires `ImplementDeref` for the tag. Same as `ImplementDeref`
+/// it is not implemented by `Permissive`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementDeref, ImplementDerefMut, Plain};
+/// pub type Buffer = TaggedType<Vec<u8>, BufferTag>;
+/// pub enum BufferTag {}
+/// impl Plain for BufferTag {};
+/// impl ImplementDeref for BufferTag {};
+/// impl ImplementDerefMut for BufferTag {};
+///
+/// let mut buffer = Buffer::new(vec![0; 4]);
+/// buffer[..2].copy_from_slice(&[1, 2]);
+/// assert_eq!(&buffer[..], &[1, 2, 0, 0]);
+/// ```
+pub trait ImplementDerefMut: ImplementDeref + NotValidated {}
+
 /// Enables `TaggedType` to implement `Default` if inner type
 /// implements `Default`.
 ///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, ImplementDefault};
+/// use tagged_types::{TaggedType, ImplementDefault, Plain};
 /// pub type MiddleName = TaggedType<String, MiddleNameTag>;
 /// pub enum MiddleNameTag {}
+/// impl Plain for MiddleNameTag {};
 /// impl ImplementDefault for MiddleNameTag {};
 /// let empty = MiddleName::default();
 /// ```
-pub trait ImplementDefault {}
+pub trait ImplementDefault: NotValidated {}
+
+/// Defines value of `TaggedType<V, Self>::default()`.
+///
+/// Implemented for all tags that implement `ImplementDefault` (as
+/// `V::default()`). Tags with their own default value implement it
+/// directly or by derive `#[default_value = ...]`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, DefaultValue, InnerAccess, Plain};
+/// pub type Retries = TaggedType<u32, RetriesTag>;
+/// pub enum RetriesTag {}
+/// impl Plain for RetriesTag {};
+/// impl DefaultValue<u32> for RetriesTag {
+///     fn default_value() -> u32 {
+///         3
+///     }
+/// }
+/// impl InnerAccess for RetriesTag {};
+///
+/// assert_eq!(*Retries::default().inner(), 3);
+/// ```
+pub trait DefaultValue<V>: NotValidated {
+    /// Default inner value.
+    fn default_value() -> V;
+}
+
+impl<V: Default, T: ImplementDefault> DefaultValue<V> for T {
+    #[inline]
+    fn default_value() -> V {
+        V::default()
+    }
+}
 
 /// Enables `TaggedType` to implement `core::fmt::Debug` trait
 ///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, TransparentDebug};
+/// use tagged_types::{TaggedType, TransparentDebug, Plain};
 /// pub type Username = TaggedType<String, UsernameTag>;
 /// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {};
 /// impl TransparentDebug for UsernameTag {};
 ///
 /// format!("{:?}", Username::new("admin".into()));
 /// ```
-pub trait TransparentDebug {}
+pub trait TransparentDebug {
+    /// Format inner value. `ReleaseRedacted` tags print placeholder
+    /// instead of it when value is not revealed.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of the formatter.
+    #[inline]
+    fn fmt_debug<V: Debug + ?Sized>(v: &V, f: &mut Formatter<'_>) -> FmtResult {
+        v.fmt(f)
+    }
+}
 
 /// Enables `TaggedType` to implement `core::fmt::Display` trait
 ///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, TransparentDisplay};
+/// use tagged_types::{TaggedType, TransparentDisplay, Plain};
 /// pub type Username = TaggedType<String, UsernameTag>;
 /// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {};
 /// impl TransparentDisplay for UsernameTag {};
 ///
 /// format!("{}", Username::new("admin".into()));
 /// ```
-pub trait TransparentDisplay {}
+pub trait TransparentDisplay {
+    /// Format inner value. `ReleaseRedacted` tags print placeholder
+    /// instead of it when value is not revealed.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of the formatter.
+    #[inline]
+    fn fmt_display<V: Display + ?Sized>(v: &V, f: &mut Formatter<'_>) -> FmtResult {
+        v.fmt(f)
+    }
+}
 
 /// Enables `TaggedType` to implement `Clone` trait if inner
 /// type implements `Clone`.
 ///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, TransparentDisplay, ImplementClone};
+/// use tagged_types::{TaggedType, TransparentDisplay, ImplementClone, Plain};
 /// pub type Username = TaggedType<String, UsernameTag>;
 /// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {};
 /// impl TransparentDisplay for UsernameTag {};
 /// impl ImplementClone for UsernameTag {};
 ///
@@ -155,11 +362,14 @@ pub trait ImplementClone {}
 /// Enables `TaggedType` to implement `Copy` trait if inner
 /// type implements `Copy`.
 ///
+/// Requires `ImplementClone` for the tag.
+///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, ImplementCopy, ImplementClone, TransparentDisplay};
+/// use tagged_types::{TaggedType, ImplementCopy, ImplementClone, TransparentDisplay, Plain};
 /// pub type NetPort = TaggedType<u16, NetPortTag>;
 /// pub enum NetPortTag {}
+/// impl Plain for NetPortTag {};
 /// impl TransparentDisplay for NetPortTag {};
 /// impl ImplementClone for NetPortTag {};
 /// impl ImplementCopy for NetPortTag {};
@@ -168,17 +378,20 @@ pub trait ImplementClone {}
 /// let port = ssh_port;
 /// format!("port: {ssh_port}; copy of port: {port}");
 /// ```
-pub trait ImplementCopy {}
+pub trait ImplementCopy: ImplementClone {}
 
 /// Enables `TaggedType` to implement `Hash` trait if inner
 /// type implements `Hash`.
 ///
+/// Requires `ImplementPartialEq` for the tag.
+///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, ImplementPartialEq, ImplementEq, ImplementHash};
+/// use tagged_types::{TaggedType, ImplementPartialEq, ImplementEq, ImplementHash, Plain};
 /// use std::collections::HashSet;
 /// pub type Username = TaggedType<String, UsernameTag>;
 /// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {};
 /// impl ImplementHash for UsernameTag {};
 /// impl ImplementPartialEq for UsernameTag {};
 /// impl ImplementEq for UsernameTag {};
@@ -186,95 +399,958 @@ pub trait ImplementCopy {}
 /// let mut users = HashSet::new();
 /// users.insert(Username::new("admin".into()));
 /// ```
-pub trait ImplementHash {}
+///
+/// Missing prerequisite is reported at the tag definition, before any
+/// value of the tag is hashed:
+/// ```compile_fail,E0277
+/// use tagged_types::ImplementHash;
+/// pub enum UsernameTag {}
+/// impl ImplementHash for UsernameTag {}; // `UsernameTag` does not implement `ImplementPartialEq`
+/// ```
+pub trait ImplementHash: ImplementPartialEq {}
+
+/// Enables `TaggedType<V, T>` to implement `Borrow<V>` (and
+/// `Borrow<str>` for `String` inner type), so maps and sets of tagged
+/// keys can be queried by inner value without building tagged key.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementBorrow, ImplementEq, ImplementHash, ImplementPartialEq, Plain};
+/// use std::collections::HashMap;
+/// pub type Username = TaggedType<String, UsernameTag>;
+/// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {};
+/// impl ImplementBorrow for UsernameTag {};
+/// impl ImplementEq for UsernameTag {};
+/// impl ImplementHash for UsernameTag {};
+/// impl ImplementPartialEq for UsernameTag {};
+///
+/// let mut roles = HashMap::new();
+/// roles.insert(Username::new("admin".into()), "root");
+/// assert_eq!(roles.get("admin"), Some(&"root"));
+/// ```
+pub trait ImplementBorrow {}
 
 /// Enables parsing of `TaggedType` to be parsed from string.
 ///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, TransparentFromStr};
+/// use tagged_types::{TaggedType, TransparentFromStr, Plain};
 /// use core::net::IpAddr;
 /// pub type DefaultGateway = TaggedType<IpAddr, DefaultGatewayTag>;
 /// pub enum DefaultGatewayTag {}
+/// impl Plain for DefaultGatewayTag {};
 /// impl TransparentFromStr for DefaultGatewayTag {};
 ///
 /// let default_gw: DefaultGateway = "192.168.0.1".parse().unwrap();
 /// ```
-pub trait TransparentFromStr {}
+///
+/// Parsed value is neither normalized nor validated, so only `Plain`
+/// tags implement it. Tags with own `Validate` or `Normalize`
+/// implement `ParseTag` with `validation::parse_validated` instead:
+/// ```rust,compile_fail,E0277
+/// use tagged_types::{TaggedType, Normalize, TransparentFromStr, Validate};
+/// pub type Port = TaggedType<u16, PortTag>;
+/// pub enum PortTag {}
+/// impl Normalize<u16> for PortTag {}
+/// impl Validate<u16> for PortTag {
+///     type Error = &'static str;
+///     fn validate(v: &u16) -> Result<(), Self::Error> {
+///         if *v == 0 { Err("zero port") } else { Ok(()) }
+///     }
+/// }
+/// impl TransparentFromStr for PortTag {};
+/// ```
+pub trait TransparentFromStr: Plain {}
+
+/// Enables indexing of `TaggedType` the same way as inner value
+/// (`Index<Idx>` for each `Idx` supported by inner type).
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentIndex, Plain};
+/// pub type Packet = TaggedType<Vec<u8>, PacketTag>;
+/// pub enum PacketTag {}
+/// impl Plain for PacketTag {};
+/// impl TransparentIndex for PacketTag {};
+///
+/// let packet = Packet::new(vec![0x45, 0x00, 0x05, 0xdc]);
+/// assert_eq!(packet[0], 0x45);
+/// assert_eq!(&packet[2..], &[0x05, 0xdc]);
+/// ```
+pub trait TransparentIndex {}
+
+/// Enables mutable indexing of `TaggedType` the same way as inner
+/// value. Requires `TransparentIndex` for the tag.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentIndex, TransparentIndexMut, Plain};
+/// pub type Packet = TaggedType<Vec<u8>, PacketTag>;
+/// pub enum PacketTag {}
+/// impl Plain for PacketTag {};
+/// impl TransparentIndex for PacketTag {};
+/// impl TransparentIndexMut for PacketTag {};
+///
+/// let mut packet = Packet::new(vec![0; 4]);
+/// packet[0] = 0x45;
+/// packet[2..].copy_from_slice(&[0x05, 0xdc]);
+/// assert_eq!(&packet[..], &[0x45, 0x00, 0x05, 0xdc]);
+/// ```
+pub trait TransparentIndexMut: TransparentIndex + NotValidated {}
+
+/// Enables iteration over `TaggedType` the same way as over inner
+/// value.
+///
+/// `IntoIterator` is implemented for `TaggedType<V, T>`,
+/// `&TaggedType<V, T>` and `&mut TaggedType<V, T>` together with
+/// `iter` / `iter_mut` methods. Mutable forms require `NotValidated`
+/// tag, validated tags are iterated only by reference or by value:
+/// ```rust,compile_fail,E0599
+/// use tagged_types::{TaggedType, Normalize, TransparentIntoIterator, Validate};
+/// pub type Ports = TaggedType<Vec<u16>, PortsTag>;
+/// pub enum PortsTag {}
+/// impl Normalize<Vec<u16>> for PortsTag {}
+/// impl Validate<Vec<u16>> for PortsTag {
+///     type Error = &'static str;
+///     fn validate(v: &Vec<u16>) -> Result<(), Self::Error> {
+///         if v.contains(&0) { Err("zero port") } else { Ok(()) }
+///     }
+/// }
+/// impl TransparentIntoIterator for PortsTag {}
+///
+/// let mut ports = Ports::try_new(vec![80]).unwrap();
+/// ports.iter_mut().for_each(|p| *p = 0);
+/// ```
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentIntoIterator, Plain};
+/// pub type Hosts = TaggedType<Vec<String>, HostsTag>;
+/// pub enum HostsTag {}
+/// impl Plain for HostsTag {};
+/// impl TransparentIntoIterator for HostsTag {};
+///
+/// let mut hosts = Hosts::new(vec!["a.example.com".into()]);
+/// for host in &mut hosts {
+///     host.make_ascii_uppercase();
+/// }
+/// for host in &hosts {
+///     assert_eq!(host, "A.EXAMPLE.COM");
+/// }
+/// let hosts: Vec<String> = hosts.into_iter().collect();
+/// ```
+pub trait TransparentIntoIterator {}
+
+/// Enables collecting of `TaggedType` from iterator the same way as
+/// inner value (`FromIterator`).
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentFromIterator, Plain};
+/// pub type Hosts = TaggedType<Vec<String>, HostsTag>;
+/// pub enum HostsTag {}
+/// impl Plain for HostsTag {};
+/// impl TransparentFromIterator for HostsTag {};
+///
+/// let hosts: Hosts = ["a.example.com", "b.example.com"]
+///     .iter()
+///     .map(|host| host.to_string())
+///     .collect();
+/// ```
+pub trait TransparentFromIterator: NotValidated {}
+
+/// Enables extending of `TaggedType` from iterator the same way as
+/// inner value (`Extend`).
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentExtend, Plain};
+/// pub type Hosts = TaggedType<Vec<String>, HostsTag>;
+/// pub enum HostsTag {}
+/// impl Plain for HostsTag {};
+/// impl TransparentExtend for HostsTag {};
+///
+/// let mut hosts = Hosts::new(vec![]);
+/// hosts.extend(["a.example.com".to_string()]);
+/// ```
+pub trait TransparentExtend: NotValidated {}
+
+/// Enables use of `TaggedType` that wraps iterator as the iterator.
+///
+/// `Iterator` itself cannot be implemented for `TaggedType` because
+/// it would conflict with `IntoIterator` of `TransparentIntoIterator`
+/// (`core` implements `IntoIterator` for all iterators). Instead
+/// `by_ref` gives iterator over items of inner iterator (with
+/// `DoubleEndedIterator` and `ExactSizeIterator` adapters if inner
+/// iterator has them) that does not expose inner iterator itself, and
+/// `for` loops consume `TaggedType` by `IntoIterator`. Requires
+/// `TransparentIntoIterator` for the tag.
+///
+/// `by_ref` advances inner iterator, so it requires `NotValidated`:
+/// ```rust,compile_fail,E0599
+/// use tagged_types::{TaggedType, Normalize, TransparentIntoIterator, TransparentIterator, Validate};
+/// use std::vec::IntoIter;
+/// pub type Batches = TaggedType<IntoIter<u32>, BatchesTag>;
+/// pub enum BatchesTag {}
+/// impl Normalize<IntoIter<u32>> for BatchesTag {}
+/// impl Validate<IntoIter<u32>> for BatchesTag {
+///     type Error = &'static str;
+///     fn validate(v: &IntoIter<u32>) -> Result<(), Self::Error> {
+///         if v.len() == 0 { Err("no batches") } else { Ok(()) }
+///     }
+/// }
+/// impl TransparentIntoIterator for BatchesTag {}
+/// impl TransparentIterator for BatchesTag {}
+///
+/// let mut batches = Batches::try_new(vec![1].into_iter()).unwrap();
+/// batches.by_ref().for_each(drop);
+/// ```
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentIntoIterator, TransparentIterator, Plain};
+/// use std::vec::IntoIter;
+/// pub type Batches = TaggedType<IntoIter<u32>, BatchesTag>;
+/// pub enum BatchesTag {}
+/// impl Plain for BatchesTag {};
+/// impl TransparentIntoIterator for BatchesTag {};
+/// impl TransparentIterator for BatchesTag {};
+///
+/// let mut batches = Batches::new(vec![1, 2, 3, 4].into_iter());
+/// assert_eq!(batches.by_ref().next(), Some(1));
+/// assert_eq!(batches.by_ref().next_back(), Some(4));
+/// assert_eq!(batches.by_ref().len(), 2);
+/// for batch in batches {
+///     assert!(batch < 4);
+/// }
+/// ```
+///
+/// ```rust,compile_fail,E0614
+/// use tagged_types::{TaggedType, TransparentIntoIterator, TransparentIterator, Plain};
+/// use std::vec::IntoIter;
+/// pub type Batches = TaggedType<IntoIter<u32>, BatchesTag>;
+/// pub enum BatchesTag {}
+/// impl Plain for BatchesTag {};
+/// impl TransparentIntoIterator for BatchesTag {};
+/// impl TransparentIterator for BatchesTag {};
+///
+/// let mut batches = Batches::new(vec![1, 2].into_iter());
+/// *batches.by_ref() = vec![0].into_iter();
+/// ```
+pub trait TransparentIterator: TransparentIntoIterator {}
+
+/// Enables awaiting of `TaggedType` that wraps future. Output is
+/// output of inner future.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentFuture, Plain};
+/// use core::future::{ready, Ready};
+/// pub type PendingAnswer = TaggedType<Ready<u32>, PendingAnswerTag>;
+/// pub enum PendingAnswerTag {}
+/// impl Plain for PendingAnswerTag {};
+/// impl TransparentFuture for PendingAnswerTag {};
+///
+/// async fn answer() -> u32 {
+///     PendingAnswer::new(ready(42)).await
+/// }
+/// ```
+pub trait TransparentFuture {}
+
+/// Enables `TaggedType` to implement `core::error::Error` when inner
+/// value is error. `source` is source of inner error.
+///
+/// `Error` requires `Debug` and `Display`, so tag has to enable
+/// them too (for example by `TransparentDebug` and `TransparentDisplay`).
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentDebug, TransparentDisplay, TransparentError, Plain};
+/// use core::num::ParseIntError;
+/// pub type PortError = TaggedType<ParseIntError, PortErrorTag>;
+/// pub enum PortErrorTag {}
+/// impl Plain for PortErrorTag {};
+/// impl TransparentDebug for PortErrorTag {};
+/// impl TransparentDisplay for PortErrorTag {};
+/// impl TransparentError for PortErrorTag {};
+///
+/// fn parse_port(s: &str) -> Result<u16, Box<dyn std::error::Error>> {
+///     Ok(s.parse::<u16>().map_err(PortError::new)?)
+/// }
+/// assert!(parse_port("http").is_err());
+/// ```
+pub trait TransparentError {}
 
 /// Gives possibility to convert from inner type to the tagged type using From/Into.
 ///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, FromInner};
+/// use tagged_types::{TaggedType, FromInner, Plain};
 /// use core::net::IpAddr;
 /// pub type DefaultGateway = TaggedType<IpAddr, DefaultGatewayTag>;
 /// pub enum DefaultGatewayTag {}
+/// impl Plain for DefaultGatewayTag {};
 /// impl FromInner for DefaultGatewayTag {};
 ///
 /// let ip: IpAddr = "192.168.0.1".parse().unwrap();
 /// let default_gw: DefaultGateway = ip.into();
 /// ```
-pub trait FromInner {}
+pub trait FromInner: NotValidated {}
+
+/// Gives possibility to create `TaggedType` from UTF-8 bytes:
+/// - `TryFrom<&[u8]>` for `String` and `&str` inner types.
+/// - `from_utf8(&[u8])` for inner types that implement `FromStr`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, FromUtf8, Plain};
+/// use core::convert::TryFrom;
+/// use core::net::IpAddr;
+/// pub type Username<'a> = TaggedType<&'a str, UsernameTag>;
+/// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {};
+/// impl FromUtf8 for UsernameTag {};
+///
+/// pub type DefaultGateway = TaggedType<IpAddr, DefaultGatewayTag>;
+/// pub enum DefaultGatewayTag {}
+/// impl Plain for DefaultGatewayTag {};
+/// impl FromUtf8 for DefaultGatewayTag {};
+///
+/// let username = Username::try_from(&b"admin"[..]).unwrap();
+/// let default_gw = DefaultGateway::from_utf8(b"192.168.0.1").unwrap();
+/// assert!(Username::try_from(&b"\xff"[..]).is_err());
+/// ```
+pub trait FromUtf8: NotValidated {}
+
+/// Gives possibility to create `TaggedType` from OS strings
+/// (command line arguments, environment variables):
+/// - `TryFrom<OsString>` and `TryFrom<&OsStr>` for `String` inner type.
+/// - `TryFrom<&OsStr>` for `&str` inner type.
+/// - `from_os_str(&OsStr)` for inner types that implement `FromStr`.
+///
+/// Errors keep original OS string if it is not valid Unicode.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, FromOsStr, Plain};
+/// use core::convert::TryFrom;
+/// use core::net::IpAddr;
+/// pub type Username = TaggedType<String, UsernameTag>;
+/// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {};
+/// impl FromOsStr for UsernameTag {};
+///
+/// pub type DefaultGateway = TaggedType<IpAddr, DefaultGatewayTag>;
+/// pub enum DefaultGatewayTag {}
+/// impl Plain for DefaultGatewayTag {};
+/// impl FromOsStr for DefaultGatewayTag {};
+///
+/// if let Some(user) = std::env::var_os("USER") {
+///     let username = Username::try_from(user).unwrap();
+/// }
+/// let default_gw = DefaultGateway::from_os_str("192.168.0.1".as_ref()).unwrap();
+/// ```
+pub trait FromOsStr: NotValidated {}
+
+/// Gives possibility to calculate hash of inner value that does not
+/// depend on process, platform and version of Rust:
+/// `stable_hash64()` and `stable_hash128()`.
+///
+/// Inner value is written to `StableHasher` by `StableEncode`, not by
+/// `Hash`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, StableHash, Plain};
+/// pub type TenantId = TaggedType<u64, TenantIdTag>;
+/// pub enum TenantIdTag {}
+/// impl Plain for TenantIdTag {};
+/// impl StableHash for TenantIdTag {};
+///
+/// let shard = TenantId::new(42).stable_hash64() % 16;
+/// ```
+pub trait StableHash {}
+
+/// Enables `TaggedType` to implement `from_ffi` / `into_ffi` for
+/// crossing `extern "C"` boundary.
+///
+/// `TaggedType` is `#[repr(transparent)]`, so tagged type over FFI-safe
+/// inner type can be used directly in `extern "C"` signatures and has
+/// the same ABI as the inner type. `track_construction` feature stores
+/// location in `TaggedType`, so tags cannot implement `Ffi` when it is
+/// enabled (compilation error at the tag definition).
+///
+/// Example:
+#[cfg_attr(not(feature = "track_construction"), doc = "```rust")]
+#[cfg_attr(feature = "track_construction", doc = "```compile_fail,E0277")]
+/// use tagged_types::{TaggedType, Ffi, Plain};
+/// pub type UserId = TaggedType<u64, UserIdTag>;
+/// pub enum UserIdTag {}
+/// impl Plain for UserIdTag {};
+/// impl Ffi for UserIdTag {};
+///
+/// pub extern "C" fn user_id_next(id: UserId) -> u64 {
+///     id.into_ffi() + 1
+/// }
+/// assert_eq!(user_id_next(UserId::from_ffi(1)), 2);
+/// ```
+pub trait Ffi: ffi_layout::TransparentLayout + NotValidated {}
+
+mod ffi_layout {
+    /// Implemented for all tags if `TaggedType` is `#[repr(transparent)]`.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` cannot implement `Ffi` with `track_construction` feature",
+        label = "`TaggedType` is not `#[repr(transparent)]`",
+        note = "`track_construction` feature stores construction location in `TaggedType`"
+    )]
+    pub trait TransparentLayout {}
+
+    #[cfg(not(feature = "track_construction"))]
+    impl<T: ?Sized> TransparentLayout for T {}
+}
+
+/// Enables iteration over ranges of tagged values (`range` /
+/// `range_inclusive`) for inner types that can be used in ranges
+/// (integers, `char`).
+///
+/// `core::iter::Step` is unstable, so `Range<TaggedType<V, T>>` cannot
+/// be iterated on stable Rust.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, StepRange, Plain};
+/// pub type RowIndex = TaggedType<usize, RowIndexTag>;
+/// pub enum RowIndexTag {}
+/// impl Plain for RowIndexTag {};
+/// impl StepRange for RowIndexTag {};
+///
+/// for row in RowIndex::range(RowIndex::new(0), RowIndex::new(10)) {
+///     let _: RowIndex = row;
+/// }
+/// ```
+pub trait StepRange: NotValidated {}
+
+/// Enables overflow-aware arithmetic (`checked_add`, `checked_sub`,
+/// `checked_mul`, `checked_div`, `checked_rem`) of tagged primitive
+/// integers that keeps the tag.
+///
+/// `try_add` / `try_sub` / `try_mul` return `ArithmeticOverflow` error
+/// that names the tagged type instead of `None`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, CheckedArithmetic, Plain};
+/// pub type Balance = TaggedType<u64, BalanceTag>;
+/// pub enum BalanceTag {}
+/// impl Plain for BalanceTag {};
+/// impl CheckedArithmetic for BalanceTag {};
+///
+/// let balance = Balance::new(10);
+/// assert!(balance.checked_sub(20).is_none());
+/// ```
+pub trait CheckedArithmetic: NotValidated {}
+
+/// Enables clamping arithmetic (`saturating_add`, `saturating_sub`,
+/// `saturating_mul`) of tagged primitive integers that keeps the tag
+/// (gauges, rate limits).
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, SaturatingArithmetic, InnerAccess, Plain};
+/// pub type Tokens = TaggedType<u32, TokensTag>;
+/// pub enum TokensTag {}
+/// impl Plain for TokensTag {};
+/// impl SaturatingArithmetic for TokensTag {};
+/// impl InnerAccess for TokensTag {};
+///
+/// assert_eq!(*Tokens::new(3).saturating_sub(5).inner(), 0);
+/// ```
+pub trait SaturatingArithmetic: NotValidated {}
+
+/// Enables modular arithmetic (`wrapping_add`, `wrapping_sub`,
+/// `wrapping_mul`) of tagged primitive integers that keeps the tag
+/// (RTP sequence numbers, ring buffer indices).
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, WrappingArithmetic, InnerAccess, Plain};
+/// pub type SequenceNumber = TaggedType<u16, SequenceNumberTag>;
+/// pub enum SequenceNumberTag {}
+/// impl Plain for SequenceNumberTag {};
+/// impl WrappingArithmetic for SequenceNumberTag {};
+/// impl InnerAccess for SequenceNumberTag {};
+///
+/// assert_eq!(*SequenceNumber::new(u16::MAX).wrapping_add(1).inner(), 0);
+/// ```
+pub trait WrappingArithmetic: NotValidated {}
+
+/// Declares that tag refines `Parent` tag: every value of
+/// `TaggedType<V, Self>` is valid value of `TaggedType<V, Parent>`
+/// (admin username is username).
+///
+/// Enables infallible `upcast()` to parent tagged type and
+/// `try_downcast()` from parent tagged type that checks `Validate` of
+/// the subtag, instead of `into_inner()` / `new()` pairs.
+///
+/// Implementor guarantees that `Validate` of the subtag implies
+/// `Validate` of `Parent`: `upcast()` does not re-run parent
+/// validation (only `debug_assert!`s it). A `NotValidated` subtag may
+/// only refine a `NotValidated` parent.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, SubtagOf, Normalize, Validate, Plain};
+/// pub type Username = TaggedType<String, UsernameTag>;
+/// pub type AdminUsername = TaggedType<String, AdminUsernameTag>;
+/// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {}
+/// pub enum AdminUsernameTag {}
+/// impl SubtagOf<UsernameTag> for AdminUsernameTag {}
+/// impl Normalize<String> for AdminUsernameTag {}
+/// impl Validate<String> for AdminUsernameTag {
+///     type Error = &'static str;
+///     fn validate(v: &String) -> Result<(), Self::Error> {
+///         if v.starts_with("admin") { Ok(()) } else { Err("not admin") }
+///     }
+/// }
+///
+/// let admin = Username::new("admin-1".into()).try_downcast::<AdminUsernameTag>().unwrap();
+/// let user: Username = admin.upcast();
+/// ```
+pub trait SubtagOf<Parent> {}
+
+/// Allows explicit conversion of `TaggedType<V, From>` to
+/// `TaggedType<V, Self>` with `retag()` (`RequestId` to
+/// `CorrelationId`). Tags without declared relation still cannot be
+/// converted to each other.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, AllowRetagFrom, Plain};
+/// pub type RequestId = TaggedType<u64, RequestIdTag>;
+/// pub type CorrelationId = TaggedType<u64, CorrelationIdTag>;
+/// pub enum RequestIdTag {}
+/// impl Plain for RequestIdTag {}
+/// pub enum CorrelationIdTag {}
+/// impl Plain for CorrelationIdTag {}
+/// impl AllowRetagFrom<RequestIdTag> for CorrelationIdTag {}
+///
+/// let id: CorrelationId = RequestId::new(42).retag();
+/// ```
+pub trait AllowRetagFrom<From>: NotValidated {}
+
+/// Declares conversion of inner value of `TaggedType<V, From>` to
+/// `TaggedType<V, Self>` (scale factor or function between units).
+///
+/// Enables `tagged.convert::<Tag>()` and keeps conversion next to tag
+/// definitions.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ConvertTag, InnerAccess, Plain};
+/// pub type Meters = TaggedType<f64, MetersTag>;
+/// pub type Feet = TaggedType<f64, FeetTag>;
+/// pub enum MetersTag {}
+/// impl Plain for MetersTag {}
+/// pub enum FeetTag {}
+/// impl Plain for FeetTag {}
+/// impl InnerAccess for FeetTag {}
+/// impl ConvertTag<MetersTag, f64> for FeetTag {
+///     fn convert(meters: f64) -> f64 {
+///         meters / 0.3048
+///     }
+/// }
+///
+/// let height: Feet = Meters::new(3.048).convert();
+/// assert!((height.inner() - 10.0).abs() < 1e-9);
+/// ```
+pub trait ConvertTag<From, V>: NotValidated {
+    /// Converts inner value of `From` tagged type.
+    fn convert(v: V) -> V;
+}
 
 /// Backward compatible alias for `FromInner`.
-pub trait TransparentFromInner {}
+pub trait TransparentFromInner: NotValidated {}
 
 impl<T: TransparentFromInner> FromInner for T {}
 
 /// Implement `core::ops::Add` trait for `TaggedType`.
 ///
+/// Values of the same tag are added by reference (`&a + &b`): owned
+/// `TaggedType + TaggedType` would overlap with `TaggedType + Rhs`.
+///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, ImplementAdd};
+/// use tagged_types::{TaggedType, ImplementAdd, Plain};
 /// pub type CounterU64 = TaggedType<u64, CounterU64Tag>;
 /// pub enum CounterU64Tag {}
+/// impl Plain for CounterU64Tag {};
 /// impl ImplementAdd for CounterU64Tag {};
 ///
 /// let counter = CounterU64::new(0);
 /// let one: CounterU64 = counter + 1;
+/// let two: CounterU64 = &one + &one;
 /// ```
-pub trait ImplementAdd {}
+pub trait ImplementAdd: NotValidated {}
 
 /// Implement `core::ops::Sub` trait for `TaggedType`.
 ///
+/// Values of the same tag are subtracted by reference (`&a - &b`) if
+/// the tag declares the tag of the difference with `TagSubOutput`.
+///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, ImplementSub, ImplementDefault};
+/// use tagged_types::{TaggedType, ImplementSub, ImplementDefault, TagSubOutput, Plain};
 /// pub type Balance = TaggedType<i64, BalanceTag>;
 /// pub enum BalanceTag {}
+/// impl Plain for BalanceTag {};
 /// impl ImplementDefault for BalanceTag {};
 /// impl ImplementSub for BalanceTag {};
+/// impl TagSubOutput for BalanceTag {
+///     type Output = Self;
+/// }
 ///
 /// let balance = Balance::default();
 /// let credit: Balance = balance - 1;
+/// let change: Balance = &credit - &credit;
 /// ```
-pub trait ImplementSub {}
+pub trait ImplementSub: NotValidated {}
 
 /// Implement `core::ops::Mul` trait for `TaggedType`.
 ///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, ImplementMul};
+/// use tagged_types::{TaggedType, ImplementMul, Plain};
 /// pub type Capital = TaggedType<f64, CapitalTag>;
 /// pub enum CapitalTag {}
+/// impl Plain for CapitalTag {};
 /// impl ImplementMul for CapitalTag {};
 ///
 /// let capital = Capital::new(100.0);
 /// let next_year_capital: Capital = capital * 1.05;
 /// ```
-pub trait ImplementMul {}
+pub trait ImplementMul: NotValidated {}
 
 /// Implement `core::ops::Div` trait for `TaggedType`.
 ///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, ImplementDiv, ImplementDefault};
+/// use tagged_types::{TaggedType, ImplementDiv, ImplementDefault, Plain};
 /// pub type Pie = TaggedType<f64, PieTag>;
 /// pub enum PieTag {}
+/// impl Plain for PieTag {};
 /// impl ImplementDiv for PieTag {};
 ///
 /// let pie = Pie::new(5.0);
 /// let small_pie: Pie = pie / 5.0;
 /// ```
-pub trait ImplementDiv {}
+pub trait ImplementDiv: NotValidated {}
+
+/// Declares that multiplication of `TaggedType<V, Self>` by
+/// `TaggedType<V, Rhs>` produces `TaggedType<V, Self::Output>`
+/// (`Meters * Meters = SquareMeters`).
+///
+/// Multiplication is implemented for references to tagged values:
+/// `&a * &b`. Tags without declared relation cannot be multiplied by
+/// each other.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TagMul, Plain};
+/// pub type Width = TaggedType<f64, MetersTag>;
+/// pub type Area = TaggedType<f64, SquareMetersTag>;
+/// pub enum MetersTag {}
+/// impl Plain for MetersTag {}
+/// pub enum SquareMetersTag {}
+/// impl Plain for SquareMetersTag {}
+/// impl TagMul<MetersTag> for MetersTag {
+///     type Output = SquareMetersTag;
+/// }
+///
+/// let width = Width::new(2.0);
+/// let area: Area = &width * &width;
+/// ```
+pub trait TagMul<Rhs> {
+    /// Tag of the product.
+    type Output: NotValidated;
+}
+
+/// Declares that division of `TaggedType<V, Self>` by
+/// `TaggedType<V, Rhs>` produces `TaggedType<V, Self::Output>`
+/// (`Meters / Seconds = MetersPerSecond`).
+///
+/// Division is implemented for references to tagged values: `&a / &b`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TagDiv, Plain};
+/// pub type Distance = TaggedType<f64, MetersTag>;
+/// pub type Time = TaggedType<f64, SecondsTag>;
+/// pub type Speed = TaggedType<f64, MetersPerSecondTag>;
+/// pub enum MetersTag {}
+/// impl Plain for MetersTag {}
+/// pub enum SecondsTag {}
+/// impl Plain for SecondsTag {}
+/// pub enum MetersPerSecondTag {}
+/// impl Plain for MetersPerSecondTag {}
+/// impl TagDiv<SecondsTag> for MetersTag {
+///     type Output = MetersPerSecondTag;
+/// }
+///
+/// let speed: Speed = &Distance::new(10.0) / &Time::new(4.0);
+/// ```
+pub trait TagDiv<Rhs> {
+    /// Tag of the quotient.
+    type Output: NotValidated;
+}
+
+/// Declares that difference of two `TaggedType<V, Self>` values is
+/// `TaggedType<V, Self::Output>` ("delta" tag), so timestamps subtract
+/// to durations and sequence numbers to offsets.
+///
+/// Subtraction is implemented for references to tagged values: `&a - &b`.
+/// Tags that keep the tag on subtraction declare `Output = Self`.
+/// `ImplementSub` is independent of it, so timestamps can also be
+/// shifted by raw inner values (`timestamp - 10`).
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementSub, TagSubOutput, Plain};
+/// pub type Timestamp = TaggedType<u64, TimestampTag>;
+/// pub type Duration = TaggedType<u64, DurationTag>;
+/// pub enum TimestampTag {}
+/// impl Plain for TimestampTag {}
+/// pub enum DurationTag {}
+/// impl Plain for DurationTag {}
+/// impl ImplementSub for TimestampTag {}
+/// impl TagSubOutput for TimestampTag {
+///     type Output = DurationTag;
+/// }
+///
+/// let elapsed: Duration = &Timestamp::new(15) - &Timestamp::new(10);
+/// let earlier: Timestamp = Timestamp::new(15) - 10;
+/// ```
+pub trait TagSubOutput {
+    /// Tag of the difference.
+    type Output: NotValidated;
+}
+
+/// Implement `core::ops::AddAssign` trait for `TaggedType`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementAddAssign, Plain};
+/// pub type Counter = TaggedType<u64, CounterTag>;
+/// pub enum CounterTag {}
+/// impl Plain for CounterTag {};
+/// impl ImplementAddAssign for CounterTag {};
+///
+/// let mut counter = Counter::new(0);
+/// counter += 1;
+/// ```
+pub trait ImplementAddAssign: NotValidated {}
+
+/// Implement `core::ops::SubAssign` trait for `TaggedType`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementSubAssign, Plain};
+/// pub type Balance = TaggedType<i64, BalanceTag>;
+/// pub enum BalanceTag {}
+/// impl Plain for BalanceTag {};
+/// impl ImplementSubAssign for BalanceTag {};
+///
+/// let mut balance = Balance::new(0);
+/// balance -= 1;
+/// ```
+pub trait ImplementSubAssign: NotValidated {}
+
+/// Implement `core::ops::MulAssign` trait for `TaggedType`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementMulAssign, Plain};
+/// pub type Capital = TaggedType<f64, CapitalTag>;
+/// pub enum CapitalTag {}
+/// impl Plain for CapitalTag {};
+/// impl ImplementMulAssign for CapitalTag {};
+///
+/// let mut capital = Capital::new(100.0);
+/// capital *= 1.05;
+/// ```
+pub trait ImplementMulAssign: NotValidated {}
+
+/// Implement `core::ops::DivAssign` trait for `TaggedType`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementDivAssign, Plain};
+/// pub type Pie = TaggedType<f64, PieTag>;
+/// pub enum PieTag {}
+/// impl Plain for PieTag {};
+/// impl ImplementDivAssign for PieTag {};
+///
+/// let mut pie = Pie::new(5.0);
+/// pie /= 5.0;
+/// ```
+pub trait ImplementDivAssign: NotValidated {}
+
+/// Implement `core::ops::Neg` trait for `TaggedType`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementNeg, ImplementPartialEq, Plain};
+/// pub type Offset = TaggedType<i64, OffsetTag>;
+/// pub enum OffsetTag {}
+/// impl Plain for OffsetTag {};
+/// impl ImplementNeg for OffsetTag {};
+/// impl ImplementPartialEq for OffsetTag {};
+///
+/// let offset = Offset::new(10);
+/// assert!(-offset == Offset::new(-10));
+/// ```
+pub trait ImplementNeg: NotValidated {}
+
+/// Implement `core::ops::Rem` trait for `TaggedType`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementRem, Plain};
+/// pub type Bucket = TaggedType<u64, BucketTag>;
+/// pub enum BucketTag {}
+/// impl Plain for BucketTag {};
+/// impl ImplementRem for BucketTag {};
+///
+/// let bucket = Bucket::new(1234) % 16;
+/// ```
+pub trait ImplementRem: NotValidated {}
+
+/// Implement `core::ops::Not` trait for `TaggedType`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementNot, Plain};
+/// pub type Permissions = TaggedType<u32, PermissionsTag>;
+/// pub enum PermissionsTag {}
+/// impl Plain for PermissionsTag {};
+/// impl ImplementNot for PermissionsTag {};
+///
+/// let denied = !Permissions::new(0b0101);
+/// ```
+pub trait ImplementNot: NotValidated {}
+
+/// Implement `core::ops::BitAnd` trait for `TaggedType`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementBitAnd, Plain};
+/// pub type Permissions = TaggedType<u32, PermissionsTag>;
+/// pub enum PermissionsTag {}
+/// impl Plain for PermissionsTag {};
+/// impl ImplementBitAnd for PermissionsTag {};
+///
+/// let permissions = Permissions::new(0b0101) & 0b0011;
+/// ```
+pub trait ImplementBitAnd: NotValidated {}
+
+/// Implement `core::ops::BitOr` trait for `TaggedType`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementBitOr, Plain};
+/// pub type Permissions = TaggedType<u32, PermissionsTag>;
+/// pub enum PermissionsTag {}
+/// impl Plain for PermissionsTag {};
+/// impl ImplementBitOr for PermissionsTag {};
+///
+/// let permissions = Permissions::new(0b0101) | 0b0011;
+/// ```
+pub trait ImplementBitOr: NotValidated {}
+
+/// Implement `core::ops::BitXor` trait for `TaggedType`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementBitXor, Plain};
+/// pub type Permissions = TaggedType<u32, PermissionsTag>;
+/// pub enum PermissionsTag {}
+/// impl Plain for PermissionsTag {};
+/// impl ImplementBitXor for PermissionsTag {};
+///
+/// let permissions = Permissions::new(0b0101) ^ 0b0011;
+/// ```
+pub trait ImplementBitXor: NotValidated {}
+
+/// Implement `core::ops::BitAndAssign` trait for `TaggedType`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementBitAndAssign, Plain};
+/// pub type Permissions = TaggedType<u32, PermissionsTag>;
+/// pub enum PermissionsTag {}
+/// impl Plain for PermissionsTag {};
+/// impl ImplementBitAndAssign for PermissionsTag {};
+///
+/// let mut permissions = Permissions::new(0b0101);
+/// permissions &= 0b0011;
+/// ```
+pub trait ImplementBitAndAssign: NotValidated {}
+
+/// Implement `core::ops::BitOrAssign` trait for `TaggedType`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementBitOrAssign, Plain};
+/// pub type Permissions = TaggedType<u32, PermissionsTag>;
+/// pub enum PermissionsTag {}
+/// impl Plain for PermissionsTag {};
+/// impl ImplementBitOrAssign for PermissionsTag {};
+///
+/// let mut permissions = Permissions::new(0b0101);
+/// permissions |= 0b0011;
+/// ```
+pub trait ImplementBitOrAssign: NotValidated {}
+
+/// Implement `core::ops::BitXorAssign` trait for `TaggedType`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementBitXorAssign, Plain};
+/// pub type Permissions = TaggedType<u32, PermissionsTag>;
+/// pub enum PermissionsTag {}
+/// impl Plain for PermissionsTag {};
+/// impl ImplementBitXorAssign for PermissionsTag {};
+///
+/// let mut permissions = Permissions::new(0b0101);
+/// permissions ^= 0b0011;
+/// ```
+pub trait ImplementBitXorAssign: NotValidated {}
+
+/// Implement `core::ops::Shl` trait for `TaggedType`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementShl, Plain};
+/// pub type ControlRegister = TaggedType<u32, ControlRegisterTag>;
+/// pub enum ControlRegisterTag {}
+/// impl Plain for ControlRegisterTag {};
+/// impl ImplementShl for ControlRegisterTag {};
+///
+/// let register = ControlRegister::new(1) << 4;
+/// ```
+pub trait ImplementShl: NotValidated {}
+
+/// Implement `core::ops::Shr` trait for `TaggedType`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementShr, Plain};
+/// pub type ControlRegister = TaggedType<u32, ControlRegisterTag>;
+/// pub enum ControlRegisterTag {}
+/// impl Plain for ControlRegisterTag {};
+/// impl ImplementShr for ControlRegisterTag {};
+///
+/// let register = ControlRegister::new(0x80) >> 3;
+/// ```
+pub trait ImplementShr: NotValidated {}
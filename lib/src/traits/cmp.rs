@@ -5,25 +5,34 @@
 ///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, ImplementPartialEq};
+/// use tagged_types::{TaggedType, ImplementPartialEq, Plain};
 /// pub type Username = TaggedType<String, UsernameTag>;
 /// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {};
 /// impl ImplementPartialEq for UsernameTag {};
 /// let admin = Username::new("admin".into());
 /// let root = Username::new("root".into());
 ///
 /// format!("{:?}", admin != root);
 /// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `ImplementPartialEq`",
+    label = "tag must implement `ImplementPartialEq`",
+    note = "add `impl ImplementPartialEq for {Self} {{}}` or `#[implement(PartialEq)]` to the tag"
+)]
 pub trait ImplementPartialEq {}
 
 /// Enables `TaggedType` to implement `Eq` if inner type
 /// implements Eq.
 ///
+/// Requires `ImplementPartialEq` for the tag.
+///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, ImplementEq, ImplementPartialEq};
+/// use tagged_types::{TaggedType, ImplementEq, ImplementPartialEq, Plain};
 /// pub type Username = TaggedType<String, UsernameTag>;
 /// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {};
 /// impl ImplementPartialEq for UsernameTag {};
 /// impl ImplementEq for UsernameTag {};
 /// let admin = Username::new("admin".into());
@@ -31,16 +40,24 @@ pub trait ImplementPartialEq {}
 ///
 /// format!("{:?}", admin != root);
 /// ```
-pub trait ImplementEq {}
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `ImplementEq`",
+    label = "tag must implement `ImplementEq`",
+    note = "add `impl ImplementEq for {Self} {{}}` or `#[implement(Eq)]` to the tag"
+)]
+pub trait ImplementEq: ImplementPartialEq {}
 
 /// Enables `TaggedType` to implement `PartialOrd` if inner type
 /// implements `PartialOrd`.
 ///
+/// Requires `ImplementPartialEq` for the tag.
+///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, ImplementPartialOrd, ImplementPartialEq};
+/// use tagged_types::{TaggedType, ImplementPartialOrd, ImplementPartialEq, Plain};
 /// pub type Priority = TaggedType<u32, PriorityTag>;
 /// pub enum PriorityTag {}
+/// impl Plain for PriorityTag {};
 /// impl ImplementPartialEq for PriorityTag {};
 /// impl ImplementPartialOrd for PriorityTag {};
 /// let p0 = Priority::new(0);
@@ -48,16 +65,24 @@ pub trait ImplementEq {}
 ///
 /// format!("{:?}", p0 < p1);
 /// ```
-pub trait ImplementPartialOrd {}
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `ImplementPartialOrd`",
+    label = "tag must implement `ImplementPartialOrd`",
+    note = "add `impl ImplementPartialOrd for {Self} {{}}` or `#[implement(PartialOrd)]` to the tag"
+)]
+pub trait ImplementPartialOrd: ImplementPartialEq {}
 
 /// Enables `TaggedType` to implement `Ord` if inner type
 /// implements Ord.
 ///
+/// Requires `ImplementPartialOrd` and `ImplementEq` for the tag.
+///
 /// Example:
 /// ```rust
-/// use tagged_types::{TaggedType, ImplementPartialEq, ImplementEq, ImplementOrd, ImplementPartialOrd};
+/// use tagged_types::{TaggedType, ImplementPartialEq, ImplementEq, ImplementOrd, ImplementPartialOrd, Plain};
 /// pub type Priority = TaggedType<u32, PriorityTag>;
 /// pub enum PriorityTag {}
+/// impl Plain for PriorityTag {};
 /// impl ImplementPartialEq for PriorityTag {};
 /// impl ImplementEq for PriorityTag {};
 /// impl ImplementPartialOrd for PriorityTag {};
@@ -67,4 +92,51 @@ pub trait ImplementPartialOrd {}
 ///
 /// format!("{:?}", p0 < p1);
 /// ```
-pub trait ImplementOrd {}
+///
+/// Missing prerequisite is reported at the tag definition, before any
+/// value of the tag is compared:
+/// ```compile_fail,E0277
+/// use tagged_types::{ImplementPartialEq, ImplementOrd, ImplementPartialOrd};
+/// pub enum PriorityTag {}
+/// impl ImplementPartialEq for PriorityTag {};
+/// impl ImplementPartialOrd for PriorityTag {};
+/// impl ImplementOrd for PriorityTag {}; // `PriorityTag` does not implement `ImplementEq`
+/// ```
+pub trait ImplementOrd: ImplementPartialOrd + ImplementEq {}
+
+/// Enables `TaggedType<V, T>` to implement `PartialEq<V>`, so tagged
+/// value can be compared with raw inner value.
+///
+/// Not implemented by `Permissive` because it weakens strictly
+/// tagged comparisons.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementPartialEqInner, Plain};
+/// pub type Port = TaggedType<u16, PortTag>;
+/// pub enum PortTag {}
+/// impl Plain for PortTag {};
+/// impl ImplementPartialEqInner for PortTag {};
+///
+/// assert!(Port::new(22) == 22);
+/// ```
+pub trait ImplementPartialEqInner {}
+
+/// Enables `TaggedType<V, T>` to implement `PartialOrd<V>`, so tagged
+/// value can be compared with raw inner value in range checks.
+///
+/// Requires `ImplementPartialEqInner` for the tag. Not implemented
+/// by `Permissive`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementPartialEqInner, ImplementPartialOrdInner, Plain};
+/// pub type Priority = TaggedType<u8, PriorityTag>;
+/// pub enum PriorityTag {}
+/// impl Plain for PriorityTag {};
+/// impl ImplementPartialEqInner for PriorityTag {};
+/// impl ImplementPartialOrdInner for PriorityTag {};
+///
+/// assert!(Priority::new(7) > 5);
+/// ```
+pub trait ImplementPartialOrdInner: ImplementPartialEqInner {}
@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: MIT
+
+use crate::NotValidated;
+use crate::TransparentFromStr;
+use core::str::FromStr;
+
+/// Enables `TaggedType<V, T>` to implement `AsRef<U>` if `V`
+/// implements `AsRef<U>`.
+///
+/// This gives possibility to pass tagged values to functions that
+/// accept `impl AsRef<str>`, `impl AsRef<Path>` or `impl AsRef<[u8]>`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentAsRef, Plain};
+/// use std::path::{Path, PathBuf};
+/// pub type ConfigPath = TaggedType<PathBuf, ConfigPathTag>;
+/// pub enum ConfigPathTag {}
+/// impl Plain for ConfigPathTag {};
+/// impl TransparentAsRef for ConfigPathTag {};
+///
+/// fn exists(path: impl AsRef<Path>) -> bool {
+///     path.as_ref().exists()
+/// }
+///
+/// let path = ConfigPath::new("/etc/app.toml".into());
+/// exists(&path);
+/// ```
+pub trait TransparentAsRef {}
+
+/// Defines how `TaggedType<V, Self>` implements `AsRef<U>`.
+///
+/// Implemented for all tags that implement `TransparentAsRef`. Tags
+/// that need other references implement it directly. For example,
+/// `AsRef<V>` to inner value itself (derive `#[implement(AsRef)]`):
+///
+/// ```rust
+/// use tagged_types::{TaggedType, AsRefTag, Plain};
+/// pub type Username = TaggedType<String, UsernameTag>;
+/// pub enum UsernameTag {}
+/// impl Plain for UsernameTag {}
+/// impl<V> AsRefTag<V, V> for UsernameTag {
+///     fn as_ref(v: &V) -> &V {
+///         v
+///     }
+/// }
+///
+/// fn len(v: impl AsRef<String>) -> usize {
+///     v.as_ref().len()
+/// }
+/// assert_eq!(len(Username::new("admin".into())), 5);
+/// ```
+pub trait AsRefTag<V, U: ?Sized> {
+    /// Converts reference to inner value of `TaggedType<V, Self>` to
+    /// reference to `U`.
+    fn as_ref(v: &V) -> &U;
+}
+
+impl<U: ?Sized, V: AsRef<U>, T: TransparentAsRef> AsRefTag<V, U> for T {
+    #[inline]
+    fn as_ref(v: &V) -> &U {
+        v.as_ref()
+    }
+}
+
+/// Defines how `TaggedType<V, Self>` implements `core::str::FromStr`.
+///
+/// Implemented for all tags that implement `TransparentFromStr`. Tags
+/// of values with own grammar (prefixes, checksums) implement it
+/// directly:
+///
+/// ```rust
+/// use tagged_types::{TaggedType, ParseTag};
+/// pub type UserId = TaggedType<u64, UserIdTag>;
+/// pub enum UserIdTag {}
+/// impl ParseTag<u64> for UserIdTag {
+///     type Err = &'static str;
+///     fn parse(s: &str) -> Result<u64, Self::Err> {
+///         s.strip_prefix("usr_")
+///             .ok_or("missing usr_ prefix")?
+///             .parse()
+///             .map_err(|_| "invalid number")
+///     }
+/// }
+///
+/// assert!("usr_42".parse::<UserId>().is_ok());
+/// assert!("42".parse::<UserId>().is_err());
+/// ```
+pub trait ParseTag<V> {
+    /// Error of parsing.
+    type Err;
+
+    /// Parse inner value of `TaggedType<V, Self>` from string.
+    ///
+    /// # Errors
+    ///
+    /// Will return error if string is not valid representation of
+    /// the value.
+    fn parse(s: &str) -> Result<V, Self::Err>;
+}
+
+impl<V: FromStr, T: TransparentFromStr> ParseTag<V> for T {
+    type Err = V::Err;
+
+    #[inline]
+    fn parse(s: &str) -> Result<V, Self::Err> {
+        V::from_str(s)
+    }
+}
+
+/// Enables `TaggedType<V, T>` to implement `AsMut<V>`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementAsMut, Plain};
+/// pub type Buffer = TaggedType<Vec<u8>, BufferTag>;
+/// pub enum BufferTag {}
+/// impl Plain for BufferTag {};
+/// impl ImplementAsMut for BufferTag {};
+///
+/// fn clear(mut v: impl AsMut<Vec<u8>>) {
+///     v.as_mut().clear();
+/// }
+/// clear(Buffer::new(vec![1, 2]));
+/// ```
+pub trait ImplementAsMut: NotValidated {}
+
+/// Enables fallible construction of `TaggedType<V, T>` from any `S`
+/// that `V` implements `TryFrom<S>` for (`try_new_from`).
+///
+/// `TryFrom<S>` itself cannot be implemented for `TaggedType` because
+/// it conflicts with `core` implementation of `TryFrom<U>` for all
+/// `U: Into<T>`. Error is error of inner conversion.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentTryFrom, Plain};
+/// use core::num::NonZeroU32;
+/// pub type WorkerCount = TaggedType<NonZeroU32, WorkerCountTag>;
+/// pub enum WorkerCountTag {}
+/// impl Plain for WorkerCountTag {};
+/// impl TransparentTryFrom for WorkerCountTag {};
+///
+/// assert!(WorkerCount::try_new_from(4u32).is_ok());
+/// assert!(WorkerCount::try_new_from(0u32).is_err());
+/// ```
+pub trait TransparentTryFrom: NotValidated {}
@@ -0,0 +1,466 @@
+// SPDX-License-Identifier: MIT
+
+use crate::NotValidated;
+use crate::TransparentDebug;
+use crate::TransparentDisplay;
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::fmt::Write;
+use core::marker::PhantomData;
+
+/// Defines how `TaggedType<V, Self>` implements `core::fmt::Display`.
+///
+/// Implemented for all tags that implement `TruncatedDisplay`, so for
+/// `TransparentDisplay`, `ReleaseRedacted` and `Permissive` tags too.
+/// Tags that need custom formatting (for example `TagUnit` tags)
+/// implement it directly.
+pub trait DisplayTag<V: ?Sized> {
+    /// Format inner value of `TaggedType<V, Self>`.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of the formatter.
+    fn fmt(v: &V, f: &mut Formatter<'_>) -> FmtResult;
+}
+
+impl<V: Display + ?Sized, T: TruncatedDisplay> DisplayTag<V> for T {
+    #[inline]
+    fn fmt(v: &V, f: &mut Formatter<'_>) -> FmtResult {
+        T::fmt_truncated(v, f)
+    }
+}
+
+/// Defines how `TaggedType<V, Self>` implements `core::fmt::Debug`.
+///
+/// Implemented for all tags that implement `TruncatedDebug`, so for
+/// `TransparentDebug`, `ReleaseRedacted` and `Permissive` tags too.
+/// Tags that need custom formatting implement it directly.
+pub trait DebugTag<V: ?Sized> {
+    /// Format inner value of `TaggedType<V, Self>`.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of the formatter.
+    fn fmt(v: &V, f: &mut Formatter<'_>) -> FmtResult;
+}
+
+impl<V: Debug + ?Sized, T: TruncatedDebug> DebugTag<V> for T {
+    #[inline]
+    fn fmt(v: &V, f: &mut Formatter<'_>) -> FmtResult {
+        T::fmt_truncated_debug(v, f)
+    }
+}
+
+/// Enables `core::fmt::LowerHex` of `TaggedType` formatted the same as
+/// inner value.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentLowerHex, Plain};
+/// pub type DeviceId = TaggedType<u32, DeviceIdTag>;
+/// pub enum DeviceIdTag {}
+/// impl Plain for DeviceIdTag {};
+/// impl TransparentLowerHex for DeviceIdTag {};
+///
+/// assert_eq!(format!("{:#x}", DeviceId::new(42)), "0x2a");
+/// ```
+pub trait TransparentLowerHex {}
+
+/// Enables `core::fmt::UpperHex` of `TaggedType` formatted the same as
+/// inner value.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentUpperHex, Plain};
+/// pub type DeviceId = TaggedType<u32, DeviceIdTag>;
+/// pub enum DeviceIdTag {}
+/// impl Plain for DeviceIdTag {};
+/// impl TransparentUpperHex for DeviceIdTag {};
+///
+/// assert_eq!(format!("{:X}", DeviceId::new(42)), "2A");
+/// ```
+pub trait TransparentUpperHex {}
+
+/// Enables `core::fmt::Octal` of `TaggedType` formatted the same as
+/// inner value.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentOctal, Plain};
+/// pub type DeviceId = TaggedType<u32, DeviceIdTag>;
+/// pub enum DeviceIdTag {}
+/// impl Plain for DeviceIdTag {};
+/// impl TransparentOctal for DeviceIdTag {};
+///
+/// assert_eq!(format!("{:o}", DeviceId::new(42)), "52");
+/// ```
+pub trait TransparentOctal {}
+
+/// Enables `core::fmt::Binary` of `TaggedType` formatted the same as
+/// inner value.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentBinary, Plain};
+/// pub type DeviceId = TaggedType<u32, DeviceIdTag>;
+/// pub enum DeviceIdTag {}
+/// impl Plain for DeviceIdTag {};
+/// impl TransparentBinary for DeviceIdTag {};
+///
+/// assert_eq!(format!("{:#010b}", DeviceId::new(42)), "0b00101010");
+/// ```
+pub trait TransparentBinary {}
+
+/// Enables `core::fmt::LowerExp` of `TaggedType` formatted the same as
+/// inner value.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentLowerExp, Plain};
+/// pub type Seconds = TaggedType<f64, SecondsTag>;
+/// pub enum SecondsTag {}
+/// impl Plain for SecondsTag {};
+/// impl TransparentLowerExp for SecondsTag {};
+///
+/// assert_eq!(format!("{:e}", Seconds::new(0.000_001_5)), "1.5e-6");
+/// ```
+pub trait TransparentLowerExp {}
+
+/// Enables `core::fmt::UpperExp` of `TaggedType` formatted the same as
+/// inner value.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentUpperExp, Plain};
+/// pub type Seconds = TaggedType<f64, SecondsTag>;
+/// pub enum SecondsTag {}
+/// impl Plain for SecondsTag {};
+/// impl TransparentUpperExp for SecondsTag {};
+///
+/// assert_eq!(format!("{:.2E}", Seconds::new(0.000_001_5)), "1.50E-6");
+/// ```
+pub trait TransparentUpperExp {}
+
+/// Enables `TaggedType` to be used as formatting sink
+/// (`core::fmt::Write`) if inner value is.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentFmtWrite, Plain};
+/// use core::fmt::Write;
+/// pub type Report = TaggedType<String, ReportTag>;
+/// pub enum ReportTag {}
+/// impl Plain for ReportTag {};
+/// impl TransparentFmtWrite for ReportTag {};
+///
+/// let mut report = Report::new(String::new());
+/// write!(report, "{} hosts", 3).unwrap();
+/// ```
+pub trait TransparentFmtWrite: NotValidated {}
+
+/// Limits number of characters printed by `Display` of `TaggedType`
+/// to `MAX_LEN`, so large payloads (bodies, blobs) do not blow up log
+/// lines. Longer output is cut and `ELLIPSIS` is appended.
+///
+/// Provides `DisplayTag`. `TransparentDisplay` tags implement it with
+/// unlimited `MAX_LEN`, so tag cannot be truncated and transparent at
+/// the same time:
+/// ```rust,compile_fail,E0119
+/// use tagged_types::{TransparentDisplay, TruncatedDisplay};
+/// pub enum BodyTag {}
+/// impl TransparentDisplay for BodyTag {}
+/// impl TruncatedDisplay for BodyTag {
+///     const MAX_LEN: usize = 5;
+/// }
+/// ```
+///
+/// Derive `#[truncated(Display, max_len = N)]` implements it.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TruncatedDisplay, Plain};
+/// pub type Body = TaggedType<String, BodyTag>;
+/// pub enum BodyTag {}
+/// impl Plain for BodyTag {}
+/// impl TruncatedDisplay for BodyTag {
+///     const MAX_LEN: usize = 5;
+/// }
+///
+/// assert_eq!(format!("{}", Body::new("Hello, world".into())), "Hello…");
+/// assert_eq!(format!("{}", Body::new("Hello".into())), "Hello");
+/// ```
+pub trait TruncatedDisplay {
+    /// Maximum number of printed characters of inner value.
+    const MAX_LEN: usize;
+    /// Appended to output if it is truncated.
+    const ELLIPSIS: &'static str = "…";
+
+    /// Format whole inner value before truncation.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of the formatter.
+    #[inline]
+    fn fmt_untruncated<V: Display + ?Sized>(v: &V, f: &mut Formatter<'_>) -> FmtResult {
+        v.fmt(f)
+    }
+
+    /// Format `v` truncated to `MAX_LEN` characters.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of the formatter.
+    #[inline]
+    fn fmt_truncated<V: Display + ?Sized>(v: &V, f: &mut Formatter<'_>) -> FmtResult {
+        if Self::MAX_LEN == usize::MAX {
+            // Keep width, precision and other flags of formatter.
+            return Self::fmt_untruncated(v, f);
+        }
+        let mut w = Truncate::new(f, Self::MAX_LEN);
+        write!(w, "{}", Untruncated::<Self, V>(v, PhantomData))?;
+        w.finish(Self::ELLIPSIS)
+    }
+}
+
+impl<T: TransparentDisplay> TruncatedDisplay for T {
+    const MAX_LEN: usize = usize::MAX;
+
+    #[inline]
+    fn fmt_untruncated<V: Display + ?Sized>(v: &V, f: &mut Formatter<'_>) -> FmtResult {
+        T::fmt_display(v, f)
+    }
+}
+
+/// Limits number of characters printed by `Debug` of `TaggedType` to
+/// `MAX_LEN`. Longer output is cut and `ELLIPSIS` is appended.
+///
+/// Provides `DebugTag`. `TransparentDebug` tags implement it with
+/// unlimited `MAX_LEN`. Derive `#[truncated(Debug, max_len = N)]`
+/// implements it.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TruncatedDebug, Plain};
+/// pub type Blob = TaggedType<Vec<u8>, BlobTag>;
+/// pub enum BlobTag {}
+/// impl Plain for BlobTag {}
+/// impl TruncatedDebug for BlobTag {
+///     const MAX_LEN: usize = 8;
+///     const ELLIPSIS: &'static str = "...";
+/// }
+///
+/// assert_eq!(format!("{:?}", Blob::new(vec![1, 2, 3, 4, 5])), "[1, 2, 3...");
+/// ```
+pub trait TruncatedDebug {
+    /// Maximum number of printed characters of inner value.
+    const MAX_LEN: usize;
+    /// Appended to output if it is truncated.
+    const ELLIPSIS: &'static str = "…";
+
+    /// Format whole inner value before truncation.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of the formatter.
+    #[inline]
+    fn fmt_untruncated_debug<V: Debug + ?Sized>(v: &V, f: &mut Formatter<'_>) -> FmtResult {
+        v.fmt(f)
+    }
+
+    /// Format `v` using `Debug` truncated to `MAX_LEN` characters.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of the formatter.
+    #[inline]
+    fn fmt_truncated_debug<V: Debug + ?Sized>(v: &V, f: &mut Formatter<'_>) -> FmtResult {
+        if Self::MAX_LEN == usize::MAX {
+            // Keep alternate and other flags of formatter.
+            return Self::fmt_untruncated_debug(v, f);
+        }
+        let mut w = Truncate::new(f, Self::MAX_LEN);
+        write!(w, "{}", UntruncatedDebug::<Self, V>(v, PhantomData))?;
+        w.finish(Self::ELLIPSIS)
+    }
+}
+
+impl<T: TransparentDebug> TruncatedDebug for T {
+    const MAX_LEN: usize = usize::MAX;
+
+    #[inline]
+    fn fmt_untruncated_debug<V: Debug + ?Sized>(v: &V, f: &mut Formatter<'_>) -> FmtResult {
+        T::fmt_debug(v, f)
+    }
+}
+
+/// Hides semi-sensitive values (emails, phone numbers) in `Display` /
+/// `Debug` of `TaggedType` unless `REVEAL` is set: real value is
+/// printed during development, `PLACEHOLDER` in production logs.
+///
+/// `REVEAL` has no default because `cfg!(debug_assertions)` must be
+/// evaluated in the crate of the tag, not in this library that may be
+/// built with different profile. Derive `#[release_redacted]` sets it
+/// to `cfg!(debug_assertions)` of the deriving crate.
+///
+/// Provides `TransparentDisplay` and `TransparentDebug` that print
+/// `PLACEHOLDER` if value is not revealed. `Permissive` tags implement
+/// it with `REVEAL = true`, so tag that also implements transparent
+/// formatting does not compile instead of printing the value:
+/// ```rust,compile_fail,E0119
+/// use tagged_types::{ReleaseRedacted, TransparentDisplay};
+/// pub enum EmailTag {}
+/// impl TransparentDisplay for EmailTag {}
+/// impl ReleaseRedacted for EmailTag {
+///     const REVEAL: bool = cfg!(debug_assertions);
+/// }
+/// ```
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ReleaseRedacted, Plain};
+/// pub type Email = TaggedType<String, EmailTag>;
+/// pub enum EmailTag {}
+/// impl Plain for EmailTag {}
+/// impl ReleaseRedacted for EmailTag {
+///     const REVEAL: bool = cfg!(debug_assertions);
+/// }
+///
+/// let email = Email::new("admin@example.com".into());
+/// if cfg!(debug_assertions) {
+///     assert_eq!(email.to_string(), "admin@example.com");
+/// } else {
+///     assert_eq!(format!("{email:?}"), "***");
+/// }
+/// ```
+pub trait ReleaseRedacted {
+    /// Print real inner value instead of `PLACEHOLDER`.
+    const REVEAL: bool;
+    /// Printed instead of inner value that is not revealed.
+    const PLACEHOLDER: &'static str = "***";
+}
+
+impl<T: ReleaseRedacted> TransparentDisplay for T {
+    #[inline]
+    fn fmt_display<V: Display + ?Sized>(v: &V, f: &mut Formatter<'_>) -> FmtResult {
+        if T::REVEAL {
+            v.fmt(f)
+        } else {
+            f.write_str(T::PLACEHOLDER)
+        }
+    }
+}
+
+impl<T: ReleaseRedacted> TransparentDebug for T {
+    #[inline]
+    fn fmt_debug<V: Debug + ?Sized>(v: &V, f: &mut Formatter<'_>) -> FmtResult {
+        if T::REVEAL {
+            v.fmt(f)
+        } else {
+            f.write_str(T::PLACEHOLDER)
+        }
+    }
+}
+
+/// `Display` of value formatted by `TruncatedDisplay::fmt_untruncated`.
+struct Untruncated<'a, T: ?Sized, V: ?Sized>(&'a V, PhantomData<fn() -> T>);
+
+impl<T: TruncatedDisplay + ?Sized, V: Display + ?Sized> Display for Untruncated<'_, T, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        T::fmt_untruncated(self.0, f)
+    }
+}
+
+/// `Display` of value formatted by
+/// `TruncatedDebug::fmt_untruncated_debug`.
+struct UntruncatedDebug<'a, T: ?Sized, V: ?Sized>(&'a V, PhantomData<fn() -> T>);
+
+impl<T: TruncatedDebug + ?Sized, V: Debug + ?Sized> Display for UntruncatedDebug<'_, T, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        T::fmt_untruncated_debug(self.0, f)
+    }
+}
+
+/// Unit of measure printed around inner value by `Display` of
+/// `TaggedType` (`"15 ms"`, `"€10"`).
+///
+/// Derive `#[display_with_unit(prefix = "...", suffix = "...")]`
+/// implements `TagUnit` and `DisplayTag`. Without derive `DisplayTag`
+/// is implemented using `fmt_with_unit`.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TagUnit, DisplayTag, Plain};
+/// use core::fmt::{Display, Formatter, Result};
+/// pub type Latency = TaggedType<u64, LatencyTag>;
+/// pub enum LatencyTag {}
+/// impl Plain for LatencyTag {}
+/// impl TagUnit for LatencyTag {
+///     const SUFFIX: &'static str = " ms";
+/// }
+/// impl<V: Display + ?Sized> DisplayTag<V> for LatencyTag {
+///     fn fmt(v: &V, f: &mut Formatter<'_>) -> Result {
+///         Self::fmt_with_unit(v, f)
+///     }
+/// }
+///
+/// assert_eq!(Latency::new(15).to_string(), "15 ms");
+/// ```
+pub trait TagUnit {
+    /// Printed before inner value.
+    const PREFIX: &'static str = "";
+    /// Printed after inner value.
+    const SUFFIX: &'static str = "";
+
+    /// Format `v` using `Display` between `PREFIX` and `SUFFIX`.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of the formatter.
+    #[inline]
+    fn fmt_with_unit<V: Display + ?Sized>(v: &V, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}{v}{}", Self::PREFIX, Self::SUFFIX)
+    }
+}
+
+/// Writer that passes at most `remaining` characters to formatter.
+struct Truncate<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    remaining: usize,
+    truncated: bool,
+}
+
+impl<'a, 'b> Truncate<'a, 'b> {
+    const fn new(f: &'a mut Formatter<'b>, max_len: usize) -> Self {
+        Self {
+            f,
+            remaining: max_len,
+            truncated: false,
+        }
+    }
+
+    fn finish(self, ellipsis: &str) -> FmtResult {
+        if self.truncated {
+            self.f.write_str(ellipsis)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Write for Truncate<'_, '_> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        if self.truncated {
+            return Ok(());
+        }
+        if let Some((end, _)) = s.char_indices().nth(self.remaining) {
+            self.truncated = true;
+            self.remaining = 0;
+            self.f.write_str(&s[..end])
+        } else {
+            self.remaining -= s.chars().count();
+            self.f.write_str(s)
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT
+
+use crate::NotValidated;
+
+/// Enables `TaggedType` to implement `std::io::Read` if inner value
+/// implements it.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentRead, Plain};
+/// use std::io::{Cursor, Read};
+/// pub type Request = TaggedType<Cursor<Vec<u8>>, RequestTag>;
+/// pub enum RequestTag {}
+/// impl Plain for RequestTag {};
+/// impl TransparentRead for RequestTag {};
+///
+/// let mut request = Request::new(Cursor::new(b"GET /".to_vec()));
+/// let mut method = [0; 3];
+/// request.read_exact(&mut method).unwrap();
+/// assert_eq!(&method, b"GET");
+/// ```
+pub trait TransparentRead {}
+
+/// Enables `TaggedType` to implement `std::io::Write` if inner value
+/// implements it.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentWrite, Plain};
+/// use std::io::Write;
+/// pub type AuditLog = TaggedType<Vec<u8>, AuditLogTag>;
+/// pub enum AuditLogTag {}
+/// impl Plain for AuditLogTag {};
+/// impl TransparentWrite for AuditLogTag {};
+///
+/// let mut log = AuditLog::new(vec![]);
+/// writeln!(log, "user admin logged in").unwrap();
+/// ```
+pub trait TransparentWrite: NotValidated {}
+
+/// Enables `TaggedType` to implement `std::io::Seek` if inner value
+/// implements it.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, TransparentSeek, Plain};
+/// use std::io::{Cursor, Seek, SeekFrom};
+/// pub type Archive = TaggedType<Cursor<Vec<u8>>, ArchiveTag>;
+/// pub enum ArchiveTag {}
+/// impl Plain for ArchiveTag {};
+/// impl TransparentSeek for ArchiveTag {};
+///
+/// let mut archive = Archive::new(Cursor::new(vec![0; 16]));
+/// assert_eq!(archive.seek(SeekFrom::End(-4)).unwrap(), 12);
+/// ```
+pub trait TransparentSeek {}
@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: MIT
+
+use core::convert::TryFrom as _;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Primitive integer types (`u8`..`u128`, `i8`..`i128`, `usize`,
+/// `isize`). Used as bound of capabilities available only for
+/// integer inner types.
+///
+/// This trait is sealed and cannot be implemented outside of the
+/// crate.
+pub trait PrimitiveInteger: Copy + sealed::Sealed {
+    /// Byte array representation of the integer.
+    type Bytes;
+
+    /// Minimal value of the integer saturated to `i128`.
+    const MIN_I128: i128;
+    /// Maximal value of the integer saturated to `i128`.
+    const MAX_I128: i128;
+
+    /// Same as `to_be_bytes` of the integer.
+    fn to_be_bytes(self) -> Self::Bytes;
+    /// Same as `to_le_bytes` of the integer.
+    fn to_le_bytes(self) -> Self::Bytes;
+    /// Same as `from_be_bytes` of the integer.
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+    /// Same as `from_le_bytes` of the integer.
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+
+    /// Convert to `i128` if value fits.
+    fn to_i128(self) -> Option<i128>;
+    /// Convert from `i128` if value fits.
+    fn from_i128(v: i128) -> Option<Self>;
+    /// Convert to nearest `f64` (large values lose precision).
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! primitive_integer {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl PrimitiveInteger for $t {
+                type Bytes = [u8; core::mem::size_of::<$t>()];
+
+                #[allow(clippy::cast_lossless, clippy::cast_possible_wrap)]
+                const MIN_I128: i128 = <$t>::MIN as i128;
+                #[allow(clippy::cast_lossless, clippy::cast_possible_wrap)]
+                const MAX_I128: i128 = if (<$t>::MAX as u128) > (i128::MAX as u128) {
+                    i128::MAX
+                } else {
+                    <$t>::MAX as i128
+                };
+
+                #[inline]
+                fn to_be_bytes(self) -> Self::Bytes {
+                    <$t>::to_be_bytes(self)
+                }
+
+                #[inline]
+                fn to_le_bytes(self) -> Self::Bytes {
+                    <$t>::to_le_bytes(self)
+                }
+
+                #[inline]
+                fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                    <$t>::from_be_bytes(bytes)
+                }
+
+                #[inline]
+                fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                    <$t>::from_le_bytes(bytes)
+                }
+
+                #[inline]
+                fn to_i128(self) -> Option<i128> {
+                    i128::try_from(self).ok()
+                }
+
+                #[inline]
+                fn from_i128(v: i128) -> Option<Self> {
+                    <$t>::try_from(v).ok()
+                }
+
+                #[inline]
+                #[allow(clippy::cast_lossless, clippy::cast_precision_loss)]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+primitive_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
@@ -4,23 +4,77 @@ use crate::traits::cmp::ImplementEq;
 use crate::traits::cmp::ImplementOrd;
 use crate::traits::cmp::ImplementPartialEq;
 use crate::traits::cmp::ImplementPartialOrd;
+use crate::traits::convert::ImplementAsMut;
+use crate::traits::convert::TransparentAsRef;
+use crate::traits::convert::TransparentTryFrom;
+use crate::traits::fmt::ReleaseRedacted;
+use crate::traits::fmt::TransparentBinary;
+use crate::traits::fmt::TransparentFmtWrite;
+use crate::traits::fmt::TransparentLowerExp;
+use crate::traits::fmt::TransparentLowerHex;
+use crate::traits::fmt::TransparentOctal;
+use crate::traits::fmt::TransparentUpperExp;
+use crate::traits::fmt::TransparentUpperHex;
+use crate::traits::AsDeref;
 use crate::traits::AsRef;
+use crate::traits::ByteConversion;
+use crate::traits::CheckedArithmetic;
 use crate::traits::Cloned;
+use crate::traits::Copied;
+#[cfg(not(feature = "track_construction"))]
+use crate::traits::Ffi;
+use crate::traits::FromOsStr;
+use crate::traits::FromUtf8;
 use crate::traits::ImplementAdd;
+use crate::traits::ImplementAddAssign;
+use crate::traits::ImplementBitAnd;
+use crate::traits::ImplementBitAndAssign;
+use crate::traits::ImplementBitOr;
+use crate::traits::ImplementBitOrAssign;
+use crate::traits::ImplementBitXor;
+use crate::traits::ImplementBitXorAssign;
+use crate::traits::ImplementBorrow;
 use crate::traits::ImplementClone;
 use crate::traits::ImplementCopy;
 use crate::traits::ImplementDefault;
 use crate::traits::ImplementDiv;
+use crate::traits::ImplementDivAssign;
 use crate::traits::ImplementHash;
 use crate::traits::ImplementMul;
+use crate::traits::ImplementMulAssign;
+use crate::traits::ImplementNeg;
+use crate::traits::ImplementNot;
+use crate::traits::ImplementRem;
+use crate::traits::ImplementShl;
+use crate::traits::ImplementShr;
 use crate::traits::ImplementSub;
+use crate::traits::ImplementSubAssign;
 use crate::traits::InnerAccess;
-use crate::traits::TransparentDebug;
-use crate::traits::TransparentDisplay;
+use crate::traits::KeyedAccess;
+use crate::traits::MutInnerAccess;
+use crate::traits::SaturatingArithmetic;
+use crate::traits::StableHash;
+use crate::traits::StepRange;
+use crate::traits::TransparentError;
+use crate::traits::TransparentExtend;
 use crate::traits::TransparentFromInner;
+use crate::traits::TransparentFromIterator;
 use crate::traits::TransparentFromStr;
+use crate::traits::TransparentFuture;
+use crate::traits::TransparentIndex;
+use crate::traits::TransparentIndexMut;
+use crate::traits::TransparentIntoIterator;
+use crate::traits::TransparentIterator;
 use crate::traits::ValueMap;
+use crate::traits::WrappingArithmetic;
+use crate::validation::Plain;
 
+#[cfg(feature = "support_io")]
+use crate::traits::io::TransparentRead;
+#[cfg(feature = "support_io")]
+use crate::traits::io::TransparentSeek;
+#[cfg(feature = "support_io")]
+use crate::traits::io::TransparentWrite;
 #[cfg(feature = "support_serde")]
 use crate::traits::serde::TransparentDeserialize;
 #[cfg(feature = "support_serde")]
@@ -62,10 +116,27 @@ use crate::traits::serde::TransparentSerialize;
 /// ```
 pub trait Permissive {}
 
+impl<T> AsDeref for T where T: Permissive {}
 impl<T> AsRef for T where T: Permissive {}
+impl<T> ByteConversion for T where T: Permissive {}
+impl<T> CheckedArithmetic for T where T: Permissive {}
 impl<T> Cloned for T where T: Permissive {}
+impl<T> Copied for T where T: Permissive {}
+#[cfg(not(feature = "track_construction"))]
+impl<T> Ffi for T where T: Permissive {}
+impl<T> FromOsStr for T where T: Permissive {}
+impl<T> FromUtf8 for T where T: Permissive {}
 impl<T> InnerAccess for T where T: Permissive {}
+impl<T> KeyedAccess for T where T: Permissive {}
+impl<T> MutInnerAccess for T where T: Permissive {}
+impl<T> Plain for T where T: Permissive {}
+impl<T> SaturatingArithmetic for T where T: Permissive {}
+impl<T> StableHash for T where T: Permissive {}
+impl<T> StepRange for T where T: Permissive {}
 impl<T> ValueMap for T where T: Permissive {}
+impl<T> WrappingArithmetic for T where T: Permissive {}
+impl<T> ImplementAsMut for T where T: Permissive {}
+impl<T> ImplementBorrow for T where T: Permissive {}
 impl<T> ImplementCopy for T where T: Permissive {}
 impl<T> ImplementClone for T where T: Permissive {}
 impl<T> ImplementDefault for T where T: Permissive {}
@@ -75,16 +146,63 @@ impl<T> ImplementPartialOrd for T where T: Permissive {}
 impl<T> ImplementOrd for T where T: Permissive {}
 impl<T> ImplementHash for T where T: Permissive {}
 impl<T> ImplementAdd for T where T: Permissive {}
+impl<T> ImplementAddAssign for T where T: Permissive {}
 impl<T> ImplementSub for T where T: Permissive {}
+impl<T> ImplementSubAssign for T where T: Permissive {}
 impl<T> ImplementMul for T where T: Permissive {}
+impl<T> ImplementMulAssign for T where T: Permissive {}
 impl<T> ImplementDiv for T where T: Permissive {}
-impl<T> TransparentDebug for T where T: Permissive {}
-impl<T> TransparentDisplay for T where T: Permissive {}
+impl<T> ImplementDivAssign for T where T: Permissive {}
+impl<T> ImplementNeg for T where T: Permissive {}
+impl<T> ImplementRem for T where T: Permissive {}
+impl<T> ImplementNot for T where T: Permissive {}
+impl<T> ImplementBitAnd for T where T: Permissive {}
+impl<T> ImplementBitOr for T where T: Permissive {}
+impl<T> ImplementBitXor for T where T: Permissive {}
+impl<T> ImplementBitAndAssign for T where T: Permissive {}
+impl<T> ImplementBitOrAssign for T where T: Permissive {}
+impl<T> ImplementBitXorAssign for T where T: Permissive {}
+impl<T> ImplementShl for T where T: Permissive {}
+impl<T> ImplementShr for T where T: Permissive {}
+impl<T> TransparentAsRef for T where T: Permissive {}
+// Gives transparent `Display` / `Debug` that cannot be combined with
+// hiding of the value.
+impl<T> ReleaseRedacted for T
+where
+    T: Permissive,
+{
+    const REVEAL: bool = true;
+}
+impl<T> TransparentFmtWrite for T where T: Permissive {}
+impl<T> TransparentLowerHex for T where T: Permissive {}
+impl<T> TransparentUpperHex for T where T: Permissive {}
+impl<T> TransparentLowerExp for T where T: Permissive {}
+impl<T> TransparentUpperExp for T where T: Permissive {}
+impl<T> TransparentOctal for T where T: Permissive {}
+impl<T> TransparentBinary for T where T: Permissive {}
+impl<T> TransparentError for T where T: Permissive {}
 impl<T> TransparentFromInner for T where T: Permissive {}
 impl<T> TransparentFromStr for T where T: Permissive {}
+impl<T> TransparentTryFrom for T where T: Permissive {}
+impl<T> TransparentFuture for T where T: Permissive {}
+impl<T> TransparentIndex for T where T: Permissive {}
+impl<T> TransparentIndexMut for T where T: Permissive {}
+impl<T> TransparentIntoIterator for T where T: Permissive {}
+impl<T> TransparentIterator for T where T: Permissive {}
+impl<T> TransparentFromIterator for T where T: Permissive {}
+impl<T> TransparentExtend for T where T: Permissive {}
 
 #[cfg(feature = "support_serde")]
 impl<T> TransparentSerialize for T where T: Permissive {}
 
 #[cfg(feature = "support_serde")]
 impl<T> TransparentDeserialize for T where T: Permissive {}
+
+#[cfg(feature = "support_io")]
+impl<T> TransparentRead for T where T: Permissive {}
+
+#[cfg(feature = "support_io")]
+impl<T> TransparentSeek for T where T: Permissive {}
+
+#[cfg(feature = "support_io")]
+impl<T> TransparentWrite for T where T: Permissive {}
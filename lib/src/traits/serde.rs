@@ -1,5 +1,11 @@
 // SPDX-License-Identifier: MIT
 
+use crate::Plain;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
 /// Transparent serde serialize if inner type implemnts
 /// serde serialization.
 ///
@@ -8,4 +14,141 @@ pub trait TransparentSerialize {}
 /// Transparent serde serialize if inner type implemnts
 /// serde serialization.
 ///
-pub trait TransparentDeserialize {}
+/// Deserialized value is neither normalized nor validated, so only
+/// `Plain` tags implement it. Tags with own `Validate` or `Normalize`
+/// implement `DeserializeTag` with `validation::deserialize_validated`
+/// instead:
+/// ```rust,compile_fail,E0277
+/// use tagged_types::{TaggedType, Normalize, TransparentDeserialize, Validate};
+/// pub type Port = TaggedType<u16, PortTag>;
+/// pub enum PortTag {}
+/// impl Normalize<u16> for PortTag {}
+/// impl Validate<u16> for PortTag {
+///     type Error = &'static str;
+///     fn validate(v: &u16) -> Result<(), Self::Error> {
+///         if *v == 0 { Err("zero port") } else { Ok(()) }
+///     }
+/// }
+/// impl TransparentDeserialize for PortTag {};
+/// ```
+pub trait TransparentDeserialize: Plain {}
+
+/// Defines how `TaggedType<V, Self>` is serialized.
+///
+/// Implemented for all tags that implement `TransparentSerialize` or
+/// `RedactedSerialize`. Tags that need custom serialization implement
+/// it directly.
+pub trait SerializeTag<V: ?Sized> {
+    /// Serialize inner value of `TaggedType<V, Self>`.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of the serializer.
+    fn serialize<S: Serializer>(v: &V, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+impl<V: Serialize + ?Sized, T: RedactedSerialize> SerializeTag<V> for T {
+    #[inline]
+    fn serialize<S: Serializer>(v: &V, serializer: S) -> Result<S::Ok, S::Error> {
+        if T::REDACTED {
+            T::serialize_redacted(serializer)
+        } else {
+            v.serialize(serializer)
+        }
+    }
+}
+
+/// Defines how `TaggedType<V, Self>` is deserialized.
+///
+/// Implemented for all tags that implement `TransparentDeserialize`.
+/// Derive implements it for tags with both
+/// `#[transparent(Deserialize)]` and `#[validate(...)]` using
+/// `validation::deserialize_validated`, so invalid data cannot
+/// produce tagged value.
+pub trait DeserializeTag<'de, V> {
+    /// Deserialize inner value of `TaggedType<V, Self>`.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of the deserializer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<V, D::Error>;
+
+    /// Deserialize inner value of `TaggedType<V, Self>` in place.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of the deserializer.
+    #[inline]
+    fn deserialize_in_place<D: Deserializer<'de>>(
+        deserializer: D,
+        place: &mut V,
+    ) -> Result<(), D::Error> {
+        *place = Self::deserialize(deserializer)?;
+        Ok(())
+    }
+}
+
+impl<'de, V: Deserialize<'de>, T: TransparentDeserialize> DeserializeTag<'de, V> for T {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<V, D::Error> {
+        V::deserialize(deserializer)
+    }
+
+    #[inline]
+    fn deserialize_in_place<D: Deserializer<'de>>(
+        deserializer: D,
+        place: &mut V,
+    ) -> Result<(), D::Error> {
+        V::deserialize_in_place(deserializer, place)
+    }
+}
+
+/// Marks tag as secret: `TaggedType<V, T>` is serialized as
+/// `PLACEHOLDER` instead of inner value. Real value can be serialized
+/// only explicitly using `unredacted()` wrapper.
+///
+/// `SerializeTag` is implemented for all `RedactedSerialize` tags.
+/// `TransparentSerialize` tags implement `RedactedSerialize` with
+/// `REDACTED = false`, so secret tag that also implements
+/// `TransparentSerialize` (or `Permissive`) does not compile instead
+/// of leaking plaintext:
+/// ```rust,compile_fail,E0119
+/// use tagged_types::{RedactedSerialize, TransparentSerialize};
+/// pub enum PasswordTag {}
+/// impl TransparentSerialize for PasswordTag {}
+/// impl RedactedSerialize for PasswordTag {}
+/// ```
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, RedactedSerialize, Plain};
+/// pub type Password = TaggedType<String, PasswordTag>;
+/// pub enum PasswordTag {}
+/// impl Plain for PasswordTag {}
+/// impl RedactedSerialize for PasswordTag {}
+///
+/// let password = Password::new("secret".into());
+/// assert_eq!(serde_json::to_string(&password).unwrap(), r#""***""#);
+/// assert_eq!(serde_json::to_string(&password.unredacted()).unwrap(), r#""secret""#);
+/// ```
+pub trait RedactedSerialize {
+    /// Serialize `PLACEHOLDER` instead of inner value.
+    const REDACTED: bool = true;
+
+    /// Serialized instead of inner value.
+    const PLACEHOLDER: &'static str = "***";
+
+    /// Serialize `PLACEHOLDER`.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of the serializer.
+    #[inline]
+    fn serialize_redacted<S: Serializer>(serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(Self::PLACEHOLDER)
+    }
+}
+
+impl<T: TransparentSerialize> RedactedSerialize for T {
+    const REDACTED: bool = false;
+}
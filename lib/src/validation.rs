@@ -0,0 +1,899 @@
+// SPDX-License-Identifier: MIT
+
+use crate::error::tagged_name;
+use crate::SubtagOf;
+use crate::TaggedType;
+use alloc::collections::BTreeMap;
+use alloc::collections::BTreeSet;
+use alloc::collections::VecDeque;
+use core::convert::Infallible;
+use core::error::Error;
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::future::Future;
+use core::marker::PhantomData;
+use core::str::FromStr;
+#[cfg(feature = "support_serde")]
+use serde::de::Error as DeError;
+#[cfg(feature = "support_serde")]
+use serde::Deserialize;
+#[cfg(feature = "support_serde")]
+use serde::Deserializer;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// Validation of inner value that turns tagged type into refinement
+/// type (non-empty username, valid port, etc).
+///
+/// `TaggedType` of the tag is created by `try_new` that normalizes
+/// value (`Normalize`) and runs the check before construction.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, Normalize, Validate};
+/// pub type Port = TaggedType<u16, PortTag>;
+/// pub enum PortTag {}
+///
+/// #[derive(Debug)]
+/// pub struct ZeroPort;
+///
+/// impl Normalize<u16> for PortTag {}
+/// impl Validate<u16> for PortTag {
+///     type Error = ZeroPort;
+///     fn validate(v: &u16) -> Result<(), ZeroPort> {
+///         if *v == 0 { Err(ZeroPort) } else { Ok(()) }
+///     }
+/// }
+///
+/// let port = Port::try_new(8080).unwrap();
+/// assert!(Port::try_new(0).is_err());
+/// ```
+pub trait Validate<V: ?Sized>: Normalize<V> {
+    /// Error of validation.
+    type Error;
+
+    /// Check inner value.
+    ///
+    /// # Errors
+    ///
+    /// Will return error if value is not valid.
+    fn validate(v: &V) -> Result<(), Self::Error>;
+}
+
+/// Marks tag that does not restrict inner value of `TaggedType`.
+///
+/// `new` and capabilities that construct or modify inner value
+/// without checks (`From<V>`, `Default`, `map`, `inner_mut`,
+/// arithmetic operators, ...) require it. Every `NotValidated` tag
+/// accepts any value by `Validate`, so tag with own `Validate` cannot
+/// be `NotValidated` and its values are created only by `try_new` /
+/// `new_unchecked`:
+/// ```rust,compile_fail,E0599
+/// use tagged_types::{TaggedType, Normalize, Validate};
+/// pub type Port = TaggedType<u16, PortTag>;
+/// pub enum PortTag {}
+///
+/// impl Normalize<u16> for PortTag {}
+/// impl Validate<u16> for PortTag {
+///     type Error = &'static str;
+///     fn validate(v: &u16) -> Result<(), Self::Error> {
+///         if *v == 0 { Err("zero port") } else { Ok(()) }
+///     }
+/// }
+///
+/// let port = Port::new(0);
+/// ```
+///
+/// ```rust,compile_fail,E0119
+/// use tagged_types::{TaggedType, Plain, Validate};
+/// pub enum PortTag {}
+/// impl Plain for PortTag {}
+///
+/// impl Validate<u16> for PortTag {
+///     type Error = &'static str;
+///     fn validate(v: &u16) -> Result<(), Self::Error> {
+///         if *v == 0 { Err("zero port") } else { Ok(()) }
+///     }
+/// }
+/// ```
+///
+/// Tags that keep value as is implement it by `Plain`. Tag that
+/// normalizes value implements `NotValidated` together with own
+/// `Normalize`, and `new`, `From<V>` and `from_static` normalize value:
+/// ```rust
+/// use tagged_types::{TaggedType, InnerAccess, Normalize, NotValidated};
+/// pub type Email = TaggedType<String, EmailTag>;
+/// pub enum EmailTag {}
+/// impl NotValidated for EmailTag {}
+/// impl InnerAccess for EmailTag {}
+///
+/// impl Normalize<String> for EmailTag {
+///     fn normalize(v: String) -> String {
+///         v.trim().to_lowercase()
+///     }
+/// }
+///
+/// let email = Email::new(" Admin@Example.com".into());
+/// assert_eq!(email.inner(), "admin@example.com");
+/// ```
+///
+/// Capabilities that modify existing value (`map`, `inner_mut`,
+/// arithmetic operators, ...) do not normalize result.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `NotValidated`",
+    note = "constructing or modifying inner value without validation requires tag that does not restrict it",
+    note = "add `impl Plain for {Self} {{}}` or create values of validated tag with `try_new`"
+)]
+pub trait NotValidated {}
+
+/// Marks tag that neither restricts nor normalizes inner value of
+/// `TaggedType`.
+///
+/// `Plain` tag implements `NotValidated` and `Normalize` that keeps
+/// value as is, so it is created in constant context by `new_const`
+/// and can implement `TransparentFromStr` / `TransparentDeserialize`.
+///
+/// Tag with own `Normalize` cannot be `Plain`:
+/// ```rust,compile_fail,E0119
+/// use tagged_types::{TaggedType, Normalize, Plain};
+/// pub enum EmailTag {}
+/// impl Plain for EmailTag {}
+///
+/// impl Normalize<String> for EmailTag {
+///     fn normalize(v: String) -> String {
+///         v.to_lowercase()
+///     }
+/// }
+/// ```
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, ImplementAdd, InnerAccess, Plain};
+/// pub type Counter = TaggedType<u64, CounterTag>;
+/// pub enum CounterTag {}
+/// impl Plain for CounterTag {};
+/// impl ImplementAdd for CounterTag {};
+/// impl InnerAccess for CounterTag {};
+///
+/// const ZERO: Counter = Counter::new_const(0);
+/// let counter: Counter = ZERO + 1;
+/// assert_eq!(*counter.inner(), 1);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `Plain`",
+    note = "capability keeps inner value as is and requires tag that neither validates nor normalizes it",
+    note = "add `impl Plain for {Self} {{}}`"
+)]
+pub trait Plain {}
+
+impl<T: Plain> NotValidated for T {}
+
+impl<V: ?Sized, T: Plain> Normalize<V> for T {}
+
+impl<V: ?Sized, T: NotValidated + Normalize<V>> Validate<V> for T {
+    type Error = Infallible;
+
+    #[inline]
+    fn validate(_: &V) -> Result<(), Infallible> {
+        Ok(())
+    }
+}
+
+impl<V, T: Validate<V>> TaggedType<V, T> {
+    /// Create `TaggedType` if normalized inner value passes checks of
+    /// `Validate`.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of validation.
+    #[inline]
+    #[cfg_attr(feature = "track_construction", track_caller)]
+    pub fn try_new(v: V) -> Result<Self, T::Error> {
+        let v = T::normalize(v);
+        T::validate(&v)?;
+        Ok(Self::new_raw(v))
+    }
+
+    /// Create `TaggedType` from trusted inner value (database loads,
+    /// constants) without validation, so skipped checks are easy to
+    /// find. Value is still normalized. Debug builds still check the
+    /// value and panic if it is not valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if value does not pass validation.
+    #[inline]
+    #[must_use]
+    #[cfg_attr(feature = "track_construction", track_caller)]
+    pub fn new_unchecked(v: V) -> Self {
+        let v = T::normalize(v);
+        debug_assert!(
+            T::validate(&v).is_ok(),
+            "new_unchecked: value does not pass validation"
+        );
+        Self::new_raw(v)
+    }
+}
+
+impl<V, T> TaggedType<V, T> {
+    /// Converts to tagged type of subtag `C` if inner value passes
+    /// checks of `Validate` of the subtag.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of validation.
+    #[inline]
+    pub fn try_downcast<C>(self) -> Result<TaggedType<V, C>, C::Error>
+    where
+        C: SubtagOf<T> + Validate<V>,
+    {
+        C::validate(self.value())?;
+        Ok(self.with_tag())
+    }
+}
+
+/// `Validate` that can also be evaluated in constant context by
+/// `tagged_const!`.
+///
+/// Trait methods cannot be `const`, so tag declares the check as
+/// inherent `const fn validate_const(v: &V) -> bool` and implements
+/// `Validate` on top of it. Constants and `try_new` then run the same
+/// check. Constants are stored as written (`Normalize` is not called
+/// in constant context).
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, tagged_const, ConstValidate, Normalize, Validate};
+/// pub type Port = TaggedType<u16, PortTag>;
+/// pub enum PortTag {}
+///
+/// #[derive(Debug)]
+/// pub struct ZeroPort;
+///
+/// impl PortTag {
+///     pub const fn validate_const(v: &u16) -> bool {
+///         *v != 0
+///     }
+/// }
+///
+/// impl Normalize<u16> for PortTag {}
+/// impl Validate<u16> for PortTag {
+///     type Error = ZeroPort;
+///     fn validate(v: &u16) -> Result<(), ZeroPort> {
+///         if Self::validate_const(v) { Ok(()) } else { Err(ZeroPort) }
+///     }
+/// }
+/// impl ConstValidate<u16> for PortTag {}
+///
+/// pub const HTTP: Port = tagged_const!(Port, 8080);
+/// assert!(Port::try_new(0).is_err());
+/// ```
+pub trait ConstValidate<V>: Validate<V> {}
+
+/// Inner type and tag of `TaggedType` for `tagged_const!`.
+#[doc(hidden)]
+pub trait TaggedParts {
+    type Value;
+    type Tag;
+}
+
+impl<V, T> TaggedParts for TaggedType<V, T> {
+    type Value = V;
+    type Tag = T;
+}
+
+impl<V, T: ConstValidate<V>> TaggedType<V, T> {
+    /// Constructor of `tagged_const!` that is called after
+    /// `validate_const` of the tag.
+    #[doc(hidden)]
+    #[inline]
+    #[cfg_attr(feature = "track_construction", track_caller)]
+    pub const fn new_const_validated(v: V) -> Self {
+        Self::new_raw(v)
+    }
+}
+
+/// Create `TaggedType` constant that is validated at compile time.
+///
+/// Tag must implement `ConstValidate`. Value that does not pass
+/// `validate_const` of the tag fails compilation instead of startup:
+/// ```rust,compile_fail,E0080
+/// use tagged_types::{TaggedType, tagged_const, ConstValidate, Normalize, Validate};
+/// pub type Port = TaggedType<u16, PortTag>;
+/// pub enum PortTag {}
+///
+/// impl PortTag {
+///     pub const fn validate_const(v: &u16) -> bool {
+///         *v != 0
+///     }
+/// }
+///
+/// impl Normalize<u16> for PortTag {}
+/// impl Validate<u16> for PortTag {
+///     type Error = &'static str;
+///     fn validate(v: &u16) -> Result<(), Self::Error> {
+///         if Self::validate_const(v) { Ok(()) } else { Err("zero port") }
+///     }
+/// }
+/// impl ConstValidate<u16> for PortTag {}
+///
+/// pub const NONE: Port = tagged_const!(Port, 0);
+/// ```
+///
+/// Tags without `ConstValidate` are rejected, so check of the constant
+/// cannot differ from `Validate` of the tag (`Plain` tags create
+/// constants with `const fn new_const`):
+/// ```rust,compile_fail,E0599
+/// use tagged_types::{TaggedType, tagged_const, Plain};
+/// pub type Port = TaggedType<u16, PortTag>;
+/// pub enum PortTag {}
+/// impl Plain for PortTag {}
+///
+/// pub const HTTP: Port = tagged_const!(Port, 8080);
+/// ```
+#[macro_export]
+macro_rules! tagged_const {
+    ($tagged:ty, $value:expr $(,)?) => {
+        const {
+            let value: <$tagged as $crate::validation::TaggedParts>::Value = $value;
+            ::core::assert!(
+                <<$tagged as $crate::validation::TaggedParts>::Tag>::validate_const(&value),
+                ::core::concat!(
+                    "tagged_const!: value of ",
+                    ::core::stringify!($tagged),
+                    " does not pass validation"
+                )
+            );
+            <$tagged>::new_const_validated(value)
+        }
+    };
+}
+
+/// Normalization of inner value (lowercase emails, trimmed
+/// whitespace, NFC-normalized usernames).
+///
+/// Every `Validate` tag implements it, and `new`, `From<V>`,
+/// `try_new`, `try_new_async`, `new_unchecked`, `ParseTag` /
+/// `DeserializeTag` of derive and `validation::parse_validated` /
+/// `validation::deserialize_validated` normalize value before
+/// validation. Default `normalize` keeps value as is, so tags that do
+/// not normalize implement it with empty body (`Plain` tags implement
+/// it this way). See `NotValidated` for tag that only normalizes.
+pub trait Normalize<V: ?Sized> {
+    /// Normalize inner value.
+    #[inline]
+    #[must_use]
+    fn normalize(v: V) -> V
+    where
+        V: Sized,
+    {
+        v
+    }
+}
+
+/// Validation of inner value that requires I/O (remote checks):
+/// uniqueness in database, DNS resolution of host names, etc.
+///
+/// `TaggedType` of the tag is created by `try_new_async` that
+/// normalizes value (`Normalize`) and runs the check before
+/// construction. Returned future must be `Send` so
+/// it can be used with multi-threaded executors.
+///
+/// Example:
+/// ```rust
+/// use tagged_types::{TaggedType, AsyncValidate, Normalize};
+/// pub type Username = TaggedType<String, UsernameTag>;
+/// pub enum UsernameTag {}
+///
+/// #[derive(Debug)]
+/// pub struct UsernameTaken;
+///
+/// impl Normalize<String> for UsernameTag {}
+/// impl AsyncValidate<String> for UsernameTag {
+///     type Error = UsernameTaken;
+///     async fn validate(v: &String) -> Result<(), UsernameTaken> {
+///         // Query user database here.
+///         if v == "admin" { Err(UsernameTaken) } else { Ok(()) }
+///     }
+/// }
+///
+/// async fn register(name: String) -> Result<Username, UsernameTaken> {
+///     Username::try_new_async(name).await
+/// }
+/// ```
+pub trait AsyncValidate<V: ?Sized>: Normalize<V> {
+    /// Error of validation.
+    type Error;
+
+    /// Check inner value.
+    ///
+    /// # Errors
+    ///
+    /// Will return error if value is not valid.
+    fn validate(v: &V) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+impl<V, T: AsyncValidate<V>> TaggedType<V, T> {
+    /// Create `TaggedType` if normalized inner value passes remote
+    /// checks of `AsyncValidate`.
+    ///
+    /// # Errors
+    ///
+    /// Will return error of validation.
+    #[inline]
+    pub async fn try_new_async(v: V) -> Result<Self, T::Error> {
+        let v = T::normalize(v);
+        T::validate(&v).await?;
+        Ok(Self::new_raw(v))
+    }
+}
+
+/// Error of parsing of validated inner value from string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseValidatedError<P, E> {
+    /// String is not valid representation of inner value.
+    Parse(P),
+    /// Parsed inner value does not pass validation.
+    Invalid(E),
+}
+
+impl<P: Display, E: Display> Display for ParseValidatedError<P, E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Parse(e) => e.fmt(f),
+            Self::Invalid(e) => e.fmt(f),
+        }
+    }
+}
+
+impl<P: Error + 'static, E: Error + 'static> Error for ParseValidatedError<P, E> {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Parse(e) => Some(e),
+            Self::Invalid(e) => Some(e),
+        }
+    }
+}
+
+/// Parse inner value with `FromStr`, normalize it and check it with
+/// `Validate` of tag `T`.
+///
+/// Derive implements `ParseTag` with it for tags with both
+/// `#[transparent(FromStr)]` and `#[validate(...)]`. Tags with own
+/// `Validate` use it in `ParseTag` instead of `TransparentFromStr`, so
+/// `parse()` cannot construct invalid values:
+/// ```rust
+/// use tagged_types::{TaggedType, Normalize, ParseTag, ParseValidatedError, Validate};
+/// use tagged_types::validation::parse_validated;
+/// use core::num::ParseIntError;
+/// pub type Port = TaggedType<u16, PortTag>;
+/// pub enum PortTag {}
+///
+/// impl Normalize<u16> for PortTag {}
+/// impl Validate<u16> for PortTag {
+///     type Error = &'static str;
+///     fn validate(v: &u16) -> Result<(), Self::Error> {
+///         if *v == 0 { Err("zero port") } else { Ok(()) }
+///     }
+/// }
+///
+/// impl ParseTag<u16> for PortTag {
+///     type Err = ParseValidatedError<ParseIntError, &'static str>;
+///     fn parse(s: &str) -> Result<u16, Self::Err> {
+///         parse_validated::<u16, Self>(s)
+///     }
+/// }
+///
+/// assert!("8080".parse::<Port>().is_ok());
+/// assert_eq!("0".parse::<Port>().err(), Some(ParseValidatedError::Invalid("zero port")));
+/// ```
+///
+/// # Errors
+///
+/// Will return error of parsing or error of validation.
+#[inline]
+pub fn parse_validated<V: FromStr, T: Validate<V>>(
+    s: &str,
+) -> Result<V, ParseValidatedError<V::Err, T::Error>> {
+    let v = T::normalize(s.parse().map_err(ParseValidatedError::Parse)?);
+    T::validate(&v).map_err(ParseValidatedError::Invalid)?;
+    Ok(v)
+}
+
+/// Deserialize inner value, normalize it and check it with `Validate`
+/// of tag `T`.
+/// Validation error is reported by `serde::de::Error::custom`.
+///
+/// Derive implements `DeserializeTag` with it for tags with both
+/// `#[transparent(Deserialize)]` and `#[validate(...)]`:
+/// ```rust
+/// use tagged_types::{TaggedType, DeserializeTag, Normalize, Validate};
+/// use tagged_types::validation::deserialize_validated;
+/// pub type Port = TaggedType<u16, PortTag>;
+/// pub enum PortTag {}
+///
+/// impl Normalize<u16> for PortTag {}
+/// impl Validate<u16> for PortTag {
+///     type Error = &'static str;
+///     fn validate(v: &u16) -> Result<(), Self::Error> {
+///         if *v == 0 { Err("zero port") } else { Ok(()) }
+///     }
+/// }
+///
+/// impl<'de> DeserializeTag<'de, u16> for PortTag {
+///     fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<u16, D::Error> {
+///         deserialize_validated::<u16, Self, D>(d)
+///     }
+/// }
+///
+/// assert!(serde_json::from_str::<Port>("8080").is_ok());
+/// assert!(serde_json::from_str::<Port>("0").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Will return error of the deserializer or error of validation.
+#[cfg(feature = "support_serde")]
+#[inline]
+pub fn deserialize_validated<'de, V, T, D>(deserializer: D) -> Result<V, D::Error>
+where
+    V: Deserialize<'de>,
+    T: Validate<V>,
+    T::Error: Display,
+    D: Deserializer<'de>,
+{
+    let v = T::normalize(V::deserialize(deserializer)?);
+    T::validate(&v).map_err(DeError::custom)?;
+    Ok(v)
+}
+
+/// Error of validation of `TaggedType<V, T>`: violated rule together
+/// with name of the tagged type, so API errors say which field failed
+/// (`"Username: length must be in range 1..=64"`).
+///
+/// Name is the tag type name without module path and `Tag` suffix.
+/// Derive `#[validate(...)]` uses it as `Validate::Error`. Hand-written
+/// validators can use it too:
+/// ```rust
+/// use tagged_types::{TaggedType, InvalidTagged, Normalize, Validate};
+/// pub type Port = TaggedType<u16, PortTag>;
+/// pub enum PortTag {}
+///
+/// impl Normalize<u16> for PortTag {}
+/// impl Validate<u16> for PortTag {
+///     type Error = InvalidTagged<Self>;
+///     fn validate(v: &u16) -> Result<(), Self::Error> {
+///         if *v == 0 { Err(InvalidTagged::new("must not be zero")) } else { Ok(()) }
+///     }
+/// }
+///
+/// let err = Port::try_new(0).err().map(|e| e.to_string());
+/// assert_eq!(err.as_deref(), Some("Port: must not be zero"));
+/// ```
+pub struct InvalidTagged<T> {
+    rule: &'static str,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> InvalidTagged<T> {
+    /// Create violation of the rule.
+    #[must_use]
+    pub const fn new(rule: &'static str) -> Self {
+        Self {
+            rule,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Description of violated rule.
+    #[must_use]
+    pub const fn rule(&self) -> &'static str {
+        self.rule
+    }
+
+    /// Name of the tagged type (`Username` for `UsernameTag`).
+    #[must_use]
+    pub fn tagged_name() -> &'static str {
+        tagged_name::<T>()
+    }
+}
+
+impl<T> Clone for InvalidTagged<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for InvalidTagged<T> {}
+
+impl<T> PartialEq for InvalidTagged<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.rule == other.rule
+    }
+}
+
+impl<T> Eq for InvalidTagged<T> {}
+
+impl<T> Debug for InvalidTagged<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("InvalidTagged")
+            .field("tagged", &Self::tagged_name())
+            .field("rule", &self.rule)
+            .finish()
+    }
+}
+
+impl<T> Display for InvalidTagged<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}: {}", Self::tagged_name(), self.rule)
+    }
+}
+
+impl<T> Error for InvalidTagged<T> {}
+
+/// Length of inner value checked by `#[validate(length(...))]`
+/// attribute of derive. Length of strings is number of characters.
+pub trait HasLength {
+    /// Length of the value.
+    fn length(&self) -> usize;
+}
+
+impl HasLength for str {
+    #[inline]
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl HasLength for String {
+    #[inline]
+    fn length(&self) -> usize {
+        self.as_str().length()
+    }
+}
+
+impl<X> HasLength for [X] {
+    #[inline]
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<X, const N: usize> HasLength for [X; N] {
+    #[inline]
+    fn length(&self) -> usize {
+        N
+    }
+}
+
+impl<H: HasLength + ?Sized> HasLength for &H {
+    #[inline]
+    fn length(&self) -> usize {
+        (**self).length()
+    }
+}
+
+macro_rules! has_length {
+    ($($t:ident<$($p:ident),*>),*) => {
+        $(
+            impl<$($p),*> HasLength for $t<$($p),*> {
+                #[inline]
+                fn length(&self) -> usize {
+                    self.len()
+                }
+            }
+        )*
+    };
+}
+
+has_length!(
+    Vec<X>,
+    VecDeque<X>,
+    BTreeSet<X>,
+    BTreeMap<K, X>,
+    HashSet<X, S>,
+    HashMap<K, X, S>
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::block_on;
+    use crate::*;
+
+    #[test]
+    fn test_try_new() {
+        enum UsernameTag {}
+        type Username = TaggedType<String, UsernameTag>;
+        impl Normalize<String> for UsernameTag {}
+        impl Validate<String> for UsernameTag {
+            type Error = &'static str;
+            fn validate(v: &String) -> Result<(), Self::Error> {
+                if v.is_empty() {
+                    Err("empty username")
+                } else {
+                    Ok(())
+                }
+            }
+        }
+        impl InnerAccess for UsernameTag {}
+        assert_eq!(
+            Username::try_new("admin".into())
+                .expect("valid username")
+                .inner(),
+            "admin"
+        );
+        assert_eq!(
+            Username::try_new(String::new()).err(),
+            Some("empty username")
+        );
+    }
+
+    #[test]
+    fn test_try_new_normalizes() {
+        enum UsernameTag {}
+        type Username = TaggedType<String, UsernameTag>;
+        impl Normalize<String> for UsernameTag {
+            fn normalize(v: String) -> String {
+                v.trim().into()
+            }
+        }
+        impl Validate<String> for UsernameTag {
+            type Error = &'static str;
+            fn validate(v: &String) -> Result<(), Self::Error> {
+                if v.is_empty() {
+                    Err("empty username")
+                } else {
+                    Ok(())
+                }
+            }
+        }
+        impl InnerAccess for UsernameTag {}
+        assert_eq!(
+            Username::try_new(" a ".into())
+                .expect("valid username")
+                .inner(),
+            "a"
+        );
+        assert!(Username::try_new("  ".into()).is_err());
+        assert_eq!(
+            validation::parse_validated::<String, UsernameTag>(" b ").ok(),
+            Some("b".into())
+        );
+        assert!(validation::parse_validated::<String, UsernameTag>("  ").is_err());
+    }
+
+    #[test]
+    fn test_new_normalizes() {
+        enum EmailTag {}
+        type Email = TaggedType<String, EmailTag>;
+        impl NotValidated for EmailTag {}
+        impl Normalize<String> for EmailTag {
+            fn normalize(v: String) -> String {
+                v.trim().to_lowercase()
+            }
+        }
+        impl InnerAccess for EmailTag {}
+        impl FromInner for EmailTag {}
+        assert_eq!(Email::new(" A@B.c".into()).inner(), "a@b.c");
+        assert_eq!(Email::from(String::from("A@B.c ")).inner(), "a@b.c");
+        assert_eq!(Email::from_static("A@B.C").inner(), "a@b.c");
+        assert_eq!(
+            Email::try_new(" A@B.c ".into()).map(Email::into_inner),
+            Ok("a@b.c".into())
+        );
+    }
+
+    #[test]
+    fn test_subtag() {
+        enum PortTag {}
+        impl Plain for PortTag {}
+        enum PrivilegedPortTag {}
+        type Port = TaggedType<u16, PortTag>;
+        impl SubtagOf<PortTag> for PrivilegedPortTag {}
+        impl Normalize<u16> for PrivilegedPortTag {}
+        impl Validate<u16> for PrivilegedPortTag {
+            type Error = InvalidTagged<Self>;
+            fn validate(v: &u16) -> Result<(), Self::Error> {
+                if *v < 1024 {
+                    Ok(())
+                } else {
+                    Err(InvalidTagged::new("must be below 1024"))
+                }
+            }
+        }
+        impl InnerAccess for PortTag {}
+        let ssh = Port::new(22)
+            .try_downcast::<PrivilegedPortTag>()
+            .expect("privileged port");
+        let port: Port = ssh.upcast();
+        assert_eq!(*port.inner(), 22);
+        assert_eq!(
+            Port::new(8080)
+                .try_downcast::<PrivilegedPortTag>()
+                .err()
+                .map(|e| e.to_string()),
+            Some("PrivilegedPort: must be below 1024".into())
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "upcast: value does not pass validation of parent tag")]
+    fn test_subtag_broken_contract() {
+        enum PortTag {}
+        impl Normalize<u16> for PortTag {}
+        impl Validate<u16> for PortTag {
+            type Error = InvalidTagged<Self>;
+            fn validate(v: &u16) -> Result<(), Self::Error> {
+                if *v == 0 {
+                    Err(InvalidTagged::new("must not be zero"))
+                } else {
+                    Ok(())
+                }
+            }
+        }
+        enum AnyPortTag {}
+        impl Plain for AnyPortTag {}
+        impl SubtagOf<PortTag> for AnyPortTag {}
+        let _ = TaggedType::<u16, AnyPortTag>::new(0).upcast::<PortTag>();
+    }
+
+    #[test]
+    fn test_tagged_const() {
+        enum HeaderNameTag {}
+        type HeaderName = TaggedType<&'static str, HeaderNameTag>;
+        impl HeaderNameTag {
+            const fn validate_const(v: &&str) -> bool {
+                !v.is_empty()
+            }
+        }
+        impl Normalize<&str> for HeaderNameTag {}
+        impl Validate<&str> for HeaderNameTag {
+            type Error = &'static str;
+            fn validate(v: &&str) -> Result<(), Self::Error> {
+                if Self::validate_const(v) {
+                    Ok(())
+                } else {
+                    Err("empty header name")
+                }
+            }
+        }
+        impl ConstValidate<&str> for HeaderNameTag {}
+        impl InnerAccess for HeaderNameTag {}
+        const ACCEPT: HeaderName = tagged_const!(HeaderName, "accept");
+        assert_eq!(*ACCEPT.inner(), "accept");
+        assert!(HeaderName::try_new("").is_err());
+    }
+
+    #[test]
+    fn test_try_new_async() {
+        enum HostTag {}
+        type Host = TaggedType<String, HostTag>;
+        impl Normalize<String> for HostTag {
+            fn normalize(v: String) -> String {
+                v.to_lowercase()
+            }
+        }
+        impl AsyncValidate<String> for HostTag {
+            type Error = &'static str;
+            async fn validate(v: &String) -> Result<(), Self::Error> {
+                if v.ends_with(".invalid") {
+                    Err("unresolvable host")
+                } else {
+                    Ok(())
+                }
+            }
+        }
+        impl InnerAccess for HostTag {}
+        let host = block_on(Host::try_new_async("Example.com".into())).expect("valid host");
+        assert_eq!(host.inner(), "example.com");
+        assert!(block_on(Host::try_new_async("host.INVALID".into())).is_err());
+    }
+}